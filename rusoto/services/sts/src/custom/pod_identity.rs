@@ -0,0 +1,325 @@
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use rusoto_core::credential::{
+    AwsCredentials, CredentialsError, ProvideAwsCredentials, Secret, Variable,
+};
+use rusoto_core::request::{DispatchSignedRequest, HttpClient};
+use rusoto_core::signature::{self, SignedRequest};
+use rusoto_core::Region;
+
+use async_trait::async_trait;
+use chrono::Duration;
+use futures::lock::Mutex;
+
+const AWS_CONTAINER_CREDENTIALS_FULL_URI: &str = "AWS_CONTAINER_CREDENTIALS_FULL_URI";
+
+const AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE: &str = "AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE";
+
+/// The endpoint of the EKS Pod Identity Agent.
+pub const DEFAULT_POD_IDENTITY_URI: &str = "http://169.254.170.23/v1/credentials";
+
+/// The file the EKS Pod Identity webhook mounts the service account token at.
+pub const DEFAULT_POD_IDENTITY_TOKEN_FILE: &str =
+    "/var/run/secrets/pods.eks.amazonaws.com/serviceaccount/eks-pod-identity-token";
+
+/// PodIdentityProvider retrieving AWS IAM credentials from the EKS Pod Identity Agent.
+///
+/// See https://docs.aws.amazon.com/eks/latest/userguide/pod-identities.html for more details.
+///
+/// The agent runs on every node of the cluster and exchanges the projected service account token
+/// of the pod for the credentials of the role associated with the service account. Unlike
+/// `WebIdentityProvider`, no call to STS is made by the workload.
+///
+/// Credentials are cached and reused until they are about to expire. By default credentials are
+/// refreshed five minutes before their expiry time; use `with_refresh_window` to change this.
+/// Clones of a provider share the same cache.
+#[derive(Clone)]
+pub struct PodIdentityProvider {
+    /// The service account token, sent to the agent in the `Authorization` header.
+    pub token: Variable<Secret, CredentialsError>,
+    /// The full URI of the agent's credentials endpoint.
+    pub uri: Variable<String, CredentialsError>,
+    /// Timeout of requests to the agent.
+    pub timeout: StdDuration,
+    /// Cached credentials are refreshed when they expire within this window.
+    pub refresh_window: Duration,
+    dispatcher: Option<Arc<dyn DispatchSignedRequest + Send + Sync>>,
+    cached_credentials: Arc<Mutex<Option<AwsCredentials>>>,
+}
+
+impl fmt::Debug for PodIdentityProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PodIdentityProvider")
+            .field("token", &self.token)
+            .field("uri", &self.uri)
+            .field("timeout", &self.timeout)
+            .field("refresh_window", &self.refresh_window)
+            .finish()
+    }
+}
+
+impl PodIdentityProvider {
+    /// Create new PodIdentityProvider by explicitly passing its configuration.
+    pub fn new<A, B>(token: A, uri: B) -> Self
+    where
+        A: Into<Variable<Secret, CredentialsError>>,
+        B: Into<Variable<String, CredentialsError>>,
+    {
+        Self {
+            token: token.into(),
+            uri: uri.into(),
+            timeout: StdDuration::from_secs(5),
+            refresh_window: Duration::seconds(super::DEFAULT_REFRESH_WINDOW_SECONDS),
+            dispatcher: None,
+            cached_credentials: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Create a PodIdentityProvider from the following environment variables:
+    ///
+    /// - `AWS_CONTAINER_CREDENTIALS_FULL_URI` URI of the agent, defaults to
+    ///   `http://169.254.170.23/v1/credentials`.
+    /// - `AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE` path to the service account token file,
+    ///   defaults to `/var/run/secrets/pods.eks.amazonaws.com/serviceaccount/eks-pod-identity-token`.
+    ///
+    /// Both are set by the EKS Pod Identity webhook. The token file is read on every refresh, as
+    /// the token is rotated by the kubelet.
+    pub fn from_k8s_env() -> Self {
+        Self::_from_k8s_env(
+            Variable::from_env_var_optional(AWS_CONTAINER_AUTHORIZATION_TOKEN_FILE),
+            Variable::from_env_var_optional(AWS_CONTAINER_CREDENTIALS_FULL_URI),
+        )
+    }
+
+    /// Used by unit testing
+    pub(crate) fn _from_k8s_env(
+        token_file: Variable<Option<String>, CredentialsError>,
+        uri: Variable<Option<String>, CredentialsError>,
+    ) -> Self {
+        Self::new(
+            Variable::dynamic(move || {
+                let token_file = token_file
+                    .resolve()?
+                    .unwrap_or_else(|| DEFAULT_POD_IDENTITY_TOKEN_FILE.to_owned());
+                Variable::from_text_file(token_file).resolve()
+            }),
+            Variable::dynamic(move || {
+                Ok(uri
+                    .resolve()?
+                    .unwrap_or_else(|| DEFAULT_POD_IDENTITY_URI.to_owned()))
+            }),
+        )
+    }
+
+    /// Set the timeout of requests to the agent.
+    pub fn with_timeout(mut self, timeout: StdDuration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set how long before their expiry cached credentials are refreshed.
+    pub fn with_refresh_window(mut self, refresh_window: Duration) -> Self {
+        self.refresh_window = refresh_window;
+        self
+    }
+
+    /// Send requests to the agent through the given dispatcher instead of the shared `HttpClient`.
+    pub fn with_dispatcher<D>(mut self, dispatcher: D) -> Self
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        self.dispatcher = Some(Arc::new(dispatcher));
+        self
+    }
+
+    /// Builds the request to the agent from the provider's configuration.
+    fn credentials_request(&self) -> Result<SignedRequest, CredentialsError> {
+        let uri = self.uri.resolve()?;
+        let (endpoint, path, query) = split_uri(&uri)?;
+        let region = Region::Custom {
+            name: String::new(),
+            endpoint: endpoint.to_owned(),
+        };
+        let mut request = SignedRequest::new("GET", "eks-auth", &region, path);
+        for param in query.split('&').filter(|param| !param.is_empty()) {
+            let mut parts = param.splitn(2, '=');
+            let key = signature::decode_uri(parts.next().unwrap_or_default());
+            let value = parts.next().map(signature::decode_uri);
+            request.params.insert(key, value);
+        }
+        request.add_header("Authorization", self.token.resolve()?.as_ref());
+        Ok(request)
+    }
+
+    /// Fetches fresh credentials from the agent.
+    async fn fetch_credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let request = self.credentials_request()?;
+        let future = match self.dispatcher {
+            Some(ref dispatcher) => dispatcher.dispatch(request, Some(self.timeout)),
+            None => HttpClient::shared()
+                .map_err(CredentialsError::new)?
+                .dispatch(request, Some(self.timeout)),
+        };
+        let response = future
+            .await
+            .map_err(CredentialsError::new)?
+            .buffer()
+            .await
+            .map_err(CredentialsError::new)?;
+        if !response.status.is_success() {
+            return Err(CredentialsError::new(format!(
+                "EKS Pod Identity Agent responded with {}: {}",
+                response.status,
+                response.body_as_str()
+            )));
+        }
+        Ok(serde_json::from_slice::<AwsCredentials>(&response.body)?)
+    }
+}
+
+/// Splits a URI into the endpoint, i.e. scheme and authority, the path and the query.
+fn split_uri(uri: &str) -> Result<(&str, &str, &str), CredentialsError> {
+    let authority_start = match uri.find("://") {
+        Some(index) => index + 3,
+        None => {
+            return Err(CredentialsError::new(format!(
+                "Invalid EKS Pod Identity Agent URI: {}",
+                uri
+            )))
+        }
+    };
+    let (uri, query) = match uri.find('?') {
+        Some(index) => (&uri[..index], &uri[index + 1..]),
+        None => (uri, ""),
+    };
+    match uri[authority_start..].find('/') {
+        Some(index) => {
+            let (endpoint, path) = uri.split_at(authority_start + index);
+            Ok((endpoint, path, query))
+        }
+        None => Ok((uri, "/", query)),
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for PodIdentityProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut cached_credentials = self.cached_credentials.lock().await;
+        if let Some(ref credentials) = *cached_credentials {
+            if !credentials.credentials_are_expired(self.refresh_window) {
+                return Ok(credentials.clone());
+            }
+        }
+        let credentials = self.fetch_credentials().await?;
+        *cached_credentials = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_mock::{MockRequestDispatcher, MultipleMockRequestDispatcher};
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    const POD_IDENTITY_RESPONSE: &str = r#"{
+        "AccessKeyId": "access_key_id",
+        "SecretAccessKey": "secret_access_key",
+        "Token": "session_token",
+        "AccountId": "123456789012",
+        "Expiration": "2100-01-01T00:00:00Z"
+    }"#;
+
+    #[test]
+    fn split_uris() {
+        assert_eq!(
+            split_uri(DEFAULT_POD_IDENTITY_URI).unwrap(),
+            ("http://169.254.170.23", "/v1/credentials", "")
+        );
+        assert_eq!(
+            split_uri("http://[fd00:ec2::23]:80/v1/credentials").unwrap(),
+            ("http://[fd00:ec2::23]:80", "/v1/credentials", "")
+        );
+        assert_eq!(
+            split_uri("http://localhost").unwrap(),
+            ("http://localhost", "/", "")
+        );
+        assert_eq!(
+            split_uri("http://localhost:8080/credentials?role=a%2Fb&debug").unwrap(),
+            ("http://localhost:8080", "/credentials", "role=a%2Fb&debug")
+        );
+        assert_eq!(
+            split_uri("http://localhost?role=a").unwrap(),
+            ("http://localhost", "/", "role=a")
+        );
+        assert!(split_uri("169.254.170.23/v1/credentials").is_err());
+    }
+
+    #[test]
+    fn from_k8s_env() -> Result<(), CredentialsError> {
+        let mut file = NamedTempFile::new()?;
+        writeln!(file, "token")?;
+        let provider = PodIdentityProvider::_from_k8s_env(
+            Variable::with_value(Some(file.path().to_string_lossy().to_string())),
+            Variable::with_value(None),
+        );
+        let request = provider.credentials_request()?;
+        assert_eq!(request.hostname(), "169.254.170.23");
+        assert_eq!(request.scheme(), "http");
+        assert_eq!(request.path(), "/v1/credentials");
+        assert_eq!(
+            request.headers().get("authorization"),
+            Some(&vec![b"token".to_vec()])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn query_parameters() -> Result<(), CredentialsError> {
+        let provider = PodIdentityProvider::new(
+            Secret::from("token".to_string()),
+            "http://localhost:8080/credentials?role=a%2Fb&debug".to_string(),
+        );
+        let request = provider.credentials_request()?;
+        assert_eq!(request.path(), "/credentials");
+        assert_eq!(request.params.get("role"), Some(&Some("a/b".to_owned())));
+        assert_eq!(request.params.get("debug"), Some(&None));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn credentials_are_cached() -> Result<(), CredentialsError> {
+        // The dispatcher only holds a single response: a second request would panic.
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(200).with_body(POD_IDENTITY_RESPONSE),
+        ]);
+        let provider = PodIdentityProvider::new(
+            Secret::from("token".to_string()),
+            DEFAULT_POD_IDENTITY_URI.to_string(),
+        )
+        .with_dispatcher(dispatcher);
+
+        let creds = provider.credentials().await?;
+        assert_eq!(creds.aws_access_key_id(), "access_key_id");
+        assert_eq!(creds.aws_secret_access_key(), "secret_access_key");
+        assert_eq!(creds.token(), &Some("session_token".to_owned()));
+        assert!(creds.expires_at().is_some());
+
+        let cached = provider.clone().credentials().await?;
+        assert_eq!(cached.aws_access_key_id(), "access_key_id");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn error_response() {
+        let provider = PodIdentityProvider::new(
+            Secret::from("token".to_string()),
+            DEFAULT_POD_IDENTITY_URI.to_string(),
+        )
+        .with_dispatcher(MockRequestDispatcher::with_status(401).with_body("unauthorized"));
+        assert!(provider.credentials().await.is_err());
+    }
+}
@@ -0,0 +1,550 @@
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::custom::cli_cache::{assume_role_cache_key, web_identity_cache_key};
+use crate::custom::credential::{MfaCodeProvider, NewAwsCredsForStsCreds};
+use crate::custom::source_identity::with_source_identity;
+use crate::custom::web_identity::WebIdentityProvider;
+use crate::{AssumeRoleRequest, AssumeRoleWithWebIdentityRequest, Sts, StsClient};
+use rusoto_core::credential::{
+    AwsCredentials, CliCache, ContainerProvider, CredentialsError, EnvironmentProvider,
+    InstanceMetadataProvider, ProfileProvider, ProfileRole, ProvideAwsCredentials, RoleChain,
+    RoleChainSource, StaticProvider, Variable,
+};
+use rusoto_core::request::{DispatchSignedRequest, HttpClient};
+use rusoto_core::Region;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+/// AssumeRoleProfileProvider provides the credentials of a profile of the shared config and
+/// credentials files, assuming the roles the profile is configured for like the AWS CLI does.
+///
+/// If the profile sets `role_arn`, the credentials of its `source_profile` (which may itself set
+/// `role_arn`, forming a chain of roles) or of its `credential_source` (`Environment`,
+/// `Ec2InstanceMetadata` or `EcsContainer`) are used to call `AssumeRole`. Otherwise the
+/// credentials are provided by the wrapped `ProfileProvider`. Profiles setting
+/// `web_identity_token_file` assume their role with `AssumeRoleWithWebIdentity`, like the
+/// `WebIdentityProvider`.
+///
+/// Roles of profiles setting `mfa_serial` are assumed with the code returned by the callback set
+/// with `with_mfa_code_provider`.
+///
+/// Credentials aren't cached in memory: wrap the provider in an `AutoRefreshingProvider` to reuse
+/// them until they expire. Like the AWS CLI, the provider can also keep the credentials of the
+/// last role in a `CliCache` (see `with_cli_cache`), so that processes running one after the
+/// other don't assume the roles again, nor ask for MFA codes every time. It can take the place of the `ProfileProvider` of a `ChainProvider`:
+///
+/// ```rust,no_run
+/// use rusoto_core::credential::{AutoRefreshingProvider, ChainProvider, ChainProviderBuilder};
+/// use rusoto_sts::AssumeRoleProfileProvider;
+///
+/// let provider = ChainProvider::builder()
+///     .replace(
+///         ChainProviderBuilder::PROFILE,
+///         AssumeRoleProfileProvider::new().expect("profile provider"),
+///     )
+///     .build();
+/// let provider = AutoRefreshingProvider::new(provider).expect("auto refreshing provider");
+/// ```
+#[derive(Clone)]
+pub struct AssumeRoleProfileProvider {
+    profile_provider: ProfileProvider,
+    region: Option<Region>,
+    dispatcher: Option<Arc<dyn DispatchSignedRequest + Send + Sync>>,
+    mfa_code_provider: Option<MfaCodeProvider>,
+    source_identity: Option<String>,
+    cli_cache: Option<CliCache>,
+}
+
+impl fmt::Debug for AssumeRoleProfileProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AssumeRoleProfileProvider")
+            .field("profile_provider", &self.profile_provider)
+            .field("region", &self.region)
+            .field("cli_cache", &self.cli_cache)
+            .finish()
+    }
+}
+
+impl AssumeRoleProfileProvider {
+    /// Create a new AssumeRoleProfileProvider for the default credentials file path and
+    /// profile name.
+    pub fn new() -> Result<Self, CredentialsError> {
+        Ok(Self::with_profile_provider(ProfileProvider::new()?))
+    }
+
+    /// Create a new AssumeRoleProfileProvider for the file path and profile of the given
+    /// `ProfileProvider`.
+    pub fn with_profile_provider(profile_provider: ProfileProvider) -> Self {
+        Self {
+            profile_provider,
+            region: None,
+            dispatcher: None,
+            mfa_code_provider: None,
+            source_identity: None,
+            cli_cache: None,
+        }
+    }
+
+    /// Set the region of the STS endpoint. Defaults to `Region::default()`.
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Send requests to STS through the given dispatcher instead of the shared `HttpClient`.
+    pub fn with_dispatcher<D>(mut self, dispatcher: D) -> Self
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        self.dispatcher = Some(Arc::new(dispatcher));
+        self
+    }
+
+    /// Set a callback returning the current code of the MFA device, invoked every time a role
+    /// of a profile setting `mfa_serial` is assumed.
+    pub fn with_mfa_code_provider<F, Fut>(mut self, provider: F) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = String> + Send + 'static,
+    {
+        self.mfa_code_provider = Some(MfaCodeProvider::new(provider));
+        self
+    }
+
+    /// Set the source identity of the sessions. It is passed when the first role of the chain
+    /// is assumed, and carried over by STS to the sessions of the following roles.
+    pub fn with_source_identity<S>(mut self, source_identity: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.source_identity = Some(source_identity.into());
+        self
+    }
+
+    /// Cache the credentials of the role of the profile in the given `CliCache`. Usually
+    /// `CliCache::new()`, which shares the credentials with the AWS CLI.
+    pub fn with_cli_cache(mut self, cache: CliCache) -> Self {
+        self.cli_cache = Some(cache);
+        self
+    }
+
+    /// Get a reference to the wrapped `ProfileProvider`.
+    pub fn profile_provider(&self) -> &ProfileProvider {
+        &self.profile_provider
+    }
+
+    /// Returns a client calling STS with the given credentials.
+    fn sts_client(&self, credentials: AwsCredentials) -> Result<StsClient, CredentialsError> {
+        let region = self.region.clone().unwrap_or_default();
+        let credentials = StaticProvider::from(credentials);
+        match self.dispatcher {
+            Some(ref dispatcher) => {
+                Ok(StsClient::new_with(dispatcher.clone(), credentials, region))
+            }
+            None => Ok(StsClient::new_with(
+                HttpClient::shared().map_err(CredentialsError::new)?,
+                credentials,
+                region,
+            )),
+        }
+    }
+
+    /// Builds the `AssumeRole` request for the given role, without the MFA code.
+    fn assume_role_request(&self, role: &ProfileRole) -> AssumeRoleRequest {
+        AssumeRoleRequest {
+            role_arn: role.role_arn.clone(),
+            role_session_name: role
+                .role_session_name
+                .clone()
+                .unwrap_or_else(|| format!("rusoto-session-{}", Utc::now().timestamp())),
+            duration_seconds: role.duration_seconds,
+            external_id: role.external_id.clone(),
+            serial_number: role.mfa_serial.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the `CliCache` key of the credentials of the chain, which are the credentials of
+    /// its last role.
+    fn cache_key(&self, chain: &RoleChain) -> Result<Option<String>, CredentialsError> {
+        let is_web_identity = matches!(chain.source, RoleChainSource::WebIdentity { .. });
+        match (chain.roles.last(), &chain.source) {
+            (Some(role), _) => {
+                // only the first role is assumed with the source identity
+                let source_identity = if chain.roles.len() == 1 && !is_web_identity {
+                    self.source_identity.as_deref()
+                } else {
+                    None
+                };
+                let request = self.assume_role_request(role);
+                Ok(Some(assume_role_cache_key(&request, source_identity)?))
+            }
+            (None, RoleChainSource::WebIdentity { ref role_arn, .. }) => {
+                let request = AssumeRoleWithWebIdentityRequest {
+                    role_arn: role_arn.clone(),
+                    ..Default::default()
+                };
+                Ok(Some(web_identity_cache_key(
+                    &request,
+                    self.source_identity.as_deref(),
+                )?))
+            }
+            (None, _) => Ok(None),
+        }
+    }
+
+    /// Calls `AssumeRole` for the given role with the given credentials.
+    async fn assume_role(
+        &self,
+        credentials: AwsCredentials,
+        role: &ProfileRole,
+        source_identity: Option<String>,
+    ) -> Result<AwsCredentials, CredentialsError> {
+        let token_code = match (&role.mfa_serial, &self.mfa_code_provider) {
+            (Some(_), Some(provider)) => Some(provider.code().await),
+            (Some(_), None) => {
+                return Err(CredentialsError::new(format!(
+                    "Profile {} sets mfa_serial, but no MFA code provider is set",
+                    role.profile
+                )))
+            }
+            (None, _) => None,
+        };
+        let request = AssumeRoleRequest {
+            token_code,
+            ..self.assume_role_request(role)
+        };
+        let sts = self.sts_client(credentials)?;
+        let response = with_source_identity(sts.assume_role(request), source_identity)
+            .await
+            .map_err(|err| {
+                CredentialsError::new(format!(
+                    "Failed to assume role {} of profile {}: {}",
+                    role.role_arn, role.profile, err
+                ))
+            })?;
+        match response.credentials {
+            Some(creds) => AwsCredentials::new_for_credentials(creds),
+            None => Err(CredentialsError::new(format!(
+                "No credentials found in AssumeRoleResponse for role {}",
+                role.role_arn
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for AssumeRoleProfileProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let chain = match self.profile_provider.role_chain()? {
+            Some(chain) => chain,
+            None => return self.profile_provider.credentials().await,
+        };
+        let cache_key = match self.cli_cache {
+            Some(ref cache) => match self.cache_key(&chain)? {
+                Some(key) => {
+                    if let Some(credentials) = cache.load(&key) {
+                        return Ok(credentials);
+                    }
+                    Some((cache, key))
+                }
+                None => None,
+            },
+            None => None,
+        };
+        // the source identity can't be changed once set, later sessions inherit it
+        let mut source_identity = self.source_identity.clone();
+        let mut credentials = match chain.source {
+            RoleChainSource::Profile(ref provider) => provider.credentials().await?,
+            RoleChainSource::Environment => EnvironmentProvider::default().credentials().await?,
+            RoleChainSource::Ec2InstanceMetadata => {
+                InstanceMetadataProvider::new().credentials().await?
+            }
+            RoleChainSource::EcsContainer => ContainerProvider::new().credentials().await?,
+            RoleChainSource::WebIdentity {
+                ref role_arn,
+                ref web_identity_token_file,
+                ref role_session_name,
+                ..
+            } => {
+                let mut provider = WebIdentityProvider::new(
+                    Variable::from_text_file(web_identity_token_file),
+                    role_arn.to_owned(),
+                    role_session_name.clone().map(Some),
+                )
+                .with_region(self.region.clone().unwrap_or_default());
+                if let Some(ref dispatcher) = self.dispatcher {
+                    provider = provider.with_dispatcher(dispatcher.clone());
+                }
+                provider.source_identity = source_identity.take();
+                provider.credentials().await?
+            }
+        };
+        for role in &chain.roles {
+            credentials = self
+                .assume_role(credentials, role, source_identity.take())
+                .await?;
+        }
+        if let Some((cache, key)) = cache_key {
+            // the credentials are still usable if they can't be cached
+            let _ = cache.store(&key, &credentials);
+        }
+        Ok(credentials)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+    use rusoto_mock::{MockRequestDispatcher, MultipleMockRequestDispatcher};
+    use std::io::Write;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::NamedTempFile;
+
+    fn assume_role_response(access_key_id: &str) -> String {
+        format!(
+            r#"<AssumeRoleResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleResult>
+    <AssumedRoleUser>
+      <Arn>arn:aws:sts::123456789012:assumed-role/role/session</Arn>
+      <AssumedRoleId>ARO123EXAMPLE123:session</AssumedRoleId>
+    </AssumedRoleUser>
+    <Credentials>
+      <AccessKeyId>{}</AccessKeyId>
+      <SecretAccessKey>secret_access_key</SecretAccessKey>
+      <SessionToken>session_token</SessionToken>
+      <Expiration>2100-01-01T00:00:00Z</Expiration>
+    </Credentials>
+  </AssumeRoleResult>
+  <ResponseMetadata>
+    <RequestId>c6104cbe-af31-11e0-8154-cbc7ccf896c7</RequestId>
+  </ResponseMetadata>
+</AssumeRoleResponse>"#,
+            access_key_id
+        )
+    }
+
+    fn request_body(request: &SignedRequest) -> String {
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => {
+                String::from_utf8_lossy(body).into_owned()
+            }
+            _ => panic!("expected a buffered payload"),
+        }
+    }
+
+    fn credentials_file() -> NamedTempFile {
+        let mut file = NamedTempFile::new().expect("temp file");
+        write!(
+            file,
+            "[base]
+aws_access_key_id = base_access_key
+aws_secret_access_key = base_secret_key
+
+[role-a]
+role_arn = arn:aws:iam::123456789012:role/a
+source_profile = base
+external_id = external-a
+
+[role-b]
+role_arn = arn:aws:iam::123456789012:role/b
+source_profile = role-a
+role_session_name = session-b
+
+[mfa]
+role_arn = arn:aws:iam::123456789012:role/mfa
+source_profile = base
+mfa_serial = arn:aws:iam::123456789012:mfa/user
+"
+        )
+        .expect("write credentials file");
+        file
+    }
+
+    #[tokio::test]
+    async fn nested_role_chain() {
+        let file = credentials_file();
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(200)
+                .with_body(&assume_role_response("role_a_access_key"))
+                .with_request_checker(|req| {
+                    let body = request_body(req);
+                    assert!(body.contains("role%2Fa"));
+                    assert!(body.contains("ExternalId=external-a"));
+                    assert!(body.contains("SourceIdentity=alice"));
+                    assert!(matches!(
+                        req.headers.get("authorization"),
+                        Some(v) if String::from_utf8_lossy(&v[0]).contains("base_access_key")
+                    ));
+                }),
+            MockRequestDispatcher::with_status(200)
+                .with_body(&assume_role_response("role_b_access_key"))
+                .with_request_checker(|req| {
+                    let body = request_body(req);
+                    assert!(body.contains("role%2Fb"));
+                    assert!(!body.contains("ExternalId"));
+                    assert!(!body.contains("SourceIdentity"));
+                    assert!(body.contains("RoleSessionName=session-b"));
+                    assert!(matches!(
+                        req.headers.get("authorization"),
+                        Some(v) if String::from_utf8_lossy(&v[0]).contains("role_a_access_key")
+                    ));
+                }),
+        ]);
+        let provider = AssumeRoleProfileProvider::with_profile_provider(
+            ProfileProvider::with_configuration(file.path(), "role-b"),
+        )
+        .with_region(Region::UsEast1)
+        .with_dispatcher(dispatcher)
+        .with_source_identity("alice");
+
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "role_b_access_key");
+        assert_eq!(creds.token(), &Some("session_token".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn profile_without_role() {
+        let file = credentials_file();
+        // no request is expected
+        let provider = AssumeRoleProfileProvider::with_profile_provider(
+            ProfileProvider::with_configuration(file.path(), "base"),
+        )
+        .with_dispatcher(MultipleMockRequestDispatcher::new(Vec::new()));
+
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "base_access_key");
+    }
+
+    #[tokio::test]
+    async fn cli_cache() {
+        let file = credentials_file();
+        let dir = tempfile::tempdir().expect("temporary directory");
+        let provider = |dispatcher| {
+            AssumeRoleProfileProvider::with_profile_provider(ProfileProvider::with_configuration(
+                file.path(),
+                "role-a",
+            ))
+            .with_dispatcher(dispatcher)
+            .with_cli_cache(CliCache::with_dir(dir.path()))
+        };
+
+        let creds = provider(MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(200)
+                .with_body(&assume_role_response("role_a_access_key")),
+        ]))
+        .credentials()
+        .await
+        .expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "role_a_access_key");
+
+        // a new provider, e.g. in the next process, finds the credentials in the cache
+        let creds = provider(MultipleMockRequestDispatcher::new(Vec::new()))
+            .credentials()
+            .await
+            .expect("cached credentials");
+        assert_eq!(creds.aws_access_key_id(), "role_a_access_key");
+        assert_eq!(creds.token(), &Some("session_token".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn mfa_code_provider() {
+        let file = credentials_file();
+        let provider = AssumeRoleProfileProvider::with_profile_provider(
+            ProfileProvider::with_configuration(file.path(), "mfa"),
+        )
+        .with_region(Region::UsEast1);
+        // without a callback, no request is made
+        assert!(provider
+            .clone()
+            .with_dispatcher(MultipleMockRequestDispatcher::new(Vec::new()))
+            .credentials()
+            .await
+            .is_err());
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let provider = provider
+            .with_dispatcher(
+                MockRequestDispatcher::with_status(200)
+                    .with_body(&assume_role_response("mfa_access_key"))
+                    .with_request_checker(|req| {
+                        let body = request_body(req);
+                        assert!(body.contains(
+                            "SerialNumber=arn%3Aaws%3Aiam%3A%3A123456789012%3Amfa%2Fuser"
+                        ));
+                        assert!(body.contains("TokenCode=123456"));
+                    }),
+            )
+            .with_mfa_code_provider(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                futures::future::ready("123456".to_owned())
+            });
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "mfa_access_key");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn web_identity_profile() {
+        let mut token_file = NamedTempFile::new().expect("temp file");
+        writeln!(token_file, "web_identity_token").expect("write token file");
+        let mut file = NamedTempFile::new().expect("temp file");
+        write!(
+            file,
+            "[web-identity]
+role_arn = arn:aws:iam::123456789012:role/web-identity
+web_identity_token_file = {}
+role_session_name = web-session
+
+[chained]
+role_arn = arn:aws:iam::123456789012:role/chained
+source_profile = web-identity
+",
+            token_file.path().display()
+        )
+        .expect("write credentials file");
+
+        let dispatcher = MultipleMockRequestDispatcher::new(vec![
+            MockRequestDispatcher::with_status(200)
+                .with_body(
+                    r#"<AssumeRoleWithWebIdentityResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleWithWebIdentityResult>
+    <Credentials>
+      <SessionToken>session_token</SessionToken>
+      <SecretAccessKey>secret_access_key</SecretAccessKey>
+      <Expiration>2100-01-01T00:00:00Z</Expiration>
+      <AccessKeyId>web_identity_access_key</AccessKeyId>
+    </Credentials>
+  </AssumeRoleWithWebIdentityResult>
+  <ResponseMetadata>
+    <RequestId>ad4156e9-bce1-11e2-82e6-6b6efEXAMPLE</RequestId>
+  </ResponseMetadata>
+</AssumeRoleWithWebIdentityResponse>"#,
+                )
+                .with_request_checker(|req| {
+                    let body = request_body(req);
+                    assert!(body.contains("Action=AssumeRoleWithWebIdentity"));
+                    assert!(body.contains("RoleSessionName=web-session"));
+                    assert!(body.contains("WebIdentityToken=web_identity_token"));
+                }),
+            MockRequestDispatcher::with_status(200)
+                .with_body(&assume_role_response("chained_access_key"))
+                .with_request_checker(|req| {
+                    assert!(request_body(req).contains("role%2Fchained"));
+                }),
+        ]);
+        let provider = AssumeRoleProfileProvider::with_profile_provider(
+            ProfileProvider::with_configuration(file.path(), "chained"),
+        )
+        .with_region(Region::UsEast1)
+        .with_dispatcher(dispatcher);
+
+        let creds = provider.credentials().await.expect("credentials");
+        assert_eq!(creds.aws_access_key_id(), "chained_access_key");
+    }
+}
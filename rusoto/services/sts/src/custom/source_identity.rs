@@ -0,0 +1,63 @@
+//! The `SourceIdentity` of assumed role sessions, which the STS model the crate is generated
+//! from predates.
+
+use std::future::Future;
+
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::{Interceptor, RequestConfigExt};
+
+/// Adds the `SourceIdentity` parameter to the `AssumeRole` or `AssumeRoleWithWebIdentity`
+/// request it hooks into.
+struct SourceIdentity(String);
+
+impl Interceptor for SourceIdentity {
+    fn before_execution(&self, request: &mut SignedRequest) {
+        // the parameters of query protocol requests are form encoded in the body
+        let mut body = match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => body.to_vec(),
+            _ => return,
+        };
+        let param = serde_urlencoded::to_string(&[("SourceIdentity", &self.0)])
+            .expect("strings can be form encoded");
+        body.push(b'&');
+        body.extend_from_slice(param.as_bytes());
+        request.set_payload(Some(body));
+    }
+}
+
+/// Runs the future making an `AssumeRole` or `AssumeRoleWithWebIdentity` request, sending the
+/// source identity with it if there is one.
+pub(crate) async fn with_source_identity<F>(future: F, source_identity: Option<String>) -> F::Output
+where
+    F: Future,
+{
+    match source_identity {
+        Some(source_identity) => {
+            future
+                .with_config(|config| {
+                    config.add_interceptor(SourceIdentity(source_identity));
+                })
+                .await
+        }
+        None => future.await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn source_identity_parameter() {
+        let mut request = SignedRequest::new("POST", "sts", &Default::default(), "/");
+        request.set_payload(Some("Action=AssumeRole&Version=2011-06-15"));
+        SourceIdentity("alice smith".to_owned()).before_execution(&mut request);
+        match request.payload {
+            Some(SignedRequestPayload::Buffer(ref body)) => assert_eq!(
+                &body[..],
+                &b"Action=AssumeRole&Version=2011-06-15&SourceIdentity=alice+smith"[..]
+            ),
+            _ => panic!("the parameters are in the body"),
+        }
+    }
+}
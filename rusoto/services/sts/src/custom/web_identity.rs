@@ -1,12 +1,19 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::custom::cli_cache::web_identity_cache_key;
 use crate::custom::credential::NewAwsCredsForStsCreds;
-use crate::{AssumeRoleWithWebIdentityRequest, Sts, StsClient, PolicyDescriptorType};
+use crate::custom::source_identity::with_source_identity;
+use crate::{AssumeRoleWithWebIdentityRequest, PolicyDescriptorType, Sts, StsClient};
 use rusoto_core::credential::{
-    AwsCredentials, CredentialsError, ProvideAwsCredentials, Secret, Variable,
+    clock, AwsCredentials, CliCache, CredentialsError, ProvideAwsCredentials, Secret, Variable,
 };
-use rusoto_core::request::HttpClient;
+use rusoto_core::request::{DispatchSignedRequest, HttpClient};
 use rusoto_core::{Client, Region};
 
 use async_trait::async_trait;
+use chrono::Duration;
+use futures::lock::Mutex;
 
 const AWS_WEB_IDENTITY_TOKEN_FILE: &str = "AWS_WEB_IDENTITY_TOKEN_FILE";
 
@@ -14,10 +21,63 @@ const AWS_ROLE_ARN: &str = "AWS_ROLE_ARN";
 
 const AWS_ROLE_SESSION_NAME: &str = "AWS_ROLE_SESSION_NAME";
 
+const POD_NAME: &str = "POD_NAME";
+
+const POD_NAMESPACE: &str = "POD_NAMESPACE";
+
+const KUBERNETES_SERVICE_HOST: &str = "KUBERNETES_SERVICE_HOST";
+
+const HOSTNAME: &str = "HOSTNAME";
+
+const K8S_NAMESPACE_FILE: &str = "/var/run/secrets/kubernetes.io/serviceaccount/namespace";
+
+// On Nitro based EC2 instances the asset tag of the board is the instance ID.
+const EC2_INSTANCE_ID_FILE: &str = "/sys/devices/virtual/dmi/id/board_asset_tag";
+
+const HOSTNAME_FILE: &str = "/etc/hostname";
+
+const DEFAULT_SESSION_NAME: &str = "WebIdentitySession";
+
+// Role session names are limited to 64 characters.
+const MAX_SESSION_NAME_LEN: usize = 64;
+
+/// Default number of seconds before expiry at which cached credentials are refreshed.
+pub const DEFAULT_REFRESH_WINDOW_SECONDS: i64 = 300;
+
+/// How a `WebIdentityProvider` reaches STS.
+#[derive(Clone)]
+enum StsConnection {
+    /// A non-signing client wrapping the shared `HttpClient`.
+    Default,
+    /// A non-signing client wrapping a user supplied dispatcher.
+    Client(Client),
+    /// A prebuilt `StsClient`, used as is.
+    Sts(StsClient),
+}
+
+impl fmt::Debug for StsConnection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StsConnection::Default => f.write_str("Default"),
+            StsConnection::Client(_) => f.write_str("Client(..)"),
+            StsConnection::Sts(_) => f.write_str("Sts(..)"),
+        }
+    }
+}
+
 /// WebIdentityProvider using OpenID Connect bearer token to retrieve AWS IAM credentials.
 ///
 /// See https://docs.aws.amazon.com/STS/latest/APIReference/API_AssumeRoleWithWebIdentity.html for
 /// more details.
+///
+/// Credentials returned by STS are cached and reused until they are about to expire, so there is
+/// no need to wrap this provider in an `AutoRefreshingProvider`. By default credentials are
+/// refreshed five minutes before their expiry time; use `with_refresh_window` to change this.
+/// Clones of a provider share the same cache. Use `with_cli_cache` to also share credentials with
+/// later processes through a `CliCache`.
+///
+/// `AssumeRoleWithWebIdentity` doesn't accept session tags: they are taken from the
+/// `https://aws.amazon.com/tags` claim of the web identity token instead.
 #[derive(Debug, Clone)]
 pub struct WebIdentityProvider {
     /// The OAuth 2.0 access token or OpenID Connect ID token that is provided by the identity provider.
@@ -39,6 +99,18 @@ pub struct WebIdentityProvider {
     pub policy: Option<String>,
     /// The Amazon Resource Names (ARNs) of the IAM managed policies that you want to use as managed session policies.
     pub policy_arns: Option<Vec<PolicyDescriptorType>>,
+    /// The source identity of the session, which can be required by the `sts:SourceIdentity`
+    /// condition key of the role's trust policy and is recorded in CloudTrail.
+    pub source_identity: Option<String>,
+
+    /// The region of the STS endpoint used for the token exchange. Use `Region::Custom` to target
+    /// a non-standard endpoint, e.g. an STS VPC endpoint. If not set, `Region::default()` is used.
+    pub region: Option<Region>,
+    /// Cached credentials are refreshed when they expire within this window.
+    pub refresh_window: Duration,
+    connection: StsConnection,
+    cached_credentials: Arc<Mutex<Option<AwsCredentials>>>,
+    cli_cache: Option<CliCache>,
 }
 
 impl WebIdentityProvider {
@@ -55,10 +127,107 @@ impl WebIdentityProvider {
             role_session_name: role_session_name.map(|v| v.into()),
             duration_seconds: None,
             policy: None,
-            policy_arns: None
+            policy_arns: None,
+            source_identity: None,
+            region: None,
+            refresh_window: Duration::seconds(DEFAULT_REFRESH_WINDOW_SECONDS),
+            connection: StsConnection::Default,
+            cached_credentials: Arc::new(Mutex::new(None)),
+            cli_cache: None,
         }
     }
 
+    /// Set the region of the STS endpoint used to exchange the web identity token.
+    pub fn with_region(mut self, region: Region) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Exchange the web identity token at a custom STS endpoint, such as a VPC endpoint or an
+    /// endpoint in a partition Rusoto doesn't know about. The name of the configured region (or
+    /// of `Region::default()`) is kept.
+    pub fn with_endpoint<E>(mut self, endpoint: E) -> Self
+    where
+        E: Into<String>,
+    {
+        let name = self.sts_region().name().to_owned();
+        self.region = Some(Region::Custom {
+            name,
+            endpoint: endpoint.into(),
+        });
+        self
+    }
+
+    /// Set the source identity of the session.
+    pub fn with_source_identity<S>(mut self, source_identity: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.source_identity = Some(source_identity.into());
+        self
+    }
+
+    /// Set an IAM policy in JSON format to use as an inline session policy.
+    pub fn with_policy<P>(mut self, policy: P) -> Self
+    where
+        P: Into<String>,
+    {
+        self.policy = Some(policy.into());
+        self
+    }
+
+    /// Add the ARN of an IAM managed policy to use as a managed session policy.
+    pub fn with_policy_arn<A>(mut self, arn: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.policy_arns
+            .get_or_insert_with(Vec::new)
+            .push(PolicyDescriptorType {
+                arn: Some(arn.into()),
+            });
+        self
+    }
+
+    /// Set the duration, in seconds, of the role session.
+    pub fn with_duration_seconds(mut self, duration_seconds: i64) -> Self {
+        self.duration_seconds = Some(duration_seconds);
+        self
+    }
+
+    /// Set how long before their expiry cached credentials are refreshed.
+    pub fn with_refresh_window(mut self, refresh_window: Duration) -> Self {
+        self.refresh_window = refresh_window;
+        self
+    }
+
+    /// Exchange the web identity token through the given dispatcher, e.g. an `HttpClient` with
+    /// custom proxy or TLS settings, or a mock in tests. The dispatcher is reused for every
+    /// exchange; requests are sent unsigned to the configured region.
+    pub fn with_dispatcher<D>(mut self, dispatcher: D) -> Self
+    where
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        self.connection = StsConnection::Client(Client::new_not_signing(dispatcher));
+        self
+    }
+
+    /// Exchange the web identity token through a prebuilt `StsClient`. The client is used as is,
+    /// so the configured region and endpoint are ignored. `AssumeRoleWithWebIdentity` doesn't
+    /// require signed requests; a client created with `Client::new_not_signing` avoids having to
+    /// resolve credentials for it.
+    pub fn with_sts_client(mut self, client: StsClient) -> Self {
+        self.connection = StsConnection::Sts(client);
+        self
+    }
+
+    /// Cache the credentials in the given `CliCache`, where they are found by later processes
+    /// assuming the same role (including the AWS CLI) until they are about to expire.
+    pub fn with_cli_cache(mut self, cache: CliCache) -> Self {
+        self.cli_cache = Some(cache);
+        self
+    }
+
     /// Creat a WebIdentityProvider from the following environment variables:
     ///
     /// - `AWS_WEB_IDENTITY_TOKEN_FILE` path to the web identity token file.
@@ -93,27 +262,39 @@ impl WebIdentityProvider {
         self.web_identity_token.resolve()
     }
 
+    /// Creates a session name identifying the workload, which enables correlation analysis in
+    /// CloudTrail. In order of preference, the name is derived from:
+    ///
+    /// - the Kubernetes pod namespace and name (`POD_NAMESPACE`/`POD_NAME` as commonly exposed
+    ///   through the downward API, or the service account namespace file and `HOSTNAME`)
+    /// - the EC2 instance ID, if running on a Nitro based EC2 instance
+    /// - the hostname
+    ///
+    /// If none of these are available, `"WebIdentitySession"` is used.
     fn create_session_name() -> String {
-        // TODO can we do better here?
-        // - Pod service account, Pod name and Pod namespace
-        // - EC2 Instance ID if available
-        // - IP address if available
-        // - ...
-        // Having some information in the session name that identifies the client would enable
-        // better correlation analysis in CloudTrail.
-        "WebIdentitySession".to_string()
+        let namespace = non_empty_env_var(POD_NAMESPACE)
+            .or_else(|| read_trimmed(K8S_NAMESPACE_FILE))
+            .filter(|_| non_empty_env_var(KUBERNETES_SERVICE_HOST).is_some());
+        let pod_name = non_empty_env_var(POD_NAME).or_else(|| non_empty_env_var(HOSTNAME));
+        let instance_id = read_trimmed(EC2_INSTANCE_ID_FILE).filter(|id| id.starts_with("i-"));
+        let hostname = non_empty_env_var(HOSTNAME).or_else(|| read_trimmed(HOSTNAME_FILE));
+        session_name_from(namespace, pod_name, instance_id, hostname)
     }
-}
 
-#[async_trait]
-impl ProvideAwsCredentials for WebIdentityProvider {
-    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
-        let http_client = match HttpClient::new() {
-            Ok(c) => c,
-            Err(e) => return Err(CredentialsError::new(e)),
-        };
-        let client = Client::new_not_signing(http_client);
-        let sts = StsClient::new_with_client(client, Region::default());
+    fn sts_region(&self) -> Region {
+        self.region.clone().unwrap_or_default()
+    }
+
+    /// Determine whether cached credentials have to be refreshed.
+    fn needs_refresh(&self, credentials: &AwsCredentials) -> bool {
+        match credentials.expires_at() {
+            Some(ref expires_at) => *expires_at <= clock::now() + self.refresh_window,
+            None => false,
+        }
+    }
+
+    /// Builds the `AssumeRoleWithWebIdentity` request from the provider's configuration.
+    fn assume_role_request(&self) -> Result<AssumeRoleWithWebIdentityRequest, CredentialsError> {
         let mut req = AssumeRoleWithWebIdentityRequest::default();
 
         req.role_arn = self.role_arn.resolve()?;
@@ -128,8 +309,34 @@ impl ProvideAwsCredentials for WebIdentityProvider {
             },
             None => Self::create_session_name(),
         };
+        Ok(req)
+    }
 
-        let assume_role = sts.assume_role_with_web_identity(req).await;
+    /// Returns the client used to call STS.
+    fn sts_client(&self) -> Result<StsClient, CredentialsError> {
+        let client = match self.connection {
+            StsConnection::Sts(ref sts) => return Ok(sts.clone()),
+            StsConnection::Client(ref client) => client.clone(),
+            StsConnection::Default => match HttpClient::shared() {
+                Ok(c) => Client::new_not_signing(c),
+                Err(e) => return Err(CredentialsError::new(e)),
+            },
+        };
+        Ok(StsClient::new_with_client(client, self.sts_region()))
+    }
+
+    /// Calls `AssumeRoleWithWebIdentity` to get fresh credentials from the STS Api.
+    async fn assume_role_with_web_identity(
+        &self,
+        req: AssumeRoleWithWebIdentityRequest,
+    ) -> Result<AwsCredentials, CredentialsError> {
+        let sts = self.sts_client()?;
+
+        let assume_role = with_source_identity(
+            sts.assume_role_with_web_identity(req),
+            self.source_identity.clone(),
+        )
+        .await;
         match assume_role {
             Err(e) => Err(CredentialsError::new(e)),
             Ok(role) => match role.credentials {
@@ -143,12 +350,108 @@ impl ProvideAwsCredentials for WebIdentityProvider {
     }
 }
 
+fn non_empty_env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+fn read_trimmed(path: &str) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
+}
+
+fn session_name_from(
+    namespace: Option<String>,
+    pod_name: Option<String>,
+    instance_id: Option<String>,
+    hostname: Option<String>,
+) -> String {
+    let name = match (namespace, pod_name, instance_id, hostname) {
+        (Some(namespace), Some(pod_name), _, _) => format!("{}.{}", namespace, pod_name),
+        (_, _, Some(instance_id), _) => instance_id,
+        (_, _, _, Some(hostname)) => hostname,
+        _ => return DEFAULT_SESSION_NAME.to_owned(),
+    };
+    sanitize_session_name(&name)
+}
+
+/// Replaces characters which are not allowed in a role session name (`[\w+=,.@-]*`) and
+/// truncates the name to the maximum length.
+fn sanitize_session_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "_+=,.@-".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .take(MAX_SESSION_NAME_LEN)
+        .collect();
+    if sanitized.len() < 2 {
+        DEFAULT_SESSION_NAME.to_owned()
+    } else {
+        sanitized
+    }
+}
+
+#[async_trait]
+impl ProvideAwsCredentials for WebIdentityProvider {
+    async fn credentials(&self) -> Result<AwsCredentials, CredentialsError> {
+        let mut cached_credentials = self.cached_credentials.lock().await;
+        if let Some(ref credentials) = *cached_credentials {
+            if !self.needs_refresh(credentials) {
+                return Ok(credentials.clone());
+            }
+        }
+        let req = self.assume_role_request()?;
+        let credentials = match self.cli_cache {
+            Some(ref cache) => {
+                let key = web_identity_cache_key(&req, self.source_identity.as_deref())?;
+                match cache.load(&key) {
+                    Some(credentials) => credentials,
+                    None => {
+                        let credentials = self.assume_role_with_web_identity(req).await?;
+                        // the credentials are still usable if they can't be cached
+                        let _ = cache.store(&key, &credentials);
+                        credentials
+                    }
+                }
+            }
+            None => self.assume_role_with_web_identity(req).await?,
+        };
+        *cached_credentials = Some(credentials.clone());
+        Ok(credentials)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Utc;
+    use rusoto_mock::{MockRequestDispatcher, MultipleMockRequestDispatcher};
     use std::io::Write;
     use tempfile::NamedTempFile;
 
+    const ASSUME_ROLE_WITH_WEB_IDENTITY_RESPONSE: &str = r#"<AssumeRoleWithWebIdentityResponse xmlns="https://sts.amazonaws.com/doc/2011-06-15/">
+  <AssumeRoleWithWebIdentityResult>
+    <Credentials>
+      <SessionToken>session_token</SessionToken>
+      <SecretAccessKey>secret_access_key</SecretAccessKey>
+      <Expiration>2100-01-01T00:00:00Z</Expiration>
+      <AccessKeyId>access_key_id</AccessKeyId>
+    </Credentials>
+  </AssumeRoleWithWebIdentityResult>
+  <ResponseMetadata>
+    <RequestId>ad4156e9-bce1-11e2-82e6-6b6efEXAMPLE</RequestId>
+  </ResponseMetadata>
+</AssumeRoleWithWebIdentityResponse>"#;
+
     #[test]
     fn api_ergonomy() {
         WebIdentityProvider::new(Secret::from("".to_string()), "", Some(Some("".to_string())));
@@ -172,4 +475,130 @@ mod tests {
         assert_eq!(token.as_ref(), TOKEN_VALUE);
         Ok(())
     }
+
+    #[test]
+    fn session_policies_and_duration() -> Result<(), CredentialsError> {
+        let provider = WebIdentityProvider::new(
+            Secret::from("token".to_string()),
+            "role",
+            Some(Some("session".to_string())),
+        )
+        .with_policy("{}")
+        .with_policy_arn("arn:aws:iam::123456789012:policy/a")
+        .with_policy_arn("arn:aws:iam::123456789012:policy/b")
+        .with_duration_seconds(7200)
+        .with_source_identity("alice");
+        let req = provider.assume_role_request()?;
+        assert_eq!(req.role_arn, "role");
+        assert_eq!(req.role_session_name, "session");
+        assert_eq!(req.web_identity_token, "token");
+        assert_eq!(req.policy, Some("{}".to_owned()));
+        assert_eq!(req.duration_seconds, Some(7200));
+        assert_eq!(provider.source_identity, Some("alice".to_owned()));
+        let arns: Vec<_> = req
+            .policy_arns
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|p| p.arn)
+            .collect();
+        assert_eq!(
+            arns,
+            vec![
+                "arn:aws:iam::123456789012:policy/a",
+                "arn:aws:iam::123456789012:policy/b"
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn region_and_endpoint() {
+        let provider =
+            WebIdentityProvider::new(Secret::from("".to_string()), "", Some(None::<String>))
+                .with_region(Region::UsGovWest1);
+        assert_eq!(provider.sts_region(), Region::UsGovWest1);
+
+        let provider =
+            provider.with_endpoint("https://vpce-1234.sts.us-gov-west-1.vpce.amazonaws.com");
+        assert_eq!(
+            provider.sts_region(),
+            Region::Custom {
+                name: "us-gov-west-1".to_owned(),
+                endpoint: "https://vpce-1234.sts.us-gov-west-1.vpce.amazonaws.com".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn session_name_sources() {
+        let s = |v: &str| Some(v.to_owned());
+        assert_eq!(
+            session_name_from(s("default"), s("web-7d9f"), s("i-0abc"), s("web-7d9f")),
+            "default.web-7d9f"
+        );
+        assert_eq!(
+            session_name_from(None, s("web-7d9f"), s("i-0abc"), s("web-7d9f")),
+            "i-0abc"
+        );
+        assert_eq!(session_name_from(None, None, None, s("my host")), "my-host");
+        assert_eq!(
+            session_name_from(None, None, None, None),
+            DEFAULT_SESSION_NAME
+        );
+        assert_eq!(
+            session_name_from(None, None, None, s("x")),
+            DEFAULT_SESSION_NAME
+        );
+        assert_eq!(
+            session_name_from(None, None, None, Some("a".repeat(100))).len(),
+            MAX_SESSION_NAME_LEN
+        );
+    }
+
+    #[test]
+    fn needs_refresh() {
+        let provider =
+            WebIdentityProvider::new(Secret::from("".to_string()), "", Some(None::<String>))
+                .with_refresh_window(Duration::seconds(60));
+        let creds = |expires_in: Option<i64>| {
+            AwsCredentials::new(
+                "",
+                "",
+                None,
+                expires_in.map(|s| Utc::now() + Duration::seconds(s)),
+            )
+        };
+        assert!(!provider.needs_refresh(&creds(None)));
+        assert!(!provider.needs_refresh(&creds(Some(3600))));
+        assert!(provider.needs_refresh(&creds(Some(30))));
+        assert!(provider.needs_refresh(&creds(Some(-30))));
+    }
+
+    #[tokio::test]
+    async fn custom_dispatcher_and_cache() -> Result<(), CredentialsError> {
+        // The dispatcher only holds a single response: a second request would panic.
+        let dispatcher =
+            MultipleMockRequestDispatcher::new(vec![MockRequestDispatcher::with_status(200)
+                .with_body(ASSUME_ROLE_WITH_WEB_IDENTITY_RESPONSE)
+                .with_request_checker(|req| {
+                    assert_eq!(req.region, Region::EuWest1);
+                    assert!(!req.headers.contains_key("authorization"));
+                })]);
+        let provider = WebIdentityProvider::new(
+            Secret::from("token".to_string()),
+            "role",
+            Some(Some("session".to_string())),
+        )
+        .with_region(Region::EuWest1)
+        .with_dispatcher(dispatcher);
+
+        let creds = provider.credentials().await?;
+        assert_eq!(creds.aws_access_key_id(), "access_key_id");
+        assert_eq!(creds.aws_secret_access_key(), "secret_access_key");
+        assert_eq!(creds.token(), &Some("session_token".to_owned()));
+
+        let cached = provider.clone().credentials().await?;
+        assert_eq!(cached.aws_access_key_id(), "access_key_id");
+        Ok(())
+    }
 }
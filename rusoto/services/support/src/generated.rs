@@ -761,29 +761,34 @@ impl AddAttachmentsToSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentLimitExceeded" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddAttachmentsToSetError::AttachmentLimitExceeded(err.msg),
+                    )
                 }
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentSetExpired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddAttachmentsToSetError::AttachmentSetExpired(err.msg),
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::AttachmentSetIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddAttachmentsToSetError::AttachmentSetIdNotFound(err.msg),
+                    )
                 }
                 "AttachmentSetSizeLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddAttachmentsToSetError::AttachmentSetSizeLimitExceeded(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AddAttachmentsToSetError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddAttachmentsToSetError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -825,24 +830,28 @@ impl AddCommunicationToCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::AttachmentSetExpired(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddCommunicationToCaseError::AttachmentSetExpired(err.msg),
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddCommunicationToCaseError::AttachmentSetIdNotFound(err.msg),
                     )
                 }
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::CaseIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddCommunicationToCaseError::CaseIdNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AddCommunicationToCaseError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddCommunicationToCaseError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -883,18 +892,28 @@ impl CreateCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentSetExpired" => {
-                    return RusotoError::Service(CreateCaseError::AttachmentSetExpired(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCaseError::AttachmentSetExpired(err.msg),
+                    )
                 }
                 "AttachmentSetIdNotFound" => {
-                    return RusotoError::Service(CreateCaseError::AttachmentSetIdNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCaseError::AttachmentSetIdNotFound(err.msg),
+                    )
                 }
                 "CaseCreationLimitExceeded" => {
-                    return RusotoError::Service(CreateCaseError::CaseCreationLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCaseError::CaseCreationLimitExceeded(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCaseError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCaseError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -931,19 +950,22 @@ impl DescribeAttachmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttachmentIdNotFound" => {
-                    return RusotoError::Service(DescribeAttachmentError::AttachmentIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAttachmentError::AttachmentIdNotFound(err.msg),
+                    )
                 }
                 "DescribeAttachmentLimitExceeded" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAttachmentError::DescribeAttachmentLimitExceeded(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAttachmentError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAttachmentError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -979,10 +1001,13 @@ impl DescribeCasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(DescribeCasesError::CaseIdNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeCasesError::CaseIdNotFound(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCasesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCasesError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1015,14 +1040,16 @@ impl DescribeCommunicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(DescribeCommunicationsError::CaseIdNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommunicationsError::CaseIdNotFound(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCommunicationsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommunicationsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1053,9 +1080,10 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeServicesError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServicesError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1085,9 +1113,10 @@ impl DescribeSeverityLevelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeSeverityLevelsError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeSeverityLevelsError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1119,7 +1148,8 @@ impl DescribeTrustedAdvisorCheckRefreshStatusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTrustedAdvisorCheckRefreshStatusesError::InternalServerError(
                             err.msg,
                         ),
@@ -1157,7 +1187,8 @@ impl DescribeTrustedAdvisorCheckResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTrustedAdvisorCheckResultError::InternalServerError(err.msg),
                     )
                 }
@@ -1193,7 +1224,8 @@ impl DescribeTrustedAdvisorCheckSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTrustedAdvisorCheckSummariesError::InternalServerError(err.msg),
                     )
                 }
@@ -1229,7 +1261,8 @@ impl DescribeTrustedAdvisorChecksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeTrustedAdvisorChecksError::InternalServerError(err.msg),
                     )
                 }
@@ -1265,7 +1298,8 @@ impl RefreshTrustedAdvisorCheckError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RefreshTrustedAdvisorCheckError::InternalServerError(err.msg),
                     )
                 }
@@ -1301,10 +1335,13 @@ impl ResolveCaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CaseIdNotFound" => {
-                    return RusotoError::Service(ResolveCaseError::CaseIdNotFound(err.msg))
+                    return RusotoError::service(&res, ResolveCaseError::CaseIdNotFound(err.msg))
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ResolveCaseError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResolveCaseError::InternalServerError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1437,6 +1474,7 @@ impl AWSSupportClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1452,6 +1490,7 @@ impl AWSSupportClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> AWSSupportClient {
         AWSSupportClient { client, region }
     }
@@ -1465,6 +1504,9 @@ impl AWSSupport for AWSSupportClient {
         input: AddAttachmentsToSetRequest,
     ) -> Result<AddAttachmentsToSetResponse, RusotoError<AddAttachmentsToSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddAttachmentsToSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSSupport_20130415.AddAttachmentsToSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1483,6 +1525,9 @@ impl AWSSupport for AWSSupportClient {
         input: AddCommunicationToCaseRequest,
     ) -> Result<AddCommunicationToCaseResponse, RusotoError<AddCommunicationToCaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AddCommunicationToCase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSSupport_20130415.AddCommunicationToCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1502,6 +1547,9 @@ impl AWSSupport for AWSSupportClient {
         input: CreateCaseRequest,
     ) -> Result<CreateCaseResponse, RusotoError<CreateCaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateCase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSSupport_20130415.CreateCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1520,6 +1568,9 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeAttachmentRequest,
     ) -> Result<DescribeAttachmentResponse, RusotoError<DescribeAttachmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAttachment");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeAttachment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1538,6 +1589,9 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeCasesRequest,
     ) -> Result<DescribeCasesResponse, RusotoError<DescribeCasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1556,6 +1610,9 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeCommunicationsRequest,
     ) -> Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeCommunications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeCommunications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1575,6 +1632,9 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1593,6 +1653,9 @@ impl AWSSupport for AWSSupportClient {
         input: DescribeSeverityLevelsRequest,
     ) -> Result<DescribeSeverityLevelsResponse, RusotoError<DescribeSeverityLevelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeSeverityLevels");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSSupport_20130415.DescribeSeverityLevels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1615,6 +1678,9 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrustedAdvisorCheckRefreshStatuses");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorCheckRefreshStatuses",
@@ -1643,6 +1709,9 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckResultError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrustedAdvisorCheckResult");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorCheckResult",
@@ -1671,6 +1740,9 @@ impl AWSSupport for AWSSupportClient {
         RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrustedAdvisorCheckSummaries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorCheckSummaries",
@@ -1697,6 +1769,9 @@ impl AWSSupport for AWSSupportClient {
     ) -> Result<DescribeTrustedAdvisorChecksResponse, RusotoError<DescribeTrustedAdvisorChecksError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTrustedAdvisorChecks");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.DescribeTrustedAdvisorChecks",
@@ -1720,6 +1795,9 @@ impl AWSSupport for AWSSupportClient {
     ) -> Result<RefreshTrustedAdvisorCheckResponse, RusotoError<RefreshTrustedAdvisorCheckError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RefreshTrustedAdvisorCheck");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSSupport_20130415.RefreshTrustedAdvisorCheck",
@@ -1742,6 +1820,9 @@ impl AWSSupport for AWSSupportClient {
         input: ResolveCaseRequest,
     ) -> Result<ResolveCaseResponse, RusotoError<ResolveCaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResolveCase");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSSupport_20130415.ResolveCase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1754,3 +1835,216 @@ impl AWSSupport for AWSSupportClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveCaseResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Support API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `AWSSupportClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AWSSupportBlockingClient {
+    client: AWSSupportClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AWSSupportBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> AWSSupportBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        AWSSupportBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AWSSupportBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AWSSupportBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> AWSSupportBlockingClient {
+        AWSSupportBlockingClient {
+            client: AWSSupportClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><p>Adds one or more attachments to an attachment set. </p> <p>An attachment set is a temporary container for attachments that you add to a case or case communication. The set is available for 1 hour after it&#39;s created. The <code>expiryTime</code> returned in the response is when the set expires. </p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::add_attachments_to_set`.
+    pub fn add_attachments_to_set(
+        &self,
+        input: AddAttachmentsToSetRequest,
+    ) -> Result<AddAttachmentsToSetResponse, RusotoError<AddAttachmentsToSetError>> {
+        self.runtime
+            .block_on(self.client.add_attachments_to_set(input))
+    }
+
+    /// <p><p>Adds additional customer communication to an AWS Support case. Use the <code>caseId</code> parameter to identify the case to which to add communication. You can list a set of email addresses to copy on the communication by using the <code>ccEmailAddresses</code> parameter. The <code>communicationBody</code> value contains the text of the communication.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::add_communication_to_case`.
+    pub fn add_communication_to_case(
+        &self,
+        input: AddCommunicationToCaseRequest,
+    ) -> Result<AddCommunicationToCaseResponse, RusotoError<AddCommunicationToCaseError>> {
+        self.runtime
+            .block_on(self.client.add_communication_to_case(input))
+    }
+
+    /// <p><p>Creates a case in the AWS Support Center. This operation is similar to how you create a case in the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page.</p> <p>The AWS Support API doesn&#39;t support requesting service limit increases. You can submit a service limit increase in the following ways: </p> <ul> <li> <p>Submit a request from the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page.</p> </li> <li> <p>Use the Service Quotas <a href="https://docs.aws.amazon.com/servicequotas/2019-06-24/apireference/API_RequestServiceQuotaIncrease.html">RequestServiceQuotaIncrease</a> operation.</p> </li> </ul> <p>A successful <code>CreateCase</code> request returns an AWS Support case number. You can use the <a>DescribeCases</a> operation and specify the case number to get existing AWS Support cases. After you create a case, use the <a>AddCommunicationToCase</a> operation to add additional communication or attachments to an existing case.</p> <p>The <code>caseId</code> is separate from the <code>displayId</code> that appears in the <a href="https://console.aws.amazon.com/support">AWS Support Center</a>. Use the <a>DescribeCases</a> operation to get the <code>displayId</code>.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::create_case`.
+    pub fn create_case(
+        &self,
+        input: CreateCaseRequest,
+    ) -> Result<CreateCaseResponse, RusotoError<CreateCaseError>> {
+        self.runtime.block_on(self.client.create_case(input))
+    }
+
+    /// <p><p>Returns the attachment that has the specified ID. Attachments can include screenshots, error logs, or other files that describe your issue. Attachment IDs are generated by the case management system when you add an attachment to a case or case communication. Attachment IDs are returned in the <a>AttachmentDetails</a> objects that are returned by the <a>DescribeCommunications</a> operation.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_attachment`.
+    pub fn describe_attachment(
+        &self,
+        input: DescribeAttachmentRequest,
+    ) -> Result<DescribeAttachmentResponse, RusotoError<DescribeAttachmentError>> {
+        self.runtime
+            .block_on(self.client.describe_attachment(input))
+    }
+
+    /// <p><p>Returns a list of cases that you specify by passing one or more case IDs. You can use the <code>afterTime</code> and <code>beforeTime</code> parameters to filter the cases by date. You can set values for the <code>includeResolvedCases</code> and <code>includeCommunications</code> parameters to specify how much information to return.</p> <p>The response returns the following in JSON format:</p> <ul> <li> <p>One or more <a href="https://docs.aws.amazon.com/awssupport/latest/APIReference/API_CaseDetails.html">CaseDetails</a> data types.</p> </li> <li> <p>One or more <code>nextToken</code> values, which specify where to paginate the returned records represented by the <code>CaseDetails</code> objects.</p> </li> </ul> <p>Case data is available for 12 months after creation. If a case was created more than 12 months ago, a request might return an error.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_cases`.
+    pub fn describe_cases(
+        &self,
+        input: DescribeCasesRequest,
+    ) -> Result<DescribeCasesResponse, RusotoError<DescribeCasesError>> {
+        self.runtime.block_on(self.client.describe_cases(input))
+    }
+
+    /// <p><p>Returns communications and attachments for one or more support cases. Use the <code>afterTime</code> and <code>beforeTime</code> parameters to filter by date. You can use the <code>caseId</code> parameter to restrict the results to a specific case.</p> <p>Case data is available for 12 months after creation. If a case was created more than 12 months ago, a request for data might cause an error.</p> <p>You can use the <code>maxResults</code> and <code>nextToken</code> parameters to control the pagination of the results. Set <code>maxResults</code> to the number of cases that you want to display on each page, and use <code>nextToken</code> to specify the resumption of pagination.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_communications`.
+    pub fn describe_communications(
+        &self,
+        input: DescribeCommunicationsRequest,
+    ) -> Result<DescribeCommunicationsResponse, RusotoError<DescribeCommunicationsError>> {
+        self.runtime
+            .block_on(self.client.describe_communications(input))
+    }
+
+    /// <p><p>Returns the current list of AWS services and a list of service categories for each service. You then use service names and categories in your <a>CreateCase</a> requests. Each AWS service has its own set of categories.</p> <p>The service codes and category codes correspond to the values that appear in the <b>Service</b> and <b>Category</b> lists on the AWS Support Center <a href="https://console.aws.amazon.com/support/home#/case/create">Create Case</a> page. The values in those fields don&#39;t necessarily match the service codes and categories returned by the <code>DescribeServices</code> operation. Always use the service codes and categories that the <code>DescribeServices</code> operation returns, so that you have the most recent set of service and category codes.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_services`.
+    pub fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.runtime.block_on(self.client.describe_services(input))
+    }
+
+    /// <p><p>Returns the list of severity levels that you can assign to an AWS Support case. The severity level for a case is also a field in the <a>CaseDetails</a> data type that you include for a <a>CreateCase</a> request.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_severity_levels`.
+    pub fn describe_severity_levels(
+        &self,
+        input: DescribeSeverityLevelsRequest,
+    ) -> Result<DescribeSeverityLevelsResponse, RusotoError<DescribeSeverityLevelsError>> {
+        self.runtime
+            .block_on(self.client.describe_severity_levels(input))
+    }
+
+    /// <p><p>Returns the refresh status of the AWS Trusted Advisor checks that have the specified check IDs. You can get the check IDs by calling the <a>DescribeTrustedAdvisorChecks</a> operation.</p> <p>Some checks are refreshed automatically, and you can&#39;t return their refresh statuses by using the <code>DescribeTrustedAdvisorCheckRefreshStatuses</code> operation. If you call this operation for these checks, you might see an <code>InvalidParameterValue</code> error.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_trusted_advisor_check_refresh_statuses`.
+    pub fn describe_trusted_advisor_check_refresh_statuses(
+        &self,
+        input: DescribeTrustedAdvisorCheckRefreshStatusesRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckRefreshStatusesResponse,
+        RusotoError<DescribeTrustedAdvisorCheckRefreshStatusesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_trusted_advisor_check_refresh_statuses(input),
+        )
+    }
+
+    /// <p><p>Returns the results of the AWS Trusted Advisor check that has the specified check ID. You can get the check IDs by calling the <a>DescribeTrustedAdvisorChecks</a> operation.</p> <p>The response contains a <a>TrustedAdvisorCheckResult</a> object, which contains these three objects:</p> <ul> <li> <p> <a>TrustedAdvisorCategorySpecificSummary</a> </p> </li> <li> <p> <a>TrustedAdvisorResourceDetail</a> </p> </li> <li> <p> <a>TrustedAdvisorResourcesSummary</a> </p> </li> </ul> <p>In addition, the response contains these fields:</p> <ul> <li> <p> <b>status</b> - The alert status of the check: &quot;ok&quot; (green), &quot;warning&quot; (yellow), &quot;error&quot; (red), or &quot;not_available&quot;.</p> </li> <li> <p> <b>timestamp</b> - The time of the last refresh of the check.</p> </li> <li> <p> <b>checkId</b> - The unique identifier for the check.</p> </li> </ul> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_trusted_advisor_check_result`.
+    pub fn describe_trusted_advisor_check_result(
+        &self,
+        input: DescribeTrustedAdvisorCheckResultRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckResultResponse,
+        RusotoError<DescribeTrustedAdvisorCheckResultError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_trusted_advisor_check_result(input))
+    }
+
+    /// <p><p>Returns the results for the AWS Trusted Advisor check summaries for the check IDs that you specified. You can get the check IDs by calling the <a>DescribeTrustedAdvisorChecks</a> operation.</p> <p>The response contains an array of <a>TrustedAdvisorCheckSummary</a> objects.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_trusted_advisor_check_summaries`.
+    pub fn describe_trusted_advisor_check_summaries(
+        &self,
+        input: DescribeTrustedAdvisorCheckSummariesRequest,
+    ) -> Result<
+        DescribeTrustedAdvisorCheckSummariesResponse,
+        RusotoError<DescribeTrustedAdvisorCheckSummariesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_trusted_advisor_check_summaries(input))
+    }
+
+    /// <p><p>Returns information about all available AWS Trusted Advisor checks, including the name, ID, category, description, and metadata. You must specify a language code. The AWS Support API currently supports English (&quot;en&quot;) and Japanese (&quot;ja&quot;). The response contains a <a>TrustedAdvisorCheckDescription</a> object for each check. You must set the AWS Region to us-east-1.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::describe_trusted_advisor_checks`.
+    pub fn describe_trusted_advisor_checks(
+        &self,
+        input: DescribeTrustedAdvisorChecksRequest,
+    ) -> Result<DescribeTrustedAdvisorChecksResponse, RusotoError<DescribeTrustedAdvisorChecksError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_trusted_advisor_checks(input))
+    }
+
+    /// <p><p>Refreshes the AWS Trusted Advisor check that you specify using the check ID. You can get the check IDs by calling the <a>DescribeTrustedAdvisorChecks</a> operation.</p> <note> <p>Some checks are refreshed automatically. If you call the <code>RefreshTrustedAdvisorCheck</code> operation to refresh them, you might see the <code>InvalidParameterValue</code> error.</p> </note> <p>The response contains a <a>TrustedAdvisorCheckRefreshStatus</a> object.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::refresh_trusted_advisor_check`.
+    pub fn refresh_trusted_advisor_check(
+        &self,
+        input: RefreshTrustedAdvisorCheckRequest,
+    ) -> Result<RefreshTrustedAdvisorCheckResponse, RusotoError<RefreshTrustedAdvisorCheckError>>
+    {
+        self.runtime
+            .block_on(self.client.refresh_trusted_advisor_check(input))
+    }
+
+    /// <p><p>Resolves a support case. This operation takes a <code>caseId</code> and returns the initial and final state of the case.</p> <note> <ul> <li> <p>You must have a Business or Enterprise support plan to use the AWS Support API. </p> </li> <li> <p>If you call the AWS Support API from an account that does not have a Business or Enterprise support plan, the <code>SubscriptionRequiredException</code> error message appears. For information about changing your support plan, see <a href="http://aws.amazon.com/premiumsupport/">AWS Support</a>.</p> </li> </ul> </note></p>
+    ///
+    /// See `AWSSupport::resolve_case`.
+    pub fn resolve_case(
+        &self,
+        input: ResolveCaseRequest,
+    ) -> Result<ResolveCaseResponse, RusotoError<ResolveCaseError>> {
+        self.runtime.block_on(self.client.resolve_case(input))
+    }
+}
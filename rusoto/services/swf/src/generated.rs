@@ -2996,12 +2996,14 @@ impl CountClosedWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountClosedWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3042,12 +3044,14 @@ impl CountOpenWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountOpenWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3086,12 +3090,14 @@ impl CountPendingActivityTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountPendingActivityTasksError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountPendingActivityTasksError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3130,12 +3136,14 @@ impl CountPendingDecisionTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountPendingDecisionTasksError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CountPendingDecisionTasksError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3176,19 +3184,22 @@ impl DeprecateActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeprecateActivityTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateActivityTypeError::TypeDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateActivityTypeError::TypeDeprecatedFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateActivityTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateActivityTypeError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3226,19 +3237,22 @@ impl DeprecateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateDomainError::DomainDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateDomainError::DomainDeprecatedFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(DeprecateDomainError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateDomainError::OperationNotPermittedFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateDomainError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateDomainError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3274,19 +3288,22 @@ impl DeprecateWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeprecateWorkflowTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(DeprecateWorkflowTypeError::TypeDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateWorkflowTypeError::TypeDeprecatedFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DeprecateWorkflowTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeprecateWorkflowTypeError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3322,14 +3339,16 @@ impl DescribeActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeActivityTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeActivityTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivityTypeError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3364,12 +3383,16 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(DescribeDomainError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainError::OperationNotPermittedFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeDomainError::UnknownResourceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3402,12 +3425,14 @@ impl DescribeWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkflowExecutionError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3446,14 +3471,16 @@ impl DescribeWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkflowTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(DescribeWorkflowTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeWorkflowTypeError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3490,12 +3517,14 @@ impl GetWorkflowExecutionHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetWorkflowExecutionHistoryError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetWorkflowExecutionHistoryError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3534,14 +3563,16 @@ impl ListActivityTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListActivityTypesError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListActivityTypesError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListActivityTypesError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3576,12 +3607,14 @@ impl ListClosedWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListClosedWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListClosedWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3618,9 +3651,10 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(ListDomainsError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainsError::OperationNotPermittedFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3654,12 +3688,14 @@ impl ListOpenWorkflowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOpenWorkflowExecutionsError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListOpenWorkflowExecutionsError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3700,19 +3736,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTagsForResourceError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3748,14 +3787,16 @@ impl ListWorkflowTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWorkflowTypesError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(ListWorkflowTypesError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListWorkflowTypesError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3790,19 +3831,22 @@ impl PollForActivityTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(PollForActivityTaskError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PollForActivityTaskError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PollForActivityTaskError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(PollForActivityTaskError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PollForActivityTaskError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3840,19 +3884,22 @@ impl PollForDecisionTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(PollForDecisionTaskError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PollForDecisionTaskError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PollForDecisionTaskError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(PollForDecisionTaskError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PollForDecisionTaskError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3890,12 +3937,14 @@ impl RecordActivityTaskHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RecordActivityTaskHeartbeatError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RecordActivityTaskHeartbeatError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -3938,24 +3987,28 @@ impl RegisterActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterActivityTypeError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterActivityTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::TypeAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterActivityTypeError::TypeAlreadyExistsFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(RegisterActivityTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterActivityTypeError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3996,20 +4049,28 @@ impl RegisterDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterDomainError::DomainAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::DomainAlreadyExistsFault(err.msg),
+                    )
                 }
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterDomainError::LimitExceededFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(RegisterDomainError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::OperationNotPermittedFault(err.msg),
+                    )
                 }
                 "TooManyTagsFault" => {
-                    return RusotoError::Service(RegisterDomainError::TooManyTagsFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterDomainError::TooManyTagsFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4048,24 +4109,28 @@ impl RegisterWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterWorkflowTypeError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWorkflowTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::TypeAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterWorkflowTypeError::TypeAlreadyExistsFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(RegisterWorkflowTypeError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterWorkflowTypeError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4104,12 +4169,14 @@ impl RequestCancelWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RequestCancelWorkflowExecutionError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RequestCancelWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4150,12 +4217,14 @@ impl RespondActivityTaskCanceledError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondActivityTaskCanceledError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondActivityTaskCanceledError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4196,12 +4265,14 @@ impl RespondActivityTaskCompletedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondActivityTaskCompletedError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondActivityTaskCompletedError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4240,12 +4311,14 @@ impl RespondActivityTaskFailedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondActivityTaskFailedError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondActivityTaskFailedError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4286,12 +4359,14 @@ impl RespondDecisionTaskCompletedError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondDecisionTaskCompletedError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RespondDecisionTaskCompletedError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4330,12 +4405,14 @@ impl SignalWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SignalWorkflowExecutionError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         SignalWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4380,32 +4457,38 @@ impl StartWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DefaultUndefinedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartWorkflowExecutionError::DefaultUndefinedFault(err.msg),
                     )
                 }
                 "LimitExceededFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::LimitExceededFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartWorkflowExecutionError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartWorkflowExecutionError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "TypeDeprecatedFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::TypeDeprecatedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartWorkflowExecutionError::TypeDeprecatedFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(StartWorkflowExecutionError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartWorkflowExecutionError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "WorkflowExecutionAlreadyStartedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartWorkflowExecutionError::WorkflowExecutionAlreadyStartedFault(err.msg),
                     )
                 }
@@ -4452,18 +4535,25 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(TagResourceError::LimitExceededFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(TagResourceError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::OperationNotPermittedFault(err.msg),
+                    )
                 }
                 "TooManyTagsFault" => {
-                    return RusotoError::Service(TagResourceError::TooManyTagsFault(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTagsFault(err.msg))
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(TagResourceError::UnknownResourceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4500,12 +4590,14 @@ impl TerminateWorkflowExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TerminateWorkflowExecutionError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         TerminateWorkflowExecutionError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4546,17 +4638,20 @@ impl UndeprecateActivityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UndeprecateActivityTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UndeprecateActivityTypeError::TypeAlreadyExistsFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UndeprecateActivityTypeError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4598,19 +4693,22 @@ impl UndeprecateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainAlreadyExistsFault" => {
-                    return RusotoError::Service(UndeprecateDomainError::DomainAlreadyExistsFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UndeprecateDomainError::DomainAlreadyExistsFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UndeprecateDomainError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(UndeprecateDomainError::UnknownResourceFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UndeprecateDomainError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4646,17 +4744,20 @@ impl UndeprecateWorkflowTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UndeprecateWorkflowTypeError::OperationNotPermittedFault(err.msg),
                     )
                 }
                 "TypeAlreadyExistsFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UndeprecateWorkflowTypeError::TypeAlreadyExistsFault(err.msg),
                     )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UndeprecateWorkflowTypeError::UnknownResourceFault(err.msg),
                     )
                 }
@@ -4698,15 +4799,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededFault" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceededFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::LimitExceededFault(err.msg),
+                    )
                 }
                 "OperationNotPermittedFault" => {
-                    return RusotoError::Service(UntagResourceError::OperationNotPermittedFault(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::OperationNotPermittedFault(err.msg),
+                    )
                 }
                 "UnknownResourceFault" => {
-                    return RusotoError::Service(UntagResourceError::UnknownResourceFault(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::UnknownResourceFault(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4969,6 +5077,7 @@ impl SwfClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4984,6 +5093,7 @@ impl SwfClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> SwfClient {
         SwfClient { client, region }
     }
@@ -4997,6 +5107,9 @@ impl Swf for SwfClient {
         input: CountClosedWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionCount, RusotoError<CountClosedWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CountClosedWorkflowExecutions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountClosedWorkflowExecutions",
@@ -5018,6 +5131,9 @@ impl Swf for SwfClient {
         input: CountOpenWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionCount, RusotoError<CountOpenWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CountOpenWorkflowExecutions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountOpenWorkflowExecutions",
@@ -5039,6 +5155,9 @@ impl Swf for SwfClient {
         input: CountPendingActivityTasksInput,
     ) -> Result<PendingTaskCount, RusotoError<CountPendingActivityTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CountPendingActivityTasks");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountPendingActivityTasks",
@@ -5060,6 +5179,9 @@ impl Swf for SwfClient {
         input: CountPendingDecisionTasksInput,
     ) -> Result<PendingTaskCount, RusotoError<CountPendingDecisionTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CountPendingDecisionTasks");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.CountPendingDecisionTasks",
@@ -5081,6 +5203,9 @@ impl Swf for SwfClient {
         input: DeprecateActivityTypeInput,
     ) -> Result<(), RusotoError<DeprecateActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeprecateActivityType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.DeprecateActivityType",
@@ -5101,6 +5226,9 @@ impl Swf for SwfClient {
         input: DeprecateDomainInput,
     ) -> Result<(), RusotoError<DeprecateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeprecateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.DeprecateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5118,6 +5246,9 @@ impl Swf for SwfClient {
         input: DeprecateWorkflowTypeInput,
     ) -> Result<(), RusotoError<DeprecateWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeprecateWorkflowType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.DeprecateWorkflowType",
@@ -5138,6 +5269,9 @@ impl Swf for SwfClient {
         input: DescribeActivityTypeInput,
     ) -> Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeActivityType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeActivityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5156,6 +5290,9 @@ impl Swf for SwfClient {
         input: DescribeDomainInput,
     ) -> Result<DomainDetail, RusotoError<DescribeDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5174,6 +5311,9 @@ impl Swf for SwfClient {
         input: DescribeWorkflowExecutionInput,
     ) -> Result<WorkflowExecutionDetail, RusotoError<DescribeWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkflowExecution");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.DescribeWorkflowExecution",
@@ -5195,6 +5335,9 @@ impl Swf for SwfClient {
         input: DescribeWorkflowTypeInput,
     ) -> Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkflowType");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SimpleWorkflowService.DescribeWorkflowType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5213,6 +5356,9 @@ impl Swf for SwfClient {
         input: GetWorkflowExecutionHistoryInput,
     ) -> Result<History, RusotoError<GetWorkflowExecutionHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetWorkflowExecutionHistory");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.GetWorkflowExecutionHistory",
@@ -5234,6 +5380,9 @@ impl Swf for SwfClient {
         input: ListActivityTypesInput,
     ) -> Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListActivityTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SimpleWorkflowService.ListActivityTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5252,6 +5401,9 @@ impl Swf for SwfClient {
         input: ListClosedWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListClosedWorkflowExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.ListClosedWorkflowExecutions",
@@ -5273,6 +5425,9 @@ impl Swf for SwfClient {
         input: ListDomainsInput,
     ) -> Result<DomainInfos, RusotoError<ListDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SimpleWorkflowService.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5291,6 +5446,9 @@ impl Swf for SwfClient {
         input: ListOpenWorkflowExecutionsInput,
     ) -> Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOpenWorkflowExecutions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.ListOpenWorkflowExecutions",
@@ -5312,6 +5470,9 @@ impl Swf for SwfClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SimpleWorkflowService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5330,6 +5491,9 @@ impl Swf for SwfClient {
         input: ListWorkflowTypesInput,
     ) -> Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWorkflowTypes");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "SimpleWorkflowService.ListWorkflowTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5348,6 +5512,9 @@ impl Swf for SwfClient {
         input: PollForActivityTaskInput,
     ) -> Result<ActivityTask, RusotoError<PollForActivityTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PollForActivityTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForActivityTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5366,6 +5533,9 @@ impl Swf for SwfClient {
         input: PollForDecisionTaskInput,
     ) -> Result<DecisionTask, RusotoError<PollForDecisionTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PollForDecisionTask");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.PollForDecisionTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5384,6 +5554,9 @@ impl Swf for SwfClient {
         input: RecordActivityTaskHeartbeatInput,
     ) -> Result<ActivityTaskStatus, RusotoError<RecordActivityTaskHeartbeatError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RecordActivityTaskHeartbeat");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RecordActivityTaskHeartbeat",
@@ -5405,6 +5578,9 @@ impl Swf for SwfClient {
         input: RegisterActivityTypeInput,
     ) -> Result<(), RusotoError<RegisterActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterActivityType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterActivityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5422,6 +5598,9 @@ impl Swf for SwfClient {
         input: RegisterDomainInput,
     ) -> Result<(), RusotoError<RegisterDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5439,6 +5618,9 @@ impl Swf for SwfClient {
         input: RegisterWorkflowTypeInput,
     ) -> Result<(), RusotoError<RegisterWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterWorkflowType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.RegisterWorkflowType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5456,6 +5638,9 @@ impl Swf for SwfClient {
         input: RequestCancelWorkflowExecutionInput,
     ) -> Result<(), RusotoError<RequestCancelWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RequestCancelWorkflowExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RequestCancelWorkflowExecution",
@@ -5476,6 +5661,9 @@ impl Swf for SwfClient {
         input: RespondActivityTaskCanceledInput,
     ) -> Result<(), RusotoError<RespondActivityTaskCanceledError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RespondActivityTaskCanceled");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondActivityTaskCanceled",
@@ -5496,6 +5684,9 @@ impl Swf for SwfClient {
         input: RespondActivityTaskCompletedInput,
     ) -> Result<(), RusotoError<RespondActivityTaskCompletedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RespondActivityTaskCompleted");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondActivityTaskCompleted",
@@ -5516,6 +5707,9 @@ impl Swf for SwfClient {
         input: RespondActivityTaskFailedInput,
     ) -> Result<(), RusotoError<RespondActivityTaskFailedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RespondActivityTaskFailed");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondActivityTaskFailed",
@@ -5536,6 +5730,9 @@ impl Swf for SwfClient {
         input: RespondDecisionTaskCompletedInput,
     ) -> Result<(), RusotoError<RespondDecisionTaskCompletedError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RespondDecisionTaskCompleted");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.RespondDecisionTaskCompleted",
@@ -5556,6 +5753,9 @@ impl Swf for SwfClient {
         input: SignalWorkflowExecutionInput,
     ) -> Result<(), RusotoError<SignalWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("SignalWorkflowExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.SignalWorkflowExecution",
@@ -5576,6 +5776,9 @@ impl Swf for SwfClient {
         input: StartWorkflowExecutionInput,
     ) -> Result<Run, RusotoError<StartWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartWorkflowExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.StartWorkflowExecution",
@@ -5597,6 +5800,9 @@ impl Swf for SwfClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5614,6 +5820,9 @@ impl Swf for SwfClient {
         input: TerminateWorkflowExecutionInput,
     ) -> Result<(), RusotoError<TerminateWorkflowExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TerminateWorkflowExecution");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.TerminateWorkflowExecution",
@@ -5634,6 +5843,9 @@ impl Swf for SwfClient {
         input: UndeprecateActivityTypeInput,
     ) -> Result<(), RusotoError<UndeprecateActivityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UndeprecateActivityType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.UndeprecateActivityType",
@@ -5654,6 +5866,9 @@ impl Swf for SwfClient {
         input: UndeprecateDomainInput,
     ) -> Result<(), RusotoError<UndeprecateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UndeprecateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.UndeprecateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5671,6 +5886,9 @@ impl Swf for SwfClient {
         input: UndeprecateWorkflowTypeInput,
     ) -> Result<(), RusotoError<UndeprecateWorkflowTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UndeprecateWorkflowType");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "SimpleWorkflowService.UndeprecateWorkflowType",
@@ -5691,6 +5909,9 @@ impl Swf for SwfClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "SimpleWorkflowService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5702,3 +5923,453 @@ impl Swf for SwfClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon SWF API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `SwfClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SwfBlockingClient {
+    client: SwfClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SwfBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> SwfBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        SwfBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SwfBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SwfBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> SwfBlockingClient {
+        SwfBlockingClient {
+            client: SwfClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Returns the number of closed workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::count_closed_workflow_executions`.
+    pub fn count_closed_workflow_executions(
+        &self,
+        input: CountClosedWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionCount, RusotoError<CountClosedWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.count_closed_workflow_executions(input))
+    }
+
+    /// <p>Returns the number of open workflow executions within the given domain that meet the specified filtering criteria.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::count_open_workflow_executions`.
+    pub fn count_open_workflow_executions(
+        &self,
+        input: CountOpenWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionCount, RusotoError<CountOpenWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.count_open_workflow_executions(input))
+    }
+
+    /// <p>Returns the estimated number of activity tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no activity task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::count_pending_activity_tasks`.
+    pub fn count_pending_activity_tasks(
+        &self,
+        input: CountPendingActivityTasksInput,
+    ) -> Result<PendingTaskCount, RusotoError<CountPendingActivityTasksError>> {
+        self.runtime
+            .block_on(self.client.count_pending_activity_tasks(input))
+    }
+
+    /// <p>Returns the estimated number of decision tasks in the specified task list. The count returned is an approximation and isn't guaranteed to be exact. If you specify a task list that no decision task was ever scheduled in then <code>0</code> is returned.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::count_pending_decision_tasks`.
+    pub fn count_pending_decision_tasks(
+        &self,
+        input: CountPendingDecisionTasksInput,
+    ) -> Result<PendingTaskCount, RusotoError<CountPendingDecisionTasksError>> {
+        self.runtime
+            .block_on(self.client.count_pending_decision_tasks(input))
+    }
+
+    /// <p>Deprecates the specified <i>activity type</i>. After an activity type has been deprecated, you cannot create new tasks of that activity type. Tasks of this type that were scheduled before the type was deprecated continue to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::deprecate_activity_type`.
+    pub fn deprecate_activity_type(
+        &self,
+        input: DeprecateActivityTypeInput,
+    ) -> Result<(), RusotoError<DeprecateActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.deprecate_activity_type(input))
+    }
+
+    /// <p>Deprecates the specified domain. After a domain has been deprecated it cannot be used to create new workflow executions or register new types. However, you can still use visibility actions on this domain. Deprecating a domain also deprecates all activity and workflow types registered in the domain. Executions that were started before the domain was deprecated continues to run.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::deprecate_domain`.
+    pub fn deprecate_domain(
+        &self,
+        input: DeprecateDomainInput,
+    ) -> Result<(), RusotoError<DeprecateDomainError>> {
+        self.runtime.block_on(self.client.deprecate_domain(input))
+    }
+
+    /// <p>Deprecates the specified <i>workflow type</i>. After a workflow type has been deprecated, you cannot create new executions of that type. Executions that were started before the type was deprecated continues to run. A deprecated workflow type may still be used when calling visibility actions.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::deprecate_workflow_type`.
+    pub fn deprecate_workflow_type(
+        &self,
+        input: DeprecateWorkflowTypeInput,
+    ) -> Result<(), RusotoError<DeprecateWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.deprecate_workflow_type(input))
+    }
+
+    /// <p>Returns information about the specified activity type. This includes configuration settings provided when the type was registered and other general information about the type.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::describe_activity_type`.
+    pub fn describe_activity_type(
+        &self,
+        input: DescribeActivityTypeInput,
+    ) -> Result<ActivityTypeDetail, RusotoError<DescribeActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.describe_activity_type(input))
+    }
+
+    /// <p>Returns information about the specified domain, including description and status.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::describe_domain`.
+    pub fn describe_domain(
+        &self,
+        input: DescribeDomainInput,
+    ) -> Result<DomainDetail, RusotoError<DescribeDomainError>> {
+        self.runtime.block_on(self.client.describe_domain(input))
+    }
+
+    /// <p>Returns information about the specified workflow execution including its type and some statistics.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::describe_workflow_execution`.
+    pub fn describe_workflow_execution(
+        &self,
+        input: DescribeWorkflowExecutionInput,
+    ) -> Result<WorkflowExecutionDetail, RusotoError<DescribeWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_workflow_execution(input))
+    }
+
+    /// <p>Returns information about the specified <i>workflow type</i>. This includes configuration settings specified when the type was registered and other information such as creation date, current status, etc.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::describe_workflow_type`.
+    pub fn describe_workflow_type(
+        &self,
+        input: DescribeWorkflowTypeInput,
+    ) -> Result<WorkflowTypeDetail, RusotoError<DescribeWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.describe_workflow_type(input))
+    }
+
+    /// <p>Returns the history of the specified workflow execution. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::get_workflow_execution_history`.
+    pub fn get_workflow_execution_history(
+        &self,
+        input: GetWorkflowExecutionHistoryInput,
+    ) -> Result<History, RusotoError<GetWorkflowExecutionHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_workflow_execution_history(input))
+    }
+
+    /// <p>Returns information about all activities registered in the specified domain that match the specified name and registration status. The result includes information like creation date, current status of the activity, etc. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the <code>nextPageToken</code> returned by the initial call.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::list_activity_types`.
+    pub fn list_activity_types(
+        &self,
+        input: ListActivityTypesInput,
+    ) -> Result<ActivityTypeInfos, RusotoError<ListActivityTypesError>> {
+        self.runtime
+            .block_on(self.client.list_activity_types(input))
+    }
+
+    /// <p>Returns a list of closed workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::list_closed_workflow_executions`.
+    pub fn list_closed_workflow_executions(
+        &self,
+        input: ListClosedWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionInfos, RusotoError<ListClosedWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_closed_workflow_executions(input))
+    }
+
+    /// <p>Returns the list of domains registered in the account. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains. The element must be set to <code>arn:aws:swf::AccountID:domain/*</code>, where <i>AccountID</i> is the account ID, with no dashes.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::list_domains`.
+    pub fn list_domains(
+        &self,
+        input: ListDomainsInput,
+    ) -> Result<DomainInfos, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// <p>Returns a list of open workflow executions in the specified domain that meet the filtering criteria. The results may be split into multiple pages. To retrieve subsequent pages, make the call again using the nextPageToken returned by the initial call.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagFilter.tag</code>: String constraint. The key is <code>swf:tagFilter.tag</code>.</p> </li> <li> <p> <code>typeFilter.name</code>: String constraint. The key is <code>swf:typeFilter.name</code>.</p> </li> <li> <p> <code>typeFilter.version</code>: String constraint. The key is <code>swf:typeFilter.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::list_open_workflow_executions`.
+    pub fn list_open_workflow_executions(
+        &self,
+        input: ListOpenWorkflowExecutionsInput,
+    ) -> Result<WorkflowExecutionInfos, RusotoError<ListOpenWorkflowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_open_workflow_executions(input))
+    }
+
+    /// <p>List tags for a given domain.</p>
+    ///
+    /// See `Swf::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns information about workflow types in the specified domain. The results may be split into multiple pages that can be retrieved by making the call repeatedly.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::list_workflow_types`.
+    pub fn list_workflow_types(
+        &self,
+        input: ListWorkflowTypesInput,
+    ) -> Result<WorkflowTypeInfos, RusotoError<ListWorkflowTypesError>> {
+        self.runtime
+            .block_on(self.client.list_workflow_types(input))
+    }
+
+    /// <p>Used by workers to get an <a>ActivityTask</a> from the specified activity <code>taskList</code>. This initiates a long poll, where the service holds the HTTP connection open and responds as soon as a task becomes available. The maximum time the service holds on to the request before responding is 60 seconds. If no task is available within 60 seconds, the poll returns an empty result. An empty result, in this context, means that an ActivityTask is returned, but that the value of taskToken is an empty string. If a task is returned, the worker should use its type to identify and process it correctly.</p> <important> <p>Workers should set their client side socket timeout to at least 70 seconds (10 seconds higher than the maximum time service may hold the poll request).</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::poll_for_activity_task`.
+    pub fn poll_for_activity_task(
+        &self,
+        input: PollForActivityTaskInput,
+    ) -> Result<ActivityTask, RusotoError<PollForActivityTaskError>> {
+        self.runtime
+            .block_on(self.client.poll_for_activity_task(input))
+    }
+
+    /// <p>Used by deciders to get a <a>DecisionTask</a> from the specified decision <code>taskList</code>. A decision task may be returned for any open workflow execution that is using the specified task list. The task includes a paginated view of the history of the workflow execution. The decider should use the workflow type and the history to determine how to properly handle the task.</p> <p>This action initiates a long poll, where the service holds the HTTP connection open and responds as soon a task becomes available. If no decision task is available in the specified task list before the timeout of 60 seconds expires, an empty result is returned. An empty result, in this context, means that a DecisionTask is returned, but that the value of taskToken is an empty string.</p> <important> <p>Deciders should set their client side socket timeout to at least 70 seconds (10 seconds higher than the timeout).</p> </important> <important> <p>Because the number of workflow history events for a single workflow execution might be very large, the result returned might be split up across a number of pages. To retrieve subsequent pages, make additional calls to <code>PollForDecisionTask</code> using the <code>nextPageToken</code> returned by the initial call. Note that you do <i>not</i> call <code>GetWorkflowExecutionHistory</code> with this <code>nextPageToken</code>. Instead, call <code>PollForDecisionTask</code> again.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the <code>taskList.name</code> parameter by using a <code>Condition</code> element with the <code>swf:taskList.name</code> key to allow the action to access only certain task lists.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::poll_for_decision_task`.
+    pub fn poll_for_decision_task(
+        &self,
+        input: PollForDecisionTaskInput,
+    ) -> Result<DecisionTask, RusotoError<PollForDecisionTaskError>> {
+        self.runtime
+            .block_on(self.client.poll_for_decision_task(input))
+    }
+
+    /// <p>Used by activity workers to report to the service that the <a>ActivityTask</a> represented by the specified <code>taskToken</code> is still making progress. The worker can also specify details of the progress, for example percent complete, using the <code>details</code> parameter. This action can also be used by the worker as a mechanism to check if cancellation is being requested for the activity task. If a cancellation is being attempted for the specified task, then the boolean <code>cancelRequested</code> flag returned by the service is set to <code>true</code>.</p> <p>This action resets the <code>taskHeartbeatTimeout</code> clock. The <code>taskHeartbeatTimeout</code> is specified in <a>RegisterActivityType</a>.</p> <p>This action doesn't in itself create an event in the workflow execution history. However, if the task times out, the workflow execution history contains a <code>ActivityTaskTimedOut</code> event that contains the information from the last heartbeat generated by the activity worker.</p> <note> <p>The <code>taskStartToCloseTimeout</code> of an activity type is the maximum duration of an activity task, regardless of the number of <a>RecordActivityTaskHeartbeat</a> requests received. The <code>taskStartToCloseTimeout</code> is also specified in <a>RegisterActivityType</a>.</p> </note> <note> <p>This operation is only useful for long-lived activities to report liveliness of the task and to determine if a cancellation is being attempted.</p> </note> <important> <p>If the <code>cancelRequested</code> flag returns <code>true</code>, a cancellation is being attempted. If the worker can cancel the activity, it should respond with <a>RespondActivityTaskCanceled</a>. Otherwise, it should ignore the cancellation request.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::record_activity_task_heartbeat`.
+    pub fn record_activity_task_heartbeat(
+        &self,
+        input: RecordActivityTaskHeartbeatInput,
+    ) -> Result<ActivityTaskStatus, RusotoError<RecordActivityTaskHeartbeatError>> {
+        self.runtime
+            .block_on(self.client.record_activity_task_heartbeat(input))
+    }
+
+    /// <p>Registers a new <i>activity type</i> along with its configuration settings in the specified domain.</p> <important> <p>A <code>TypeAlreadyExists</code> fault is returned if the type already exists in the domain. You cannot change any configuration settings of the type after its registration, and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::register_activity_type`.
+    pub fn register_activity_type(
+        &self,
+        input: RegisterActivityTypeInput,
+    ) -> Result<(), RusotoError<RegisterActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.register_activity_type(input))
+    }
+
+    /// <p>Registers a new domain.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>You cannot use an IAM policy to control domain access for this action. The name of the domain being registered is available as the resource of this action.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::register_domain`.
+    pub fn register_domain(
+        &self,
+        input: RegisterDomainInput,
+    ) -> Result<(), RusotoError<RegisterDomainError>> {
+        self.runtime.block_on(self.client.register_domain(input))
+    }
+
+    /// <p>Registers a new <i>workflow type</i> and its configuration settings in the specified domain.</p> <p>The retention period for the workflow history is set by the <a>RegisterDomain</a> action.</p> <important> <p>If the type already exists, then a <code>TypeAlreadyExists</code> fault is returned. You cannot change the configuration settings of a workflow type once it is registered and it must be registered as a new version.</p> </important> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>defaultTaskList.name</code>: String constraint. The key is <code>swf:defaultTaskList.name</code>.</p> </li> <li> <p> <code>name</code>: String constraint. The key is <code>swf:name</code>.</p> </li> <li> <p> <code>version</code>: String constraint. The key is <code>swf:version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::register_workflow_type`.
+    pub fn register_workflow_type(
+        &self,
+        input: RegisterWorkflowTypeInput,
+    ) -> Result<(), RusotoError<RegisterWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.register_workflow_type(input))
+    }
+
+    /// <p>Records a <code>WorkflowExecutionCancelRequested</code> event in the currently running workflow execution identified by the given domain, workflowId, and runId. This logically requests the cancellation of the workflow execution as a whole. It is up to the decider to take appropriate actions when it receives an execution history with this event.</p> <note> <p>If the runId isn't specified, the <code>WorkflowExecutionCancelRequested</code> event is recorded in the history of the current open workflow execution with the specified workflowId in the domain.</p> </note> <note> <p>Because this action allows the workflow to properly clean up and gracefully close, it should be used instead of <a>TerminateWorkflowExecution</a> when possible.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::request_cancel_workflow_execution`.
+    pub fn request_cancel_workflow_execution(
+        &self,
+        input: RequestCancelWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<RequestCancelWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.request_cancel_workflow_execution(input))
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> was successfully canceled. Additional <code>details</code> can be provided using the <code>details</code> argument.</p> <p>These <code>details</code> (if provided) appear in the <code>ActivityTaskCanceled</code> event added to the workflow history.</p> <important> <p>Only use this operation if the <code>canceled</code> flag of a <a>RecordActivityTaskHeartbeat</a> request returns <code>true</code> and if the activity can be safely undone or abandoned.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, RespondActivityTaskCanceled, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::respond_activity_task_canceled`.
+    pub fn respond_activity_task_canceled(
+        &self,
+        input: RespondActivityTaskCanceledInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskCanceledError>> {
+        self.runtime
+            .block_on(self.client.respond_activity_task_canceled(input))
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> completed successfully with a <code>result</code> (if provided). The <code>result</code> appears in the <code>ActivityTaskCompleted</code> event in the workflow history.</p> <important> <p>If the requested task doesn't complete successfully, use <a>RespondActivityTaskFailed</a> instead. If the worker finds that the task is canceled through the <code>canceled</code> flag returned by <a>RecordActivityTaskHeartbeat</a>, it should cancel the task, clean up and then call <a>RespondActivityTaskCanceled</a>.</p> </important> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to RespondActivityTaskCompleted, <a>RespondActivityTaskCanceled</a>, <a>RespondActivityTaskFailed</a>, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::respond_activity_task_completed`.
+    pub fn respond_activity_task_completed(
+        &self,
+        input: RespondActivityTaskCompletedInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskCompletedError>> {
+        self.runtime
+            .block_on(self.client.respond_activity_task_completed(input))
+    }
+
+    /// <p>Used by workers to tell the service that the <a>ActivityTask</a> identified by the <code>taskToken</code> has failed with <code>reason</code> (if specified). The <code>reason</code> and <code>details</code> appear in the <code>ActivityTaskFailed</code> event added to the workflow history.</p> <p>A task is considered open from the time that it is scheduled until it is closed. Therefore a task is reported as open while a worker is processing it. A task is closed after it has been specified in a call to <a>RespondActivityTaskCompleted</a>, <a>RespondActivityTaskCanceled</a>, RespondActivityTaskFailed, or the task has <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dg-basic.html#swf-dev-timeout-types">timed out</a>.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::respond_activity_task_failed`.
+    pub fn respond_activity_task_failed(
+        &self,
+        input: RespondActivityTaskFailedInput,
+    ) -> Result<(), RusotoError<RespondActivityTaskFailedError>> {
+        self.runtime
+            .block_on(self.client.respond_activity_task_failed(input))
+    }
+
+    /// <p>Used by deciders to tell the service that the <a>DecisionTask</a> identified by the <code>taskToken</code> has successfully completed. The <code>decisions</code> argument specifies the list of decisions made while processing the task.</p> <p>A <code>DecisionTaskCompleted</code> event is added to the workflow history. The <code>executionContext</code> specified is attached to the event in the workflow execution history.</p> <p> <b>Access Control</b> </p> <p>If an IAM policy grants permission to use <code>RespondDecisionTaskCompleted</code>, it can express permissions for the list of decisions in the <code>decisions</code> parameter. Each of the decisions has one or more parameters, much like a regular API call. To allow for policies to be as readable as possible, you can express permissions on decisions as if they were actual API calls, including applying conditions to some parameters. For more information, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::respond_decision_task_completed`.
+    pub fn respond_decision_task_completed(
+        &self,
+        input: RespondDecisionTaskCompletedInput,
+    ) -> Result<(), RusotoError<RespondDecisionTaskCompletedError>> {
+        self.runtime
+            .block_on(self.client.respond_decision_task_completed(input))
+    }
+
+    /// <p>Records a <code>WorkflowExecutionSignaled</code> event in the workflow execution history and creates a decision task for the workflow execution identified by the given domain, workflowId and runId. The event is recorded with the specified user defined signalName and input (if provided).</p> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionSignaled</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>If the specified workflow execution isn't open, this method fails with <code>UnknownResource</code>.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::signal_workflow_execution`.
+    pub fn signal_workflow_execution(
+        &self,
+        input: SignalWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<SignalWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.signal_workflow_execution(input))
+    }
+
+    /// <p>Starts an execution of the workflow type in the specified domain using the provided <code>workflowId</code> and input data.</p> <p>This action returns the newly started workflow execution.</p> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>tagList.member.0</code>: The key is <code>swf:tagList.member.0</code>.</p> </li> <li> <p> <code>tagList.member.1</code>: The key is <code>swf:tagList.member.1</code>.</p> </li> <li> <p> <code>tagList.member.2</code>: The key is <code>swf:tagList.member.2</code>.</p> </li> <li> <p> <code>tagList.member.3</code>: The key is <code>swf:tagList.member.3</code>.</p> </li> <li> <p> <code>tagList.member.4</code>: The key is <code>swf:tagList.member.4</code>.</p> </li> <li> <p> <code>taskList</code>: String constraint. The key is <code>swf:taskList.name</code>.</p> </li> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::start_workflow_execution`.
+    pub fn start_workflow_execution(
+        &self,
+        input: StartWorkflowExecutionInput,
+    ) -> Result<Run, RusotoError<StartWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_workflow_execution(input))
+    }
+
+    /// <p><p>Add a tag to a Amazon SWF domain.</p> <note> <p>Amazon SWF supports a maximum of 50 tags per resource.</p> </note></p>
+    ///
+    /// See `Swf::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Records a <code>WorkflowExecutionTerminated</code> event and forces closure of the workflow execution identified by the given domain, runId, and workflowId. The child policy, registered with the workflow type or specified when starting this execution, is applied to any open child workflow executions of this workflow execution.</p> <important> <p>If the identified workflow execution was in progress, it is terminated immediately.</p> </important> <note> <p>If a runId isn't specified, then the <code>WorkflowExecutionTerminated</code> event is recorded in the history of the current open workflow with the matching workflowId in the domain.</p> </note> <note> <p>You should consider using <a>RequestCancelWorkflowExecution</a> action instead because it allows the workflow to gracefully close while <a>TerminateWorkflowExecution</a> doesn't.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::terminate_workflow_execution`.
+    pub fn terminate_workflow_execution(
+        &self,
+        input: TerminateWorkflowExecutionInput,
+    ) -> Result<(), RusotoError<TerminateWorkflowExecutionError>> {
+        self.runtime
+            .block_on(self.client.terminate_workflow_execution(input))
+    }
+
+    /// <p>Undeprecates a previously deprecated <i>activity type</i>. After an activity type has been undeprecated, you can create new tasks of that activity type.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>activityType.name</code>: String constraint. The key is <code>swf:activityType.name</code>.</p> </li> <li> <p> <code>activityType.version</code>: String constraint. The key is <code>swf:activityType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::undeprecate_activity_type`.
+    pub fn undeprecate_activity_type(
+        &self,
+        input: UndeprecateActivityTypeInput,
+    ) -> Result<(), RusotoError<UndeprecateActivityTypeError>> {
+        self.runtime
+            .block_on(self.client.undeprecate_activity_type(input))
+    }
+
+    /// <p>Undeprecates a previously deprecated domain. After a domain has been undeprecated it can be used to create new workflow executions or register new types.</p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>You cannot use an IAM policy to constrain this action's parameters.</p> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::undeprecate_domain`.
+    pub fn undeprecate_domain(
+        &self,
+        input: UndeprecateDomainInput,
+    ) -> Result<(), RusotoError<UndeprecateDomainError>> {
+        self.runtime.block_on(self.client.undeprecate_domain(input))
+    }
+
+    /// <p>Undeprecates a previously deprecated <i>workflow type</i>. After a workflow type has been undeprecated, you can create new executions of that type. </p> <note> <p>This operation is eventually consistent. The results are best effort and may not exactly reflect recent updates and changes.</p> </note> <p> <b>Access Control</b> </p> <p>You can use IAM policies to control this action's access to Amazon SWF resources as follows:</p> <ul> <li> <p>Use a <code>Resource</code> element with the domain name to limit the action to only specified domains.</p> </li> <li> <p>Use an <code>Action</code> element to allow or deny permission to call this action.</p> </li> <li> <p>Constrain the following parameters by using a <code>Condition</code> element with the appropriate keys.</p> <ul> <li> <p> <code>workflowType.name</code>: String constraint. The key is <code>swf:workflowType.name</code>.</p> </li> <li> <p> <code>workflowType.version</code>: String constraint. The key is <code>swf:workflowType.version</code>.</p> </li> </ul> </li> </ul> <p>If the caller doesn't have sufficient permissions to invoke the action, or the parameter values fall outside the specified constraints, the action fails. The associated event attribute's <code>cause</code> parameter is set to <code>OPERATION_NOT_PERMITTED</code>. For details and example IAM policies, see <a href="https://docs.aws.amazon.com/amazonswf/latest/developerguide/swf-dev-iam.html">Using IAM to Manage Access to Amazon SWF Workflows</a> in the <i>Amazon SWF Developer Guide</i>.</p>
+    ///
+    /// See `Swf::undeprecate_workflow_type`.
+    pub fn undeprecate_workflow_type(
+        &self,
+        input: UndeprecateWorkflowTypeInput,
+    ) -> Result<(), RusotoError<UndeprecateWorkflowTypeError>> {
+        self.runtime
+            .block_on(self.client.undeprecate_workflow_type(input))
+    }
+
+    /// <p>Remove a tag from a Amazon SWF domain.</p>
+    ///
+    /// See `Swf::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
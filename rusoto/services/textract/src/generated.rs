@@ -549,38 +549,55 @@ impl AnalyzeDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AnalyzeDocumentError::AccessDenied(err.msg))
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::BadDocument(err.msg))
+                    return RusotoError::service(&res, AnalyzeDocumentError::BadDocument(err.msg))
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::DocumentTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AnalyzeDocumentError::DocumentTooLarge(err.msg),
+                    )
                 }
                 "HumanLoopQuotaExceededException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::HumanLoopQuotaExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AnalyzeDocumentError::HumanLoopQuotaExceeded(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AnalyzeDocumentError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AnalyzeDocumentError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AnalyzeDocumentError::InvalidS3Object(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AnalyzeDocumentError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::Throttling(err.msg))
+                    return RusotoError::service(&res, AnalyzeDocumentError::Throttling(err.msg))
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(AnalyzeDocumentError::UnsupportedDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AnalyzeDocumentError::UnsupportedDocument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -637,37 +654,55 @@ impl DetectDocumentTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectDocumentTextError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectDocumentTextError::AccessDenied(err.msg),
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(DetectDocumentTextError::BadDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectDocumentTextError::BadDocument(err.msg),
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(DetectDocumentTextError::DocumentTooLarge(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectDocumentTextError::DocumentTooLarge(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectDocumentTextError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectDocumentTextError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectDocumentTextError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectDocumentTextError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectDocumentTextError::InvalidS3Object(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DetectDocumentTextError::InvalidS3Object(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DetectDocumentTextError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectDocumentTextError::Throttling(err.msg))
+                    return RusotoError::service(&res, DetectDocumentTextError::Throttling(err.msg))
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(DetectDocumentTextError::UnsupportedDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DetectDocumentTextError::UnsupportedDocument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -717,28 +752,40 @@ impl GetDocumentAnalysisError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentAnalysisError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentAnalysisError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidJobIdException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InvalidJobId(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentAnalysisError::InvalidJobId(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentAnalysisError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDocumentAnalysisError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDocumentAnalysisError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentAnalysisError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -785,32 +832,40 @@ impl GetDocumentTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentTextDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDocumentTextDetectionError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidJobIdException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::InvalidJobId(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentTextDetectionError::InvalidJobId(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentTextDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDocumentTextDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDocumentTextDetectionError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentTextDetectionError::Throttling(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -867,51 +922,70 @@ impl StartDocumentAnalysisError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::AccessDenied(err.msg),
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::BadDocument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::BadDocument(err.msg),
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::DocumentTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::DocumentTooLarge(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentAnalysisError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentAnalysisError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::Throttling(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(StartDocumentAnalysisError::UnsupportedDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentAnalysisError::UnsupportedDocument(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -977,57 +1051,68 @@ impl StartDocumentTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentTextDetectionError::AccessDenied(err.msg),
+                    )
                 }
                 "BadDocumentException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::BadDocument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentTextDetectionError::BadDocument(err.msg),
+                    )
                 }
                 "DocumentTooLargeException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::DocumentTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentTextDetectionError::DocumentTooLarge(err.msg),
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentTextDetectionError::IdempotentParameterMismatch(err.msg),
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentTextDetectionError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentTextDetectionError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::InvalidS3Object(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentTextDetectionError::InvalidS3Object(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentTextDetectionError::LimitExceeded(err.msg),
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentTextDetectionError::ProvisionedThroughputExceeded(err.msg),
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDocumentTextDetectionError::Throttling(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartDocumentTextDetectionError::Throttling(err.msg),
+                    )
                 }
                 "UnsupportedDocumentException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartDocumentTextDetectionError::UnsupportedDocument(err.msg),
                     )
                 }
@@ -1122,6 +1207,7 @@ impl TextractClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1137,6 +1223,7 @@ impl TextractClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> TextractClient {
         TextractClient { client, region }
     }
@@ -1150,6 +1237,9 @@ impl Textract for TextractClient {
         input: AnalyzeDocumentRequest,
     ) -> Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AnalyzeDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Textract.AnalyzeDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1168,6 +1258,9 @@ impl Textract for TextractClient {
         input: DetectDocumentTextRequest,
     ) -> Result<DetectDocumentTextResponse, RusotoError<DetectDocumentTextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DetectDocumentText");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Textract.DetectDocumentText");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1186,6 +1279,9 @@ impl Textract for TextractClient {
         input: GetDocumentAnalysisRequest,
     ) -> Result<GetDocumentAnalysisResponse, RusotoError<GetDocumentAnalysisError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDocumentAnalysis");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Textract.GetDocumentAnalysis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1204,6 +1300,9 @@ impl Textract for TextractClient {
         input: GetDocumentTextDetectionRequest,
     ) -> Result<GetDocumentTextDetectionResponse, RusotoError<GetDocumentTextDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDocumentTextDetection");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Textract.GetDocumentTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1223,6 +1322,9 @@ impl Textract for TextractClient {
         input: StartDocumentAnalysisRequest,
     ) -> Result<StartDocumentAnalysisResponse, RusotoError<StartDocumentAnalysisError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDocumentAnalysis");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Textract.StartDocumentAnalysis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1243,6 +1345,9 @@ impl Textract for TextractClient {
     ) -> Result<StartDocumentTextDetectionResponse, RusotoError<StartDocumentTextDetectionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartDocumentTextDetection");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Textract.StartDocumentTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1256,3 +1361,119 @@ impl Textract for TextractClient {
             .deserialize::<StartDocumentTextDetectionResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Textract API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `TextractClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct TextractBlockingClient {
+    client: TextractClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl TextractBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> TextractBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        TextractBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TextractBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TextractBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> TextractBlockingClient {
+        TextractBlockingClient {
+            client: TextractClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Analyzes an input document for relationships between detected items. </p> <p>The types of information returned are as follows: </p> <ul> <li> <p>Form data (key-value pairs). The related information is returned in two <a>Block</a> objects, each of type <code>KEY_VALUE_SET</code>: a KEY <code>Block</code> object and a VALUE <code>Block</code> object. For example, <i>Name: Ana Silva Carolina</i> contains a key and value. <i>Name:</i> is the key. <i>Ana Silva Carolina</i> is the value.</p> </li> <li> <p>Table and table cell data. A TABLE <code>Block</code> object contains information about a detected table. A CELL <code>Block</code> object is returned for each cell in a table.</p> </li> <li> <p>Lines and words of text. A LINE <code>Block</code> object contains one or more WORD <code>Block</code> objects. All lines and words that are detected in the document are returned (including text that doesn't have a relationship with the value of <code>FeatureTypes</code>). </p> </li> </ul> <p>Selection elements such as check boxes and option buttons (radio buttons) can be detected in form data and in tables. A SELECTION_ELEMENT <code>Block</code> object contains information about a selection element, including the selection status.</p> <p>You can choose which type of analysis to perform by specifying the <code>FeatureTypes</code> list. </p> <p>The output is returned in a list of <code>Block</code> objects.</p> <p> <code>AnalyzeDocument</code> is a synchronous operation. To analyze documents asynchronously, use <a>StartDocumentAnalysis</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
+    ///
+    /// See `Textract::analyze_document`.
+    pub fn analyze_document(
+        &self,
+        input: AnalyzeDocumentRequest,
+    ) -> Result<AnalyzeDocumentResponse, RusotoError<AnalyzeDocumentError>> {
+        self.runtime.block_on(self.client.analyze_document(input))
+    }
+
+    /// <p>Detects text in the input document. Amazon Textract can detect lines of text and the words that make up a line of text. The input document must be an image in JPEG or PNG format. <code>DetectDocumentText</code> returns the detected text in an array of <a>Block</a> objects. </p> <p>Each document page has as an associated <code>Block</code> of type PAGE. Each PAGE <code>Block</code> object is the parent of LINE <code>Block</code> objects that represent the lines of detected text on a page. A LINE <code>Block</code> object is a parent for each word that makes up the line. Words are represented by <code>Block</code> objects of type WORD.</p> <p> <code>DetectDocumentText</code> is a synchronous operation. To analyze documents asynchronously, use <a>StartDocumentTextDetection</a>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
+    ///
+    /// See `Textract::detect_document_text`.
+    pub fn detect_document_text(
+        &self,
+        input: DetectDocumentTextRequest,
+    ) -> Result<DetectDocumentTextResponse, RusotoError<DetectDocumentTextError>> {
+        self.runtime
+            .block_on(self.client.detect_document_text(input))
+    }
+
+    /// <p>Gets the results for an Amazon Textract asynchronous operation that analyzes text in a document.</p> <p>You start asynchronous text analysis by calling <a>StartDocumentAnalysis</a>, which returns a job identifier (<code>JobId</code>). When the text analysis operation finishes, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that's registered in the initial call to <code>StartDocumentAnalysis</code>. To get the results of the text-detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetDocumentAnalysis</code>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentAnalysis</code>.</p> <p> <code>GetDocumentAnalysis</code> returns an array of <a>Block</a> objects. The following types of information are returned: </p> <ul> <li> <p>Form data (key-value pairs). The related information is returned in two <a>Block</a> objects, each of type <code>KEY_VALUE_SET</code>: a KEY <code>Block</code> object and a VALUE <code>Block</code> object. For example, <i>Name: Ana Silva Carolina</i> contains a key and value. <i>Name:</i> is the key. <i>Ana Silva Carolina</i> is the value.</p> </li> <li> <p>Table and table cell data. A TABLE <code>Block</code> object contains information about a detected table. A CELL <code>Block</code> object is returned for each cell in a table.</p> </li> <li> <p>Lines and words of text. A LINE <code>Block</code> object contains one or more WORD <code>Block</code> objects. All lines and words that are detected in the document are returned (including text that doesn't have a relationship with the value of the <code>StartDocumentAnalysis</code> <code>FeatureTypes</code> input parameter). </p> </li> </ul> <p>Selection elements such as check boxes and option buttons (radio buttons) can be detected in form data and in tables. A SELECTION_ELEMENT <code>Block</code> object contains information about a selection element, including the selection status.</p> <p>Use the <code>MaxResults</code> parameter to limit the number of blocks that are returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetDocumentAnalysis</code>, and populate the <code>NextToken</code> request parameter with the token value that's returned from the previous call to <code>GetDocumentAnalysis</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
+    ///
+    /// See `Textract::get_document_analysis`.
+    pub fn get_document_analysis(
+        &self,
+        input: GetDocumentAnalysisRequest,
+    ) -> Result<GetDocumentAnalysisResponse, RusotoError<GetDocumentAnalysisError>> {
+        self.runtime
+            .block_on(self.client.get_document_analysis(input))
+    }
+
+    /// <p>Gets the results for an Amazon Textract asynchronous operation that detects text in a document. Amazon Textract can detect lines of text and the words that make up a line of text.</p> <p>You start asynchronous text detection by calling <a>StartDocumentTextDetection</a>, which returns a job identifier (<code>JobId</code>). When the text detection operation finishes, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that's registered in the initial call to <code>StartDocumentTextDetection</code>. To get the results of the text-detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <code>GetDocumentTextDetection</code>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentTextDetection</code>.</p> <p> <code>GetDocumentTextDetection</code> returns an array of <a>Block</a> objects. </p> <p>Each document page has as an associated <code>Block</code> of type PAGE. Each PAGE <code>Block</code> object is the parent of LINE <code>Block</code> objects that represent the lines of detected text on a page. A LINE <code>Block</code> object is a parent for each word that makes up the line. Words are represented by <code>Block</code> objects of type WORD.</p> <p>Use the MaxResults parameter to limit the number of blocks that are returned. If there are more results than specified in <code>MaxResults</code>, the value of <code>NextToken</code> in the operation response contains a pagination token for getting the next set of results. To get the next page of results, call <code>GetDocumentTextDetection</code>, and populate the <code>NextToken</code> request parameter with the token value that's returned from the previous call to <code>GetDocumentTextDetection</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
+    ///
+    /// See `Textract::get_document_text_detection`.
+    pub fn get_document_text_detection(
+        &self,
+        input: GetDocumentTextDetectionRequest,
+    ) -> Result<GetDocumentTextDetectionResponse, RusotoError<GetDocumentTextDetectionError>> {
+        self.runtime
+            .block_on(self.client.get_document_text_detection(input))
+    }
+
+    /// <p>Starts the asynchronous analysis of an input document for relationships between detected items such as key-value pairs, tables, and selection elements.</p> <p> <code>StartDocumentAnalysis</code> can analyze text in documents that are in JPEG, PNG, and PDF format. The documents are stored in an Amazon S3 bucket. Use <a>DocumentLocation</a> to specify the bucket name and file name of the document. </p> <p> <code>StartDocumentAnalysis</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When text analysis is finished, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that you specify in <code>NotificationChannel</code>. To get the results of the text analysis operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetDocumentAnalysis</a>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentAnalysis</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-analyzing.html">Document Text Analysis</a>.</p>
+    ///
+    /// See `Textract::start_document_analysis`.
+    pub fn start_document_analysis(
+        &self,
+        input: StartDocumentAnalysisRequest,
+    ) -> Result<StartDocumentAnalysisResponse, RusotoError<StartDocumentAnalysisError>> {
+        self.runtime
+            .block_on(self.client.start_document_analysis(input))
+    }
+
+    /// <p>Starts the asynchronous detection of text in a document. Amazon Textract can detect lines of text and the words that make up a line of text.</p> <p> <code>StartDocumentTextDetection</code> can analyze text in documents that are in JPEG, PNG, and PDF format. The documents are stored in an Amazon S3 bucket. Use <a>DocumentLocation</a> to specify the bucket name and file name of the document. </p> <p> <code>StartTextDetection</code> returns a job identifier (<code>JobId</code>) that you use to get the results of the operation. When text detection is finished, Amazon Textract publishes a completion status to the Amazon Simple Notification Service (Amazon SNS) topic that you specify in <code>NotificationChannel</code>. To get the results of the text detection operation, first check that the status value published to the Amazon SNS topic is <code>SUCCEEDED</code>. If so, call <a>GetDocumentTextDetection</a>, and pass the job identifier (<code>JobId</code>) from the initial call to <code>StartDocumentTextDetection</code>.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/textract/latest/dg/how-it-works-detecting.html">Document Text Detection</a>.</p>
+    ///
+    /// See `Textract::start_document_text_detection`.
+    pub fn start_document_text_detection(
+        &self,
+        input: StartDocumentTextDetectionRequest,
+    ) -> Result<StartDocumentTextDetectionResponse, RusotoError<StartDocumentTextDetectionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_document_text_detection(input))
+    }
+}
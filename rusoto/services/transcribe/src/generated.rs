@@ -1127,20 +1127,28 @@ impl CreateMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMedicalVocabularyError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateMedicalVocabularyError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMedicalVocabularyError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMedicalVocabularyError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateMedicalVocabularyError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1179,16 +1187,22 @@ impl CreateVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, CreateVocabularyError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVocabularyError::Conflict(err.msg))
+                    return RusotoError::service(&res, CreateVocabularyError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVocabularyError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVocabularyError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1227,20 +1241,28 @@ impl CreateVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVocabularyFilterError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateVocabularyFilterError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVocabularyFilterError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVocabularyFilterError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateVocabularyFilterError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1279,19 +1301,22 @@ impl DeleteMedicalTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMedicalTranscriptionJobError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMedicalTranscriptionJobError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteMedicalTranscriptionJobError::InternalFailure(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteMedicalTranscriptionJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMedicalTranscriptionJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1331,20 +1356,28 @@ impl DeleteMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMedicalVocabularyError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMedicalVocabularyError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMedicalVocabularyError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMedicalVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMedicalVocabularyError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1381,17 +1414,22 @@ impl DeleteTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTranscriptionJobError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTranscriptionJobError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTranscriptionJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTranscriptionJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1429,16 +1467,22 @@ impl DeleteVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, DeleteVocabularyError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVocabularyError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVocabularyError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(&res, DeleteVocabularyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1477,20 +1521,28 @@ impl DeleteVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVocabularyFilterError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVocabularyFilterError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVocabularyFilterError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteVocabularyFilterError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1531,22 +1583,28 @@ impl GetMedicalTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMedicalTranscriptionJobError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMedicalTranscriptionJobError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMedicalTranscriptionJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMedicalTranscriptionJobError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMedicalTranscriptionJobError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1585,18 +1643,25 @@ impl GetMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMedicalVocabularyError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMedicalVocabularyError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMedicalVocabularyError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMedicalVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetMedicalVocabularyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1635,16 +1700,25 @@ impl GetTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTranscriptionJobError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTranscriptionJobError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTranscriptionJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTranscriptionJobError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetTranscriptionJobError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1683,16 +1757,16 @@ impl GetVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, GetVocabularyError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(&res, GetVocabularyError::InternalFailure(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, GetVocabularyError::LimitExceeded(err.msg))
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetVocabularyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1731,16 +1805,25 @@ impl GetVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVocabularyFilterError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVocabularyFilterError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetVocabularyFilterError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service(&res, GetVocabularyFilterError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1779,19 +1862,22 @@ impl ListMedicalTranscriptionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMedicalTranscriptionJobsError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMedicalTranscriptionJobsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListMedicalTranscriptionJobsError::InternalFailure(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListMedicalTranscriptionJobsError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMedicalTranscriptionJobsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1827,17 +1913,22 @@ impl ListMedicalVocabulariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMedicalVocabulariesError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListMedicalVocabulariesError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListMedicalVocabulariesError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMedicalVocabulariesError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListMedicalVocabulariesError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMedicalVocabulariesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1873,15 +1964,22 @@ impl ListTranscriptionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTranscriptionJobsError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTranscriptionJobsError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTranscriptionJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTranscriptionJobsError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1917,13 +2015,19 @@ impl ListVocabulariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVocabulariesError::BadRequest(err.msg))
+                    return RusotoError::service(&res, ListVocabulariesError::BadRequest(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListVocabulariesError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVocabulariesError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListVocabulariesError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVocabulariesError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1959,15 +2063,22 @@ impl ListVocabularyFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVocabularyFiltersError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListVocabularyFiltersError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListVocabularyFiltersError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListVocabularyFiltersError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2007,24 +2118,28 @@ impl StartMedicalTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMedicalTranscriptionJobError::BadRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMedicalTranscriptionJobError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartMedicalTranscriptionJobError::Conflict(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMedicalTranscriptionJobError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartMedicalTranscriptionJobError::InternalFailure(err.msg),
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartMedicalTranscriptionJobError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartMedicalTranscriptionJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2063,18 +2178,28 @@ impl StartTranscriptionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTranscriptionJobError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTranscriptionJobError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTranscriptionJobError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartTranscriptionJobError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartTranscriptionJobError::LimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2115,23 +2240,34 @@ impl UpdateMedicalVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMedicalVocabularyError::BadRequest(err.msg),
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::Conflict(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMedicalVocabularyError::Conflict(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMedicalVocabularyError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMedicalVocabularyError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMedicalVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMedicalVocabularyError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2173,19 +2309,25 @@ impl UpdateVocabularyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVocabularyError::BadRequest(err.msg))
+                    return RusotoError::service(&res, UpdateVocabularyError::BadRequest(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVocabularyError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateVocabularyError::Conflict(err.msg))
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateVocabularyError::InternalFailure(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVocabularyError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVocabularyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVocabularyError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVocabularyError::NotFound(err.msg))
+                    return RusotoError::service(&res, UpdateVocabularyError::NotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2225,20 +2367,28 @@ impl UpdateVocabularyFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::BadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVocabularyFilterError::BadRequest(err.msg),
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::InternalFailure(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVocabularyFilterError::InternalFailure(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::LimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVocabularyFilterError::LimitExceeded(err.msg),
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVocabularyFilterError::NotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateVocabularyFilterError::NotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2418,6 +2568,7 @@ impl TranscribeClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2433,6 +2584,7 @@ impl TranscribeClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> TranscribeClient {
         TranscribeClient { client, region }
     }
@@ -2446,6 +2598,9 @@ impl Transcribe for TranscribeClient {
         input: CreateMedicalVocabularyRequest,
     ) -> Result<CreateMedicalVocabularyResponse, RusotoError<CreateMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateMedicalVocabulary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.CreateMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2465,6 +2620,9 @@ impl Transcribe for TranscribeClient {
         input: CreateVocabularyRequest,
     ) -> Result<CreateVocabularyResponse, RusotoError<CreateVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateVocabulary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.CreateVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2483,6 +2641,9 @@ impl Transcribe for TranscribeClient {
         input: CreateVocabularyFilterRequest,
     ) -> Result<CreateVocabularyFilterResponse, RusotoError<CreateVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateVocabularyFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.CreateVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2502,6 +2663,9 @@ impl Transcribe for TranscribeClient {
         input: DeleteMedicalTranscriptionJobRequest,
     ) -> Result<(), RusotoError<DeleteMedicalTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMedicalTranscriptionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.DeleteMedicalTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2519,6 +2683,9 @@ impl Transcribe for TranscribeClient {
         input: DeleteMedicalVocabularyRequest,
     ) -> Result<(), RusotoError<DeleteMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMedicalVocabulary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.DeleteMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2536,6 +2703,9 @@ impl Transcribe for TranscribeClient {
         input: DeleteTranscriptionJobRequest,
     ) -> Result<(), RusotoError<DeleteTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTranscriptionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.DeleteTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2553,6 +2723,9 @@ impl Transcribe for TranscribeClient {
         input: DeleteVocabularyRequest,
     ) -> Result<(), RusotoError<DeleteVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteVocabulary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.DeleteVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2570,6 +2743,9 @@ impl Transcribe for TranscribeClient {
         input: DeleteVocabularyFilterRequest,
     ) -> Result<(), RusotoError<DeleteVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteVocabularyFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.DeleteVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2588,6 +2764,9 @@ impl Transcribe for TranscribeClient {
     ) -> Result<GetMedicalTranscriptionJobResponse, RusotoError<GetMedicalTranscriptionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMedicalTranscriptionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.GetMedicalTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2607,6 +2786,9 @@ impl Transcribe for TranscribeClient {
         input: GetMedicalVocabularyRequest,
     ) -> Result<GetMedicalVocabularyResponse, RusotoError<GetMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMedicalVocabulary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.GetMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2626,6 +2808,9 @@ impl Transcribe for TranscribeClient {
         input: GetTranscriptionJobRequest,
     ) -> Result<GetTranscriptionJobResponse, RusotoError<GetTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTranscriptionJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.GetTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2644,6 +2829,9 @@ impl Transcribe for TranscribeClient {
         input: GetVocabularyRequest,
     ) -> Result<GetVocabularyResponse, RusotoError<GetVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetVocabulary");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.GetVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2662,6 +2850,9 @@ impl Transcribe for TranscribeClient {
         input: GetVocabularyFilterRequest,
     ) -> Result<GetVocabularyFilterResponse, RusotoError<GetVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetVocabularyFilter");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.GetVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2681,6 +2872,9 @@ impl Transcribe for TranscribeClient {
     ) -> Result<ListMedicalTranscriptionJobsResponse, RusotoError<ListMedicalTranscriptionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMedicalTranscriptionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.ListMedicalTranscriptionJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2700,6 +2894,9 @@ impl Transcribe for TranscribeClient {
         input: ListMedicalVocabulariesRequest,
     ) -> Result<ListMedicalVocabulariesResponse, RusotoError<ListMedicalVocabulariesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMedicalVocabularies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.ListMedicalVocabularies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2719,6 +2916,9 @@ impl Transcribe for TranscribeClient {
         input: ListTranscriptionJobsRequest,
     ) -> Result<ListTranscriptionJobsResponse, RusotoError<ListTranscriptionJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTranscriptionJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.ListTranscriptionJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2738,6 +2938,9 @@ impl Transcribe for TranscribeClient {
         input: ListVocabulariesRequest,
     ) -> Result<ListVocabulariesResponse, RusotoError<ListVocabulariesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListVocabularies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.ListVocabularies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2756,6 +2959,9 @@ impl Transcribe for TranscribeClient {
         input: ListVocabularyFiltersRequest,
     ) -> Result<ListVocabularyFiltersResponse, RusotoError<ListVocabularyFiltersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListVocabularyFilters");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "Transcribe.ListVocabularyFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2776,6 +2982,9 @@ impl Transcribe for TranscribeClient {
     ) -> Result<StartMedicalTranscriptionJobResponse, RusotoError<StartMedicalTranscriptionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartMedicalTranscriptionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.StartMedicalTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2795,6 +3004,9 @@ impl Transcribe for TranscribeClient {
         input: StartTranscriptionJobRequest,
     ) -> Result<StartTranscriptionJobResponse, RusotoError<StartTranscriptionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartTranscriptionJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.StartTranscriptionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2814,6 +3026,9 @@ impl Transcribe for TranscribeClient {
         input: UpdateMedicalVocabularyRequest,
     ) -> Result<UpdateMedicalVocabularyResponse, RusotoError<UpdateMedicalVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMedicalVocabulary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.UpdateMedicalVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2833,6 +3048,9 @@ impl Transcribe for TranscribeClient {
         input: UpdateVocabularyRequest,
     ) -> Result<UpdateVocabularyResponse, RusotoError<UpdateVocabularyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateVocabulary");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.UpdateVocabulary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2851,6 +3069,9 @@ impl Transcribe for TranscribeClient {
         input: UpdateVocabularyFilterRequest,
     ) -> Result<UpdateVocabularyFilterResponse, RusotoError<UpdateVocabularyFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateVocabularyFilter");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "Transcribe.UpdateVocabularyFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2864,3 +3085,304 @@ impl Transcribe for TranscribeClient {
             .deserialize::<UpdateVocabularyFilterResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Transcribe Service API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `TranscribeClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct TranscribeBlockingClient {
+    client: TranscribeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl TranscribeBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> TranscribeBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        TranscribeBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TranscribeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TranscribeBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> TranscribeBlockingClient {
+        TranscribeBlockingClient {
+            client: TranscribeClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Creates a new custom vocabulary that you can use to change how Amazon Transcribe Medical transcribes your audio file.</p>
+    ///
+    /// See `Transcribe::create_medical_vocabulary`.
+    pub fn create_medical_vocabulary(
+        &self,
+        input: CreateMedicalVocabularyRequest,
+    ) -> Result<CreateMedicalVocabularyResponse, RusotoError<CreateMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.create_medical_vocabulary(input))
+    }
+
+    /// <p>Creates a new custom vocabulary that you can use to change the way Amazon Transcribe handles transcription of an audio file. </p>
+    ///
+    /// See `Transcribe::create_vocabulary`.
+    pub fn create_vocabulary(
+        &self,
+        input: CreateVocabularyRequest,
+    ) -> Result<CreateVocabularyResponse, RusotoError<CreateVocabularyError>> {
+        self.runtime.block_on(self.client.create_vocabulary(input))
+    }
+
+    /// <p>Creates a new vocabulary filter that you can use to filter words, such as profane words, from the output of a transcription job.</p>
+    ///
+    /// See `Transcribe::create_vocabulary_filter`.
+    pub fn create_vocabulary_filter(
+        &self,
+        input: CreateVocabularyFilterRequest,
+    ) -> Result<CreateVocabularyFilterResponse, RusotoError<CreateVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.create_vocabulary_filter(input))
+    }
+
+    /// <p>Deletes a transcription job generated by Amazon Transcribe Medical and any related information.</p>
+    ///
+    /// See `Transcribe::delete_medical_transcription_job`.
+    pub fn delete_medical_transcription_job(
+        &self,
+        input: DeleteMedicalTranscriptionJobRequest,
+    ) -> Result<(), RusotoError<DeleteMedicalTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.delete_medical_transcription_job(input))
+    }
+
+    /// <p>Deletes a vocabulary from Amazon Transcribe Medical.</p>
+    ///
+    /// See `Transcribe::delete_medical_vocabulary`.
+    pub fn delete_medical_vocabulary(
+        &self,
+        input: DeleteMedicalVocabularyRequest,
+    ) -> Result<(), RusotoError<DeleteMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.delete_medical_vocabulary(input))
+    }
+
+    /// <p>Deletes a previously submitted transcription job along with any other generated results such as the transcription, models, and so on.</p>
+    ///
+    /// See `Transcribe::delete_transcription_job`.
+    pub fn delete_transcription_job(
+        &self,
+        input: DeleteTranscriptionJobRequest,
+    ) -> Result<(), RusotoError<DeleteTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.delete_transcription_job(input))
+    }
+
+    /// <p>Deletes a vocabulary from Amazon Transcribe. </p>
+    ///
+    /// See `Transcribe::delete_vocabulary`.
+    pub fn delete_vocabulary(
+        &self,
+        input: DeleteVocabularyRequest,
+    ) -> Result<(), RusotoError<DeleteVocabularyError>> {
+        self.runtime.block_on(self.client.delete_vocabulary(input))
+    }
+
+    /// <p>Removes a vocabulary filter.</p>
+    ///
+    /// See `Transcribe::delete_vocabulary_filter`.
+    pub fn delete_vocabulary_filter(
+        &self,
+        input: DeleteVocabularyFilterRequest,
+    ) -> Result<(), RusotoError<DeleteVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_vocabulary_filter(input))
+    }
+
+    /// <p>Returns information about a transcription job from Amazon Transcribe Medical. To see the status of the job, check the <code>TranscriptionJobStatus</code> field. If the status is <code>COMPLETED</code>, the job is finished. You find the results of the completed job in the <code>TranscriptFileUri</code> field.</p>
+    ///
+    /// See `Transcribe::get_medical_transcription_job`.
+    pub fn get_medical_transcription_job(
+        &self,
+        input: GetMedicalTranscriptionJobRequest,
+    ) -> Result<GetMedicalTranscriptionJobResponse, RusotoError<GetMedicalTranscriptionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.get_medical_transcription_job(input))
+    }
+
+    /// <p>Retrieve information about a medical vocabulary.</p>
+    ///
+    /// See `Transcribe::get_medical_vocabulary`.
+    pub fn get_medical_vocabulary(
+        &self,
+        input: GetMedicalVocabularyRequest,
+    ) -> Result<GetMedicalVocabularyResponse, RusotoError<GetMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.get_medical_vocabulary(input))
+    }
+
+    /// <p>Returns information about a transcription job. To see the status of the job, check the <code>TranscriptionJobStatus</code> field. If the status is <code>COMPLETED</code>, the job is finished and you can find the results at the location specified in the <code>TranscriptFileUri</code> field. If you enable content redaction, the redacted transcript appears in <code>RedactedTranscriptFileUri</code>.</p>
+    ///
+    /// See `Transcribe::get_transcription_job`.
+    pub fn get_transcription_job(
+        &self,
+        input: GetTranscriptionJobRequest,
+    ) -> Result<GetTranscriptionJobResponse, RusotoError<GetTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.get_transcription_job(input))
+    }
+
+    /// <p>Gets information about a vocabulary. </p>
+    ///
+    /// See `Transcribe::get_vocabulary`.
+    pub fn get_vocabulary(
+        &self,
+        input: GetVocabularyRequest,
+    ) -> Result<GetVocabularyResponse, RusotoError<GetVocabularyError>> {
+        self.runtime.block_on(self.client.get_vocabulary(input))
+    }
+
+    /// <p>Returns information about a vocabulary filter.</p>
+    ///
+    /// See `Transcribe::get_vocabulary_filter`.
+    pub fn get_vocabulary_filter(
+        &self,
+        input: GetVocabularyFilterRequest,
+    ) -> Result<GetVocabularyFilterResponse, RusotoError<GetVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.get_vocabulary_filter(input))
+    }
+
+    /// <p>Lists medical transcription jobs with a specified status or substring that matches their names.</p>
+    ///
+    /// See `Transcribe::list_medical_transcription_jobs`.
+    pub fn list_medical_transcription_jobs(
+        &self,
+        input: ListMedicalTranscriptionJobsRequest,
+    ) -> Result<ListMedicalTranscriptionJobsResponse, RusotoError<ListMedicalTranscriptionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_medical_transcription_jobs(input))
+    }
+
+    /// <p>Returns a list of vocabularies that match the specified criteria. You get the entire list of vocabularies if you don't enter a value in any of the request parameters.</p>
+    ///
+    /// See `Transcribe::list_medical_vocabularies`.
+    pub fn list_medical_vocabularies(
+        &self,
+        input: ListMedicalVocabulariesRequest,
+    ) -> Result<ListMedicalVocabulariesResponse, RusotoError<ListMedicalVocabulariesError>> {
+        self.runtime
+            .block_on(self.client.list_medical_vocabularies(input))
+    }
+
+    /// <p>Lists transcription jobs with the specified status.</p>
+    ///
+    /// See `Transcribe::list_transcription_jobs`.
+    pub fn list_transcription_jobs(
+        &self,
+        input: ListTranscriptionJobsRequest,
+    ) -> Result<ListTranscriptionJobsResponse, RusotoError<ListTranscriptionJobsError>> {
+        self.runtime
+            .block_on(self.client.list_transcription_jobs(input))
+    }
+
+    /// <p>Returns a list of vocabularies that match the specified criteria. If no criteria are specified, returns the entire list of vocabularies.</p>
+    ///
+    /// See `Transcribe::list_vocabularies`.
+    pub fn list_vocabularies(
+        &self,
+        input: ListVocabulariesRequest,
+    ) -> Result<ListVocabulariesResponse, RusotoError<ListVocabulariesError>> {
+        self.runtime.block_on(self.client.list_vocabularies(input))
+    }
+
+    /// <p>Gets information about vocabulary filters.</p>
+    ///
+    /// See `Transcribe::list_vocabulary_filters`.
+    pub fn list_vocabulary_filters(
+        &self,
+        input: ListVocabularyFiltersRequest,
+    ) -> Result<ListVocabularyFiltersResponse, RusotoError<ListVocabularyFiltersError>> {
+        self.runtime
+            .block_on(self.client.list_vocabulary_filters(input))
+    }
+
+    /// <p>Start a batch job to transcribe medical speech to text.</p>
+    ///
+    /// See `Transcribe::start_medical_transcription_job`.
+    pub fn start_medical_transcription_job(
+        &self,
+        input: StartMedicalTranscriptionJobRequest,
+    ) -> Result<StartMedicalTranscriptionJobResponse, RusotoError<StartMedicalTranscriptionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_medical_transcription_job(input))
+    }
+
+    /// <p>Starts an asynchronous job to transcribe speech to text. </p>
+    ///
+    /// See `Transcribe::start_transcription_job`.
+    pub fn start_transcription_job(
+        &self,
+        input: StartTranscriptionJobRequest,
+    ) -> Result<StartTranscriptionJobResponse, RusotoError<StartTranscriptionJobError>> {
+        self.runtime
+            .block_on(self.client.start_transcription_job(input))
+    }
+
+    /// <p>Updates an existing vocabulary with new values in a different text file. The <code>UpdateMedicalVocabulary</code> operation overwrites all of the existing information with the values that you provide in the request.</p>
+    ///
+    /// See `Transcribe::update_medical_vocabulary`.
+    pub fn update_medical_vocabulary(
+        &self,
+        input: UpdateMedicalVocabularyRequest,
+    ) -> Result<UpdateMedicalVocabularyResponse, RusotoError<UpdateMedicalVocabularyError>> {
+        self.runtime
+            .block_on(self.client.update_medical_vocabulary(input))
+    }
+
+    /// <p>Updates an existing vocabulary with new values. The <code>UpdateVocabulary</code> operation overwrites all of the existing information with the values that you provide in the request. </p>
+    ///
+    /// See `Transcribe::update_vocabulary`.
+    pub fn update_vocabulary(
+        &self,
+        input: UpdateVocabularyRequest,
+    ) -> Result<UpdateVocabularyResponse, RusotoError<UpdateVocabularyError>> {
+        self.runtime.block_on(self.client.update_vocabulary(input))
+    }
+
+    /// <p>Updates a vocabulary filter with a new list of filtered words.</p>
+    ///
+    /// See `Transcribe::update_vocabulary_filter`.
+    pub fn update_vocabulary_filter(
+        &self,
+        input: UpdateVocabularyFilterRequest,
+    ) -> Result<UpdateVocabularyFilterResponse, RusotoError<UpdateVocabularyFilterError>> {
+        self.runtime
+            .block_on(self.client.update_vocabulary_filter(input))
+    }
+}
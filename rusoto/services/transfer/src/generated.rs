@@ -757,22 +757,28 @@ impl CreateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateServerError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateServerError::AccessDenied(err.msg))
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreateServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateServerError::InvalidRequest(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateServerError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, CreateServerError::ResourceExists(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateServerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateServerError::Throttling(err.msg))
+                    return RusotoError::service(&res, CreateServerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -815,19 +821,22 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreateUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateUserError::InvalidRequest(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateUserError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -869,19 +878,25 @@ impl DeleteServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteServerError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteServerError::AccessDenied(err.msg))
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteServerError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteServerError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteServerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -923,23 +938,31 @@ impl DeleteSshPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSshPublicKeyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSshPublicKeyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSshPublicKeyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSshPublicKeyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSshPublicKeyError::Throttling(err.msg))
+                    return RusotoError::service(&res, DeleteSshPublicKeyError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -979,16 +1002,19 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeleteUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1027,16 +1053,25 @@ impl DescribeServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeServerError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServerError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeServerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1075,16 +1110,22 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1127,26 +1168,37 @@ impl ImportSshPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportSshPublicKeyError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportSshPublicKeyError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::ResourceExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportSshPublicKeyError::ResourceExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportSshPublicKeyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportSshPublicKeyError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ImportSshPublicKeyError::Throttling(err.msg))
+                    return RusotoError::service(&res, ImportSshPublicKeyError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1187,16 +1239,22 @@ impl ListServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListServersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListServersError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListServersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListServersError::InvalidNextToken(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListServersError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListServersError::InvalidRequest(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListServersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListServersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1235,22 +1293,28 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidNextToken(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1291,19 +1355,22 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListUsersError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUsersError::InvalidNextToken(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InvalidNextToken(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUsersError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, ListUsersError::ServiceUnavailable(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1345,19 +1412,25 @@ impl StartServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(StartServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartServerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, StartServerError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StartServerError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StartServerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartServerError::Throttling(err.msg))
+                    return RusotoError::service(&res, StartServerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1399,19 +1472,22 @@ impl StopServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(StopServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        StopServerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, StopServerError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, StopServerError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, StopServerError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopServerError::Throttling(err.msg))
+                    return RusotoError::service(&res, StopServerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1451,16 +1527,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1499,22 +1581,28 @@ impl TestIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(TestIdentityProviderError::InternalServiceError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestIdentityProviderError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TestIdentityProviderError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TestIdentityProviderError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestIdentityProviderError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestIdentityProviderError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestIdentityProviderError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TestIdentityProviderError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1553,16 +1641,25 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1609,28 +1706,34 @@ impl UpdateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServerError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::AccessDenied(err.msg))
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateServerError::Conflict(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::Conflict(err.msg))
                 }
                 "InternalServiceError" => {
-                    return RusotoError::Service(UpdateServerError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServerError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateServerError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::InvalidRequest(err.msg))
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateServerError::ResourceExists(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::ResourceExists(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateServerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateServerError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateServerError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateServerError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1675,19 +1778,22 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(UpdateUserError::InternalServiceError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserError::InternalServiceError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::ResourceNotFound(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::ServiceUnavailable(err.msg))
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserError::Throttling(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::Throttling(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1838,6 +1944,7 @@ impl TransferClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1853,6 +1960,7 @@ impl TransferClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> TransferClient {
         TransferClient { client, region }
     }
@@ -1866,6 +1974,9 @@ impl Transfer for TransferClient {
         input: CreateServerRequest,
     ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.CreateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1884,6 +1995,9 @@ impl Transfer for TransferClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1902,6 +2016,9 @@ impl Transfer for TransferClient {
         input: DeleteServerRequest,
     ) -> Result<(), RusotoError<DeleteServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteServer");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.DeleteServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1919,6 +2036,9 @@ impl Transfer for TransferClient {
         input: DeleteSshPublicKeyRequest,
     ) -> Result<(), RusotoError<DeleteSshPublicKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSshPublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.DeleteSshPublicKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1936,6 +2056,9 @@ impl Transfer for TransferClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1953,6 +2076,9 @@ impl Transfer for TransferClient {
         input: DescribeServerRequest,
     ) -> Result<DescribeServerResponse, RusotoError<DescribeServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeServer");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.DescribeServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1971,6 +2097,9 @@ impl Transfer for TransferClient {
         input: DescribeUserRequest,
     ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.DescribeUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1989,6 +2118,9 @@ impl Transfer for TransferClient {
         input: ImportSshPublicKeyRequest,
     ) -> Result<ImportSshPublicKeyResponse, RusotoError<ImportSshPublicKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportSshPublicKey");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.ImportSshPublicKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2007,6 +2139,9 @@ impl Transfer for TransferClient {
         input: ListServersRequest,
     ) -> Result<ListServersResponse, RusotoError<ListServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListServers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.ListServers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2025,6 +2160,9 @@ impl Transfer for TransferClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2043,6 +2181,9 @@ impl Transfer for TransferClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "TransferService.ListUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2061,6 +2202,9 @@ impl Transfer for TransferClient {
         input: StartServerRequest,
     ) -> Result<(), RusotoError<StartServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.StartServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2078,6 +2222,9 @@ impl Transfer for TransferClient {
         input: StopServerRequest,
     ) -> Result<(), RusotoError<StopServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.StopServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2095,6 +2242,9 @@ impl Transfer for TransferClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2112,6 +2262,9 @@ impl Transfer for TransferClient {
         input: TestIdentityProviderRequest,
     ) -> Result<TestIdentityProviderResponse, RusotoError<TestIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TestIdentityProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.TestIdentityProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2131,6 +2284,9 @@ impl Transfer for TransferClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2148,6 +2304,9 @@ impl Transfer for TransferClient {
         input: UpdateServerRequest,
     ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateServer");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.UpdateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2166,6 +2325,9 @@ impl Transfer for TransferClient {
         input: UpdateUserRequest,
     ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "TransferService.UpdateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2178,3 +2340,237 @@ impl Transfer for TransferClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Transfer API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `TransferClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct TransferBlockingClient {
+    client: TransferClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl TransferBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> TransferBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        TransferBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TransferBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TransferBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> TransferBlockingClient {
+        TransferBlockingClient {
+            client: TransferClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Instantiates an autoscaling virtual server based on the selected file transfer protocol in AWS. When you make updates to your file transfer protocol-enabled server or when you work with users, use the service-generated <code>ServerId</code> property that is assigned to the newly created server.</p>
+    ///
+    /// See `Transfer::create_server`.
+    pub fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
+        self.runtime.block_on(self.client.create_server(input))
+    }
+
+    /// <p>Creates a user and associates them with an existing file transfer protocol-enabled server. You can only create and associate users with servers that have the <code>IdentityProviderType</code> set to <code>SERVICE_MANAGED</code>. Using parameters for <code>CreateUser</code>, you can specify the user name, set the home directory, store the user's public key, and assign the user's AWS Identity and Access Management (IAM) role. You can also optionally add a scope-down policy, and assign metadata with tags that can be used to group and search for users.</p>
+    ///
+    /// See `Transfer::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Deletes the file transfer protocol-enabled server that you specify.</p> <p>No response returns from this operation.</p>
+    ///
+    /// See `Transfer::delete_server`.
+    pub fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> Result<(), RusotoError<DeleteServerError>> {
+        self.runtime.block_on(self.client.delete_server(input))
+    }
+
+    /// <p>Deletes a user's Secure Shell (SSH) public key.</p> <p>No response is returned from this operation.</p>
+    ///
+    /// See `Transfer::delete_ssh_public_key`.
+    pub fn delete_ssh_public_key(
+        &self,
+        input: DeleteSshPublicKeyRequest,
+    ) -> Result<(), RusotoError<DeleteSshPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.delete_ssh_public_key(input))
+    }
+
+    /// <p><p>Deletes the user belonging to a file transfer protocol-enabled server you specify.</p> <p>No response returns from this operation.</p> <note> <p>When you delete a user from a server, the user&#39;s information is lost.</p> </note></p>
+    ///
+    /// See `Transfer::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Describes a file transfer protocol-enabled server that you specify by passing the <code>ServerId</code> parameter.</p> <p>The response contains a description of a server's properties. When you set <code>EndpointType</code> to VPC, the response will contain the <code>EndpointDetails</code>.</p>
+    ///
+    /// See `Transfer::describe_server`.
+    pub fn describe_server(
+        &self,
+        input: DescribeServerRequest,
+    ) -> Result<DescribeServerResponse, RusotoError<DescribeServerError>> {
+        self.runtime.block_on(self.client.describe_server(input))
+    }
+
+    /// <p>Describes the user assigned to the specific file transfer protocol-enabled server, as identified by its <code>ServerId</code> property.</p> <p>The response from this call returns the properties of the user associated with the <code>ServerId</code> value that was specified.</p>
+    ///
+    /// See `Transfer::describe_user`.
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// <p>Adds a Secure Shell (SSH) public key to a user account identified by a <code>UserName</code> value assigned to the specific file transfer protocol-enabled server, identified by <code>ServerId</code>.</p> <p>The response returns the <code>UserName</code> value, the <code>ServerId</code> value, and the name of the <code>SshPublicKeyId</code>.</p>
+    ///
+    /// See `Transfer::import_ssh_public_key`.
+    pub fn import_ssh_public_key(
+        &self,
+        input: ImportSshPublicKeyRequest,
+    ) -> Result<ImportSshPublicKeyResponse, RusotoError<ImportSshPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.import_ssh_public_key(input))
+    }
+
+    /// <p>Lists the file transfer protocol-enabled servers that are associated with your AWS account.</p>
+    ///
+    /// See `Transfer::list_servers`.
+    pub fn list_servers(
+        &self,
+        input: ListServersRequest,
+    ) -> Result<ListServersResponse, RusotoError<ListServersError>> {
+        self.runtime.block_on(self.client.list_servers(input))
+    }
+
+    /// <p>Lists all of the tags associated with the Amazon Resource Number (ARN) you specify. The resource can be a user, server, or role.</p>
+    ///
+    /// See `Transfer::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Lists the users for a file transfer protocol-enabled server that you specify by passing the <code>ServerId</code> parameter.</p>
+    ///
+    /// See `Transfer::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Changes the state of a file transfer protocol-enabled server from <code>OFFLINE</code> to <code>ONLINE</code>. It has no impact on a server that is already <code>ONLINE</code>. An <code>ONLINE</code> server can accept and process file transfer jobs.</p> <p>The state of <code>STARTING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully online. The values of <code>START_FAILED</code> can indicate an error condition.</p> <p>No response is returned from this call.</p>
+    ///
+    /// See `Transfer::start_server`.
+    pub fn start_server(
+        &self,
+        input: StartServerRequest,
+    ) -> Result<(), RusotoError<StartServerError>> {
+        self.runtime.block_on(self.client.start_server(input))
+    }
+
+    /// <p>Changes the state of a file transfer protocol-enabled server from <code>ONLINE</code> to <code>OFFLINE</code>. An <code>OFFLINE</code> server cannot accept and process file transfer jobs. Information tied to your server, such as server and user properties, are not affected by stopping your server. Stopping the server will not reduce or impact your file transfer protocol endpoint billing.</p> <p>The state of <code>STOPPING</code> indicates that the server is in an intermediate state, either not fully able to respond, or not fully offline. The values of <code>STOP_FAILED</code> can indicate an error condition.</p> <p>No response is returned from this call.</p>
+    ///
+    /// See `Transfer::stop_server`.
+    pub fn stop_server(
+        &self,
+        input: StopServerRequest,
+    ) -> Result<(), RusotoError<StopServerError>> {
+        self.runtime.block_on(self.client.stop_server(input))
+    }
+
+    /// <p>Attaches a key-value pair to a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>There is no response returned from this call.</p>
+    ///
+    /// See `Transfer::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>If the <code>IdentityProviderType</code> of a file transfer protocol-enabled server is <code>API_Gateway</code>, tests whether your API Gateway is set up successfully. We highly recommend that you call this operation to test your authentication method as soon as you create your server. By doing so, you can troubleshoot issues with the API Gateway integration to ensure that your users can successfully use the service.</p>
+    ///
+    /// See `Transfer::test_identity_provider`.
+    pub fn test_identity_provider(
+        &self,
+        input: TestIdentityProviderRequest,
+    ) -> Result<TestIdentityProviderResponse, RusotoError<TestIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.test_identity_provider(input))
+    }
+
+    /// <p>Detaches a key-value pair from a resource, as identified by its Amazon Resource Name (ARN). Resources are users, servers, roles, and other entities.</p> <p>No response is returned from this call.</p>
+    ///
+    /// See `Transfer::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the file transfer protocol-enabled server's properties after that server has been created.</p> <p>The <code>UpdateServer</code> call returns the <code>ServerId</code> of the server you updated.</p>
+    ///
+    /// See `Transfer::update_server`.
+    pub fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
+        self.runtime.block_on(self.client.update_server(input))
+    }
+
+    /// <p>Assigns new properties to a user. Parameters you pass modify any or all of the following: the home directory, role, and policy for the <code>UserName</code> and <code>ServerId</code> you specify.</p> <p>The response returns the <code>ServerId</code> and the <code>UserName</code> for the updated user.</p>
+    ///
+    /// See `Transfer::update_user`.
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+}
@@ -534,13 +534,22 @@ impl DeleteTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTerminologyError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTerminologyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTerminologyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTerminologyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -578,19 +587,22 @@ impl DescribeTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTextTranslationJobError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTextTranslationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeTextTranslationJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeTextTranslationJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -628,18 +640,25 @@ impl GetTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service(&res, GetTerminologyError::InternalServer(err.msg))
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetTerminologyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTerminologyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetTerminologyError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTerminologyError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTerminologyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -678,18 +697,28 @@ impl ImportTerminologyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ImportTerminologyError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportTerminologyError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ImportTerminologyError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportTerminologyError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportTerminologyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportTerminologyError::LimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportTerminologyError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportTerminologyError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -726,15 +755,22 @@ impl ListTerminologiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTerminologiesError::InternalServer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTerminologiesError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTerminologiesError::InvalidParameterValue(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTerminologiesError::InvalidParameterValue(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTerminologiesError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTerminologiesError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -772,24 +808,28 @@ impl ListTextTranslationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTextTranslationJobsError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InvalidFilter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTextTranslationJobsError::InvalidFilter(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTextTranslationJobsError::InvalidRequest(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTextTranslationJobsError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTextTranslationJobsError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -830,27 +870,32 @@ impl StartTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTextTranslationJobError::InternalServer(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTextTranslationJobError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTextTranslationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartTextTranslationJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StartTextTranslationJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnsupportedLanguagePairException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         StartTextTranslationJobError::UnsupportedLanguagePair(err.msg),
                     )
                 }
@@ -892,19 +937,22 @@ impl StopTextTranslationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::InternalServer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopTextTranslationJobError::InternalServer(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopTextTranslationJobError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopTextTranslationJobError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        StopTextTranslationJobError::TooManyRequests(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -950,32 +998,43 @@ impl TranslateTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DetectedLanguageLowConfidenceException" => {
-                    return RusotoError::Service(TranslateTextError::DetectedLanguageLowConfidence(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TranslateTextError::DetectedLanguageLowConfidence(err.msg),
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TranslateTextError::InternalServer(err.msg))
+                    return RusotoError::service(&res, TranslateTextError::InternalServer(err.msg))
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TranslateTextError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TranslateTextError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TranslateTextError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TranslateTextError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TranslateTextError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TranslateTextError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(TranslateTextError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TranslateTextError::TextSizeLimitExceeded(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TranslateTextError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, TranslateTextError::TooManyRequests(err.msg))
                 }
                 "UnsupportedLanguagePairException" => {
-                    return RusotoError::Service(TranslateTextError::UnsupportedLanguagePair(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TranslateTextError::UnsupportedLanguagePair(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1075,6 +1134,7 @@ impl TranslateClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -1090,6 +1150,7 @@ impl TranslateClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> TranslateClient {
         TranslateClient { client, region }
     }
@@ -1103,6 +1164,9 @@ impl Translate for TranslateClient {
         input: DeleteTerminologyRequest,
     ) -> Result<(), RusotoError<DeleteTerminologyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTerminology");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.DeleteTerminology",
@@ -1124,6 +1188,9 @@ impl Translate for TranslateClient {
     ) -> Result<DescribeTextTranslationJobResponse, RusotoError<DescribeTextTranslationJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTextTranslationJob");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.DescribeTextTranslationJob",
@@ -1146,6 +1213,9 @@ impl Translate for TranslateClient {
         input: GetTerminologyRequest,
     ) -> Result<GetTerminologyResponse, RusotoError<GetTerminologyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetTerminology");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.GetTerminology",
@@ -1167,6 +1237,9 @@ impl Translate for TranslateClient {
         input: ImportTerminologyRequest,
     ) -> Result<ImportTerminologyResponse, RusotoError<ImportTerminologyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportTerminology");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.ImportTerminology",
@@ -1188,6 +1261,9 @@ impl Translate for TranslateClient {
         input: ListTerminologiesRequest,
     ) -> Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTerminologies");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.ListTerminologies",
@@ -1209,6 +1285,9 @@ impl Translate for TranslateClient {
         input: ListTextTranslationJobsRequest,
     ) -> Result<ListTextTranslationJobsResponse, RusotoError<ListTextTranslationJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTextTranslationJobs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.ListTextTranslationJobs",
@@ -1231,6 +1310,9 @@ impl Translate for TranslateClient {
         input: StartTextTranslationJobRequest,
     ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartTextTranslationJob");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.StartTextTranslationJob",
@@ -1253,6 +1335,9 @@ impl Translate for TranslateClient {
         input: StopTextTranslationJobRequest,
     ) -> Result<StopTextTranslationJobResponse, RusotoError<StopTextTranslationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopTextTranslationJob");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.StopTextTranslationJob",
@@ -1275,6 +1360,9 @@ impl Translate for TranslateClient {
         input: TranslateTextRequest,
     ) -> Result<TranslateTextResponse, RusotoError<TranslateTextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TranslateText");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "AWSShineFrontendService_20170701.TranslateText",
@@ -1290,3 +1378,148 @@ impl Translate for TranslateClient {
         proto::json::ResponsePayload::new(&response).deserialize::<TranslateTextResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Translate API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `TranslateClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct TranslateBlockingClient {
+    client: TranslateClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl TranslateBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> TranslateBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        TranslateBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> TranslateBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        TranslateBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> TranslateBlockingClient {
+        TranslateBlockingClient {
+            client: TranslateClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>A synchronous action that deletes a custom terminology.</p>
+    ///
+    /// See `Translate::delete_terminology`.
+    pub fn delete_terminology(
+        &self,
+        input: DeleteTerminologyRequest,
+    ) -> Result<(), RusotoError<DeleteTerminologyError>> {
+        self.runtime.block_on(self.client.delete_terminology(input))
+    }
+
+    /// <p>Gets the properties associated with an asycnhronous batch translation job including name, ID, status, source and target languages, input/output S3 buckets, and so on.</p>
+    ///
+    /// See `Translate::describe_text_translation_job`.
+    pub fn describe_text_translation_job(
+        &self,
+        input: DescribeTextTranslationJobRequest,
+    ) -> Result<DescribeTextTranslationJobResponse, RusotoError<DescribeTextTranslationJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_text_translation_job(input))
+    }
+
+    /// <p>Retrieves a custom terminology.</p>
+    ///
+    /// See `Translate::get_terminology`.
+    pub fn get_terminology(
+        &self,
+        input: GetTerminologyRequest,
+    ) -> Result<GetTerminologyResponse, RusotoError<GetTerminologyError>> {
+        self.runtime.block_on(self.client.get_terminology(input))
+    }
+
+    /// <p>Creates or updates a custom terminology, depending on whether or not one already exists for the given terminology name. Importing a terminology with the same name as an existing one will merge the terminologies based on the chosen merge strategy. Currently, the only supported merge strategy is OVERWRITE, and so the imported terminology will overwrite an existing terminology of the same name.</p> <p>If you import a terminology that overwrites an existing one, the new terminology take up to 10 minutes to fully propagate and be available for use in a translation due to cache policies with the DataPlane service that performs the translations.</p>
+    ///
+    /// See `Translate::import_terminology`.
+    pub fn import_terminology(
+        &self,
+        input: ImportTerminologyRequest,
+    ) -> Result<ImportTerminologyResponse, RusotoError<ImportTerminologyError>> {
+        self.runtime.block_on(self.client.import_terminology(input))
+    }
+
+    /// <p>Provides a list of custom terminologies associated with your account.</p>
+    ///
+    /// See `Translate::list_terminologies`.
+    pub fn list_terminologies(
+        &self,
+        input: ListTerminologiesRequest,
+    ) -> Result<ListTerminologiesResponse, RusotoError<ListTerminologiesError>> {
+        self.runtime.block_on(self.client.list_terminologies(input))
+    }
+
+    /// <p>Gets a list of the batch translation jobs that you have submitted.</p>
+    ///
+    /// See `Translate::list_text_translation_jobs`.
+    pub fn list_text_translation_jobs(
+        &self,
+        input: ListTextTranslationJobsRequest,
+    ) -> Result<ListTextTranslationJobsResponse, RusotoError<ListTextTranslationJobsError>> {
+        self.runtime
+            .block_on(self.client.list_text_translation_jobs(input))
+    }
+
+    /// <p><p>Starts an asynchronous batch translation job. Batch translation jobs can be used to translate large volumes of text across multiple documents at once. For more information, see <a>async</a>.</p> <p>Batch translation jobs can be described with the <a>DescribeTextTranslationJob</a> operation, listed with the <a>ListTextTranslationJobs</a> operation, and stopped with the <a>StopTextTranslationJob</a> operation.</p> <note> <p>Amazon Translate does not support batch translation of multiple source languages at once.</p> </note></p>
+    ///
+    /// See `Translate::start_text_translation_job`.
+    pub fn start_text_translation_job(
+        &self,
+        input: StartTextTranslationJobRequest,
+    ) -> Result<StartTextTranslationJobResponse, RusotoError<StartTextTranslationJobError>> {
+        self.runtime
+            .block_on(self.client.start_text_translation_job(input))
+    }
+
+    /// <p>Stops an asynchronous batch translation job that is in progress.</p> <p>If the job's state is <code>IN_PROGRESS</code>, the job will be marked for termination and put into the <code>STOP_REQUESTED</code> state. If the job completes before it can be stopped, it is put into the <code>COMPLETED</code> state. Otherwise, the job is put into the <code>STOPPED</code> state.</p> <p>Asynchronous batch translation jobs are started with the <a>StartTextTranslationJob</a> operation. You can use the <a>DescribeTextTranslationJob</a> or <a>ListTextTranslationJobs</a> operations to get a batch translation job's <code>JobId</code>.</p>
+    ///
+    /// See `Translate::stop_text_translation_job`.
+    pub fn stop_text_translation_job(
+        &self,
+        input: StopTextTranslationJobRequest,
+    ) -> Result<StopTextTranslationJobResponse, RusotoError<StopTextTranslationJobError>> {
+        self.runtime
+            .block_on(self.client.stop_text_translation_job(input))
+    }
+
+    /// <p>Translates input text from the source language to the target language. For a list of available languages and language codes, see <a>what-is-languages</a>.</p>
+    ///
+    /// See `Translate::translate_text`.
+    pub fn translate_text(
+        &self,
+        input: TranslateTextRequest,
+    ) -> Result<TranslateTextResponse, RusotoError<TranslateTextError>> {
+        self.runtime.block_on(self.client.translate_text(input))
+    }
+}
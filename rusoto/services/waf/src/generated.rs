@@ -2591,30 +2591,40 @@ impl CreateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateByteMatchSetError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateByteMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateByteMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateByteMatchSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateByteMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateByteMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2659,24 +2669,40 @@ impl CreateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGeoMatchSetError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGeoMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGeoMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateGeoMatchSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGeoMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGeoMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2721,22 +2747,25 @@ impl CreateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(&res, CreateIPSetError::WAFDisallowedName(err.msg))
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, CreateIPSetError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, CreateIPSetError::WAFInvalidAccount(err.msg))
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIPSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(&res, CreateIPSetError::WAFLimitsExceeded(err.msg))
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, CreateIPSetError::WAFStaleData(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2785,36 +2814,50 @@ impl CreateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRateBasedRuleError::WAFBadRequest(err.msg),
+                    )
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRateBasedRuleError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRateBasedRuleError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRateBasedRuleError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRateBasedRuleError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRateBasedRuleError::WAFStaleData(err.msg),
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRateBasedRuleError::WAFTagOperation(err.msg),
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRateBasedRuleError::WAFTagOperationInternalError(err.msg),
                     )
                 }
@@ -2861,22 +2904,28 @@ impl CreateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexMatchSetError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2915,22 +2964,28 @@ impl CreateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexPatternSetError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexPatternSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexPatternSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRegexPatternSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2977,30 +3032,34 @@ impl CreateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleError::WAFBadRequest(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::WAFBadRequest(err.msg))
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::WAFDisallowedName(err.msg))
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRuleError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::WAFLimitsExceeded(err.msg))
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::WAFStaleData(err.msg))
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(&res, CreateRuleError::WAFTagOperation(err.msg))
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateRuleError::WAFTagOperationInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3049,25 +3108,38 @@ impl CreateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFBadRequest(err.msg))
+                    return RusotoError::service(&res, CreateRuleGroupError::WAFBadRequest(err.msg))
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRuleGroupError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRuleGroupError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRuleGroupError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, CreateRuleGroupError::WAFStaleData(err.msg))
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateRuleGroupError::WAFTagOperation(err.msg),
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateRuleGroupError::WAFTagOperationInternalError(err.msg),
                     )
                 }
@@ -3115,34 +3187,40 @@ impl CreateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSizeConstraintSetError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSizeConstraintSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSizeConstraintSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSizeConstraintSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSizeConstraintSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSizeConstraintSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3189,34 +3267,40 @@ impl CreateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSqlInjectionMatchSetError::WAFDisallowedName(err.msg),
                     )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateSqlInjectionMatchSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSqlInjectionMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3269,33 +3353,46 @@ impl CreateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFBadRequest(err.msg))
+                    return RusotoError::service(&res, CreateWebACLError::WAFBadRequest(err.msg))
                 }
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebACLError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, CreateWebACLError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebACLError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebACLError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebACLError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, CreateWebACLError::WAFStaleData(err.msg))
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service(&res, CreateWebACLError::WAFTagOperation(err.msg))
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebACLError::WAFTagOperationInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3343,27 +3440,32 @@ impl CreateWebACLMigrationStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFEntityMigrationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateWebACLMigrationStackError::WAFEntityMigration(err.msg),
                     )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateWebACLMigrationStackError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWebACLMigrationStackError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateWebACLMigrationStackError::WAFInvalidOperation(err.msg),
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateWebACLMigrationStackError::WAFInvalidParameter(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateWebACLMigrationStackError::WAFNonexistentItem(err.msg),
                     )
                 }
@@ -3417,24 +3519,40 @@ impl CreateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFDisallowedName(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateXssMatchSetError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateXssMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateXssMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateXssMatchSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateXssMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(CreateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateXssMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3479,30 +3597,40 @@ impl DeleteByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteByteMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteByteMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteByteMatchSetError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteByteMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteByteMatchSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteByteMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3547,24 +3675,40 @@ impl DeleteGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGeoMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGeoMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGeoMatchSetError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGeoMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGeoMatchSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGeoMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3609,22 +3753,25 @@ impl DeleteIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, DeleteIPSetError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, DeleteIPSetError::WAFInvalidAccount(err.msg))
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(&res, DeleteIPSetError::WAFNonEmptyEntity(err.msg))
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIPSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(&res, DeleteIPSetError::WAFReferencedItem(err.msg))
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, DeleteIPSetError::WAFStaleData(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3665,19 +3812,22 @@ impl DeleteLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoggingConfigurationError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteLoggingConfigurationError::WAFNonexistentItem(err.msg),
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteLoggingConfigurationError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLoggingConfigurationError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3715,17 +3865,22 @@ impl DeletePermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePermissionPolicyError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeletePermissionPolicyError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeletePermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeletePermissionPolicyError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3771,38 +3926,50 @@ impl DeleteRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRateBasedRuleError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRateBasedRuleError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRateBasedRuleError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRateBasedRuleError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRateBasedRuleError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRateBasedRuleError::WAFStaleData(err.msg),
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRateBasedRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRateBasedRuleError::WAFTagOperation(err.msg),
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRateBasedRuleError::WAFTagOperationInternalError(err.msg),
                     )
                 }
@@ -3853,32 +4020,40 @@ impl DeleteRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexMatchSetError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexMatchSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3923,32 +4098,40 @@ impl DeleteRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexPatternSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexPatternSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexPatternSetError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexPatternSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexPatternSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRegexPatternSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3997,30 +4180,31 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::WAFInvalidAccount(err.msg))
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::WAFNonEmptyEntity(err.msg))
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::WAFNonexistentItem(err.msg))
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::WAFReferencedItem(err.msg))
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::WAFStaleData(err.msg))
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperation(err.msg))
+                    return RusotoError::service(&res, DeleteRuleError::WAFTagOperation(err.msg))
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleError::WAFTagOperationInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4071,28 +4255,47 @@ impl DeleteRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleGroupError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleGroupError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleGroupError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleGroupError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleGroupError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, DeleteRuleGroupError::WAFStaleData(err.msg))
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteRuleGroupError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRuleGroupError::WAFTagOperation(err.msg),
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteRuleGroupError::WAFTagOperationInternalError(err.msg),
                     )
                 }
@@ -4141,34 +4344,40 @@ impl DeleteSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSizeConstraintSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonEmptyEntity(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSizeConstraintSetError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSizeConstraintSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSizeConstraintSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSizeConstraintSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSizeConstraintSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4215,34 +4424,40 @@ impl DeleteSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
                     )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSqlInjectionMatchSetError::WAFNonEmptyEntity(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
                     )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteSqlInjectionMatchSetError::WAFReferencedItem(err.msg),
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteSqlInjectionMatchSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSqlInjectionMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4293,30 +4508,43 @@ impl DeleteWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, DeleteWebACLError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWebACLError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWebACLError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWebACLError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWebACLError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, DeleteWebACLError::WAFStaleData(err.msg))
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperation(err.msg))
+                    return RusotoError::service(&res, DeleteWebACLError::WAFTagOperation(err.msg))
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(DeleteWebACLError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWebACLError::WAFTagOperationInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4363,24 +4591,40 @@ impl DeleteXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteXssMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteXssMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonEmptyEntityException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonEmptyEntity(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteXssMatchSetError::WAFNonEmptyEntity(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteXssMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteXssMatchSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(DeleteXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteXssMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4419,13 +4663,22 @@ impl GetByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetByteMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetByteMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetByteMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetByteMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4457,7 +4710,10 @@ impl GetChangeTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetChangeTokenError::WAFInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4489,14 +4745,16 @@ impl GetChangeTokenStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetChangeTokenStatusError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetChangeTokenStatusError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetChangeTokenStatusError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4531,13 +4789,22 @@ impl GetGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGeoMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGeoMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetGeoMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetGeoMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4573,13 +4840,13 @@ impl GetIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, GetIPSetError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, GetIPSetError::WAFInvalidAccount(err.msg))
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(&res, GetIPSetError::WAFNonexistentItem(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4613,14 +4880,16 @@ impl GetLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoggingConfigurationError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetLoggingConfigurationError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetLoggingConfigurationError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4653,14 +4922,16 @@ impl GetPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPermissionPolicyError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetPermissionPolicyError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetPermissionPolicyError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4695,13 +4966,22 @@ impl GetRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRateBasedRuleError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRateBasedRuleError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRateBasedRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRateBasedRuleError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4741,22 +5021,26 @@ impl GetRateBasedRuleManagedKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRateBasedRuleManagedKeysError::WAFInternalError(err.msg),
                     )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRateBasedRuleManagedKeysError::WAFInvalidAccount(err.msg),
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRateBasedRuleManagedKeysError::WAFInvalidParameter(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetRateBasedRuleManagedKeysError::WAFNonexistentItem(err.msg),
                     )
                 }
@@ -4801,13 +5085,22 @@ impl GetRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegexMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegexMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegexMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4843,17 +5136,22 @@ impl GetRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRegexPatternSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRegexPatternSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRegexPatternSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRegexPatternSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4889,13 +5187,13 @@ impl GetRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, GetRuleError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, GetRuleError::WAFInvalidAccount(err.msg))
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(&res, GetRuleError::WAFNonexistentItem(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4929,10 +5227,13 @@ impl GetRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, GetRuleGroupError::WAFInternalError(err.msg))
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetRuleGroupError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4965,12 +5266,16 @@ impl GetSampledRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSampledRequestsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSampledRequestsError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSampledRequestsError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5005,19 +5310,22 @@ impl GetSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSizeConstraintSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSizeConstraintSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSizeConstraintSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5053,19 +5361,22 @@ impl GetSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetSqlInjectionMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5101,13 +5412,13 @@ impl GetWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, GetWebACLError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, GetWebACLError::WAFInvalidAccount(err.msg))
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(&res, GetWebACLError::WAFNonexistentItem(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5143,13 +5454,22 @@ impl GetXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetXssMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetXssMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(GetXssMatchSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetXssMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5187,17 +5507,20 @@ impl ListActivatedRulesInRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListActivatedRulesInRuleGroupError::WAFInternalError(err.msg),
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListActivatedRulesInRuleGroupError::WAFInvalidParameter(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListActivatedRulesInRuleGroupError::WAFNonexistentItem(err.msg),
                     )
                 }
@@ -5239,10 +5562,16 @@ impl ListByteMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListByteMatchSetsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListByteMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListByteMatchSetsError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5275,10 +5604,16 @@ impl ListGeoMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGeoMatchSetsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListGeoMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGeoMatchSetsError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5311,10 +5646,10 @@ impl ListIPSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, ListIPSetsError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListIPSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, ListIPSetsError::WAFInvalidAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5349,17 +5684,20 @@ impl ListLoggingConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListLoggingConfigurationsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListLoggingConfigurationsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLoggingConfigurationsError::WAFInvalidParameter(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListLoggingConfigurationsError::WAFNonexistentItem(err.msg),
                     )
                 }
@@ -5397,12 +5735,16 @@ impl ListRateBasedRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRateBasedRulesError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRateBasedRulesError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRateBasedRulesError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5435,12 +5777,16 @@ impl ListRegexMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRegexMatchSetsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexMatchSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRegexMatchSetsError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5473,14 +5819,16 @@ impl ListRegexPatternSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRegexPatternSetsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRegexPatternSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListRegexPatternSetsError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5511,7 +5859,10 @@ impl ListRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRuleGroupsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListRuleGroupsError::WAFInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5543,10 +5894,10 @@ impl ListRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListRulesError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, ListRulesError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListRulesError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, ListRulesError::WAFInvalidAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5579,14 +5930,16 @@ impl ListSizeConstraintSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSizeConstraintSetsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSizeConstraintSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSizeConstraintSetsError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5619,14 +5972,16 @@ impl ListSqlInjectionMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSqlInjectionMatchSetsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListSqlInjectionMatchSetsError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSqlInjectionMatchSetsError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5659,14 +6014,16 @@ impl ListSubscribedRuleGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSubscribedRuleGroupsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListSubscribedRuleGroupsError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListSubscribedRuleGroupsError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5707,28 +6064,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::WAFBadRequest(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::WAFTagOperation(err.msg),
+                    )
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListTagsForResourceError::WAFTagOperationInternalError(err.msg),
                     )
                 }
@@ -5769,10 +6136,10 @@ impl ListWebACLsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, ListWebACLsError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListWebACLsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, ListWebACLsError::WAFInvalidAccount(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5805,10 +6172,16 @@ impl ListXssMatchSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListXssMatchSetsError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(ListXssMatchSetsError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListXssMatchSetsError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5845,24 +6218,28 @@ impl PutLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingConfigurationError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingConfigurationError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFServiceLinkedRoleErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutLoggingConfigurationError::WAFServiceLinkedRoleError(err.msg),
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutLoggingConfigurationError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutLoggingConfigurationError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5903,22 +6280,28 @@ impl PutPermissionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutPermissionPolicyError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidPermissionPolicyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         PutPermissionPolicyError::WAFInvalidPermissionPolicy(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutPermissionPolicyError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(PutPermissionPolicyError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutPermissionPolicyError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -5965,27 +6348,34 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(TagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::WAFBadRequest(err.msg))
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, TagResourceError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(TagResourceError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(&res, TagResourceError::WAFLimitsExceeded(err.msg))
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(TagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service(&res, TagResourceError::WAFTagOperation(err.msg))
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        TagResourceError::WAFTagOperationInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6031,24 +6421,34 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFBadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::WAFBadRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::WAFBadRequest(err.msg))
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UntagResourceError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFTagOperationException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperation(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::WAFTagOperation(err.msg))
                 }
                 "WAFTagOperationInternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::WAFTagOperationInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::WAFTagOperationInternalError(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6097,40 +6497,52 @@ impl UpdateByteMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateByteMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateByteMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6183,39 +6595,58 @@ impl UpdateGeoMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateGeoMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateGeoMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6269,31 +6700,43 @@ impl UpdateIPSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, UpdateIPSetError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, UpdateIPSetError::WAFInvalidAccount(err.msg))
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIPSetError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIPSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateIPSetError::WAFLimitsExceeded(err.msg))
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIPSetError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateIPSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(&res, UpdateIPSetError::WAFReferencedItem(err.msg))
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateIPSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, UpdateIPSetError::WAFStaleData(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6347,47 +6790,58 @@ impl UpdateRateBasedRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRateBasedRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRateBasedRuleError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6439,42 +6893,52 @@ impl UpdateRegexMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFDisallowedNameException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFDisallowedName(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFDisallowedName(err.msg),
+                    )
                 }
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6525,42 +6989,52 @@ impl UpdateRegexPatternSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexPatternSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexPatternSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexPatternSetError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidRegexPatternException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRegexPatternSetError::WAFInvalidRegexPattern(err.msg),
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexPatternSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateRegexPatternSetError::WAFNonexistentContainer(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexPatternSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRegexPatternSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRegexPatternSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6615,31 +7089,40 @@ impl UpdateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, UpdateRuleError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(&res, UpdateRuleError::WAFInvalidAccount(err.msg))
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateRuleError::WAFLimitsExceeded(err.msg))
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentContainer(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(&res, UpdateRuleError::WAFNonexistentItem(err.msg))
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(&res, UpdateRuleError::WAFReferencedItem(err.msg))
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, UpdateRuleError::WAFStaleData(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6689,27 +7172,43 @@ impl UpdateRuleGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleGroupError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleGroupError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleGroupError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleGroupError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleGroupError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRuleGroupError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateRuleGroupError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, UpdateRuleGroupError::WAFStaleData(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6761,49 +7260,58 @@ impl UpdateSizeConstraintSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidAccount(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFLimitsExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSizeConstraintSetError::WAFNonexistentContainer(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFReferencedItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSizeConstraintSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSizeConstraintSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6859,44 +7367,52 @@ impl UpdateSqlInjectionMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFInternalError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSqlInjectionMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSqlInjectionMatchSetError::WAFInvalidAccount(err.msg),
                     )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSqlInjectionMatchSetError::WAFInvalidOperation(err.msg),
                     )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSqlInjectionMatchSetError::WAFInvalidParameter(err.msg),
                     )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSqlInjectionMatchSetError::WAFLimitsExceeded(err.msg),
                     )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSqlInjectionMatchSetError::WAFNonexistentContainer(err.msg),
                     )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateSqlInjectionMatchSetError::WAFNonexistentItem(err.msg),
                     )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateSqlInjectionMatchSetError::WAFStaleData(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSqlInjectionMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -6959,38 +7475,58 @@ impl UpdateWebACLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInternalError(err.msg))
+                    return RusotoError::service(&res, UpdateWebACLError::WAFInternalError(err.msg))
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFInvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFNonexistentItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFReferencedItemException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFReferencedItem(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFReferencedItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFStaleData(err.msg))
+                    return RusotoError::service(&res, UpdateWebACLError::WAFStaleData(err.msg))
                 }
                 "WAFSubscriptionNotFoundException" => {
-                    return RusotoError::Service(UpdateWebACLError::WAFSubscriptionNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateWebACLError::WAFSubscriptionNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7043,36 +7579,52 @@ impl UpdateXssMatchSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WAFInternalErrorException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInternalError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFInternalError(err.msg),
+                    )
                 }
                 "WAFInvalidAccountException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidAccount(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFInvalidAccount(err.msg),
+                    )
                 }
                 "WAFInvalidOperationException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFInvalidOperation(err.msg),
+                    )
                 }
                 "WAFInvalidParameterException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFInvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFInvalidParameter(err.msg),
+                    )
                 }
                 "WAFLimitsExceededException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFLimitsExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFLimitsExceeded(err.msg),
+                    )
                 }
                 "WAFNonexistentContainerException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentContainer(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFNonexistentContainer(err.msg),
+                    )
                 }
                 "WAFNonexistentItemException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFNonexistentItem(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFNonexistentItem(err.msg),
+                    )
                 }
                 "WAFStaleDataException" => {
-                    return RusotoError::Service(UpdateXssMatchSetError::WAFStaleData(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateXssMatchSetError::WAFStaleData(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -7582,6 +8134,7 @@ impl WafClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -7597,6 +8150,7 @@ impl WafClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> WafClient {
         WafClient { client, region }
     }
@@ -7610,6 +8164,9 @@ impl Waf for WafClient {
         input: CreateByteMatchSetRequest,
     ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateByteMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7628,6 +8185,9 @@ impl Waf for WafClient {
         input: CreateGeoMatchSetRequest,
     ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGeoMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7646,6 +8206,9 @@ impl Waf for WafClient {
         input: CreateIPSetRequest,
     ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateIPSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7664,6 +8227,9 @@ impl Waf for WafClient {
         input: CreateRateBasedRuleRequest,
     ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRateBasedRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7682,6 +8248,9 @@ impl Waf for WafClient {
         input: CreateRegexMatchSetRequest,
     ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRegexMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7700,6 +8269,9 @@ impl Waf for WafClient {
         input: CreateRegexPatternSetRequest,
     ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRegexPatternSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7719,6 +8291,9 @@ impl Waf for WafClient {
         input: CreateRuleRequest,
     ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7737,6 +8312,9 @@ impl Waf for WafClient {
         input: CreateRuleGroupRequest,
     ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateRuleGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7755,6 +8333,9 @@ impl Waf for WafClient {
         input: CreateSizeConstraintSetRequest,
     ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSizeConstraintSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7775,6 +8356,9 @@ impl Waf for WafClient {
     ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateSqlInjectionMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7794,6 +8378,9 @@ impl Waf for WafClient {
         input: CreateWebACLRequest,
     ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWebACL");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7813,6 +8400,9 @@ impl Waf for WafClient {
     ) -> Result<CreateWebACLMigrationStackResponse, RusotoError<CreateWebACLMigrationStackError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWebACLMigrationStack");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateWebACLMigrationStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7832,6 +8422,9 @@ impl Waf for WafClient {
         input: CreateXssMatchSetRequest,
     ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateXssMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.CreateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7850,6 +8443,9 @@ impl Waf for WafClient {
         input: DeleteByteMatchSetRequest,
     ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteByteMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7868,6 +8464,9 @@ impl Waf for WafClient {
         input: DeleteGeoMatchSetRequest,
     ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGeoMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7886,6 +8485,9 @@ impl Waf for WafClient {
         input: DeleteIPSetRequest,
     ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteIPSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7905,6 +8507,9 @@ impl Waf for WafClient {
     ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteLoggingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteLoggingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7924,6 +8529,9 @@ impl Waf for WafClient {
         input: DeletePermissionPolicyRequest,
     ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeletePermissionPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeletePermissionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7943,6 +8551,9 @@ impl Waf for WafClient {
         input: DeleteRateBasedRuleRequest,
     ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRateBasedRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7961,6 +8572,9 @@ impl Waf for WafClient {
         input: DeleteRegexMatchSetRequest,
     ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRegexMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7979,6 +8593,9 @@ impl Waf for WafClient {
         input: DeleteRegexPatternSetRequest,
     ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRegexPatternSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7998,6 +8615,9 @@ impl Waf for WafClient {
         input: DeleteRuleRequest,
     ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8016,6 +8636,9 @@ impl Waf for WafClient {
         input: DeleteRuleGroupRequest,
     ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRuleGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8034,6 +8657,9 @@ impl Waf for WafClient {
         input: DeleteSizeConstraintSetRequest,
     ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSizeConstraintSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8054,6 +8680,9 @@ impl Waf for WafClient {
     ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteSqlInjectionMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8073,6 +8702,9 @@ impl Waf for WafClient {
         input: DeleteWebACLRequest,
     ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWebACL");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8091,6 +8723,9 @@ impl Waf for WafClient {
         input: DeleteXssMatchSetRequest,
     ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteXssMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.DeleteXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8109,6 +8744,9 @@ impl Waf for WafClient {
         input: GetByteMatchSetRequest,
     ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetByteMatchSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8126,6 +8764,9 @@ impl Waf for WafClient {
         &self,
     ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetChangeToken");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeToken");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -8143,6 +8784,9 @@ impl Waf for WafClient {
         input: GetChangeTokenStatusRequest,
     ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetChangeTokenStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetChangeTokenStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8162,6 +8806,9 @@ impl Waf for WafClient {
         input: GetGeoMatchSetRequest,
     ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetGeoMatchSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8180,6 +8827,9 @@ impl Waf for WafClient {
         input: GetIPSetRequest,
     ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetIPSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8198,6 +8848,9 @@ impl Waf for WafClient {
         input: GetLoggingConfigurationRequest,
     ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetLoggingConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetLoggingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8217,6 +8870,9 @@ impl Waf for WafClient {
         input: GetPermissionPolicyRequest,
     ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetPermissionPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetPermissionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8235,6 +8891,9 @@ impl Waf for WafClient {
         input: GetRateBasedRuleRequest,
     ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRateBasedRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8254,6 +8913,9 @@ impl Waf for WafClient {
     ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRateBasedRuleManagedKeys");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSWAF_20150824.GetRateBasedRuleManagedKeys",
@@ -8276,6 +8938,9 @@ impl Waf for WafClient {
         input: GetRegexMatchSetRequest,
     ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRegexMatchSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8294,6 +8959,9 @@ impl Waf for WafClient {
         input: GetRegexPatternSetRequest,
     ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRegexPatternSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8312,6 +8980,9 @@ impl Waf for WafClient {
         input: GetRuleRequest,
     ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRule");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8330,6 +9001,9 @@ impl Waf for WafClient {
         input: GetRuleGroupRequest,
     ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetRuleGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8348,6 +9022,9 @@ impl Waf for WafClient {
         input: GetSampledRequestsRequest,
     ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSampledRequests");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSampledRequests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8366,6 +9043,9 @@ impl Waf for WafClient {
         input: GetSizeConstraintSetRequest,
     ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSizeConstraintSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8385,6 +9065,9 @@ impl Waf for WafClient {
         input: GetSqlInjectionMatchSetRequest,
     ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetSqlInjectionMatchSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8404,6 +9087,9 @@ impl Waf for WafClient {
         input: GetWebACLRequest,
     ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetWebACL");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8422,6 +9108,9 @@ impl Waf for WafClient {
         input: GetXssMatchSetRequest,
     ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetXssMatchSet");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.GetXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8443,6 +9132,9 @@ impl Waf for WafClient {
         RusotoError<ListActivatedRulesInRuleGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListActivatedRulesInRuleGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "AWSWAF_20150824.ListActivatedRulesInRuleGroup",
@@ -8465,6 +9157,9 @@ impl Waf for WafClient {
         input: ListByteMatchSetsRequest,
     ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListByteMatchSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListByteMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8483,6 +9178,9 @@ impl Waf for WafClient {
         input: ListGeoMatchSetsRequest,
     ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGeoMatchSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListGeoMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8501,6 +9199,9 @@ impl Waf for WafClient {
         input: ListIPSetsRequest,
     ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListIPSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListIPSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8520,6 +9221,9 @@ impl Waf for WafClient {
     ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListLoggingConfigurations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListLoggingConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8539,6 +9243,9 @@ impl Waf for WafClient {
         input: ListRateBasedRulesRequest,
     ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRateBasedRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRateBasedRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8557,6 +9264,9 @@ impl Waf for WafClient {
         input: ListRegexMatchSetsRequest,
     ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRegexMatchSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8575,6 +9285,9 @@ impl Waf for WafClient {
         input: ListRegexPatternSetsRequest,
     ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRegexPatternSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRegexPatternSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8594,6 +9307,9 @@ impl Waf for WafClient {
         input: ListRuleGroupsRequest,
     ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRuleGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRuleGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8612,6 +9328,9 @@ impl Waf for WafClient {
         input: ListRulesRequest,
     ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8630,6 +9349,9 @@ impl Waf for WafClient {
         input: ListSizeConstraintSetsRequest,
     ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSizeConstraintSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSizeConstraintSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8650,6 +9372,9 @@ impl Waf for WafClient {
     ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSqlInjectionMatchSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSqlInjectionMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8669,6 +9394,9 @@ impl Waf for WafClient {
         input: ListSubscribedRuleGroupsRequest,
     ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListSubscribedRuleGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListSubscribedRuleGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8688,6 +9416,9 @@ impl Waf for WafClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8706,6 +9437,9 @@ impl Waf for WafClient {
         input: ListWebACLsRequest,
     ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListWebACLs");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListWebACLs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8724,6 +9458,9 @@ impl Waf for WafClient {
         input: ListXssMatchSetsRequest,
     ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListXssMatchSets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "AWSWAF_20150824.ListXssMatchSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8742,6 +9479,9 @@ impl Waf for WafClient {
         input: PutLoggingConfigurationRequest,
     ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutLoggingConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.PutLoggingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8761,6 +9501,9 @@ impl Waf for WafClient {
         input: PutPermissionPolicyRequest,
     ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutPermissionPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.PutPermissionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8779,6 +9522,9 @@ impl Waf for WafClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8797,6 +9543,9 @@ impl Waf for WafClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8815,6 +9564,9 @@ impl Waf for WafClient {
         input: UpdateByteMatchSetRequest,
     ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateByteMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateByteMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8833,6 +9585,9 @@ impl Waf for WafClient {
         input: UpdateGeoMatchSetRequest,
     ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateGeoMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateGeoMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8851,6 +9606,9 @@ impl Waf for WafClient {
         input: UpdateIPSetRequest,
     ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateIPSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateIPSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8869,6 +9627,9 @@ impl Waf for WafClient {
         input: UpdateRateBasedRuleRequest,
     ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRateBasedRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRateBasedRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8887,6 +9648,9 @@ impl Waf for WafClient {
         input: UpdateRegexMatchSetRequest,
     ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRegexMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8905,6 +9669,9 @@ impl Waf for WafClient {
         input: UpdateRegexPatternSetRequest,
     ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRegexPatternSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRegexPatternSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8924,6 +9691,9 @@ impl Waf for WafClient {
         input: UpdateRuleRequest,
     ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8942,6 +9712,9 @@ impl Waf for WafClient {
         input: UpdateRuleGroupRequest,
     ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRuleGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateRuleGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8960,6 +9733,9 @@ impl Waf for WafClient {
         input: UpdateSizeConstraintSetRequest,
     ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSizeConstraintSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSizeConstraintSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8980,6 +9756,9 @@ impl Waf for WafClient {
     ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateSqlInjectionMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateSqlInjectionMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8999,6 +9778,9 @@ impl Waf for WafClient {
         input: UpdateWebACLRequest,
     ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateWebACL");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateWebACL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9017,6 +9799,9 @@ impl Waf for WafClient {
         input: UpdateXssMatchSetRequest,
     ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateXssMatchSet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "AWSWAF_20150824.UpdateXssMatchSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9029,3 +9814,884 @@ impl Waf for WafClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateXssMatchSetResponse, _>()
     }
 }
+
+/// A blocking client for the WAF API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `WafClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WafBlockingClient {
+    client: WafClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WafBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WafBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        WafBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WafBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WafBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> WafBlockingClient {
+        WafBlockingClient {
+            client: WafClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <code>ByteMatchSet</code>. You then use <a>UpdateByteMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>ByteMatchSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>BadBot</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateByteMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateByteMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_byte_match_set`.
+    pub fn create_byte_match_set(
+        &self,
+        input: CreateByteMatchSetRequest,
+    ) -> Result<CreateByteMatchSetResponse, RusotoError<CreateByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_byte_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates an <a>GeoMatchSet</a>, which you use to specify which web requests you want to allow or block based on the country that the requests originate from. For example, if you&#39;re receiving a lot of requests from one or more countries and you want to block the requests, you can create an <code>GeoMatchSet</code> that contains those countries and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateGeoMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSetSet</code> request to specify the countries that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_geo_match_set`.
+    pub fn create_geo_match_set(
+        &self,
+        input: CreateGeoMatchSetRequest,
+    ) -> Result<CreateGeoMatchSetResponse, RusotoError<CreateGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_geo_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates an <a>IPSet</a>, which you use to specify which web requests that you want to allow or block based on the IP addresses that the requests originate from. For example, if you&#39;re receiving a lot of requests from one or more individual IP addresses or one or more ranges of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that contains those IP addresses and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateIPSet</code> request.</p> </li> <li> <p>Submit a <code>CreateIPSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_ip_set`.
+    pub fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        self.runtime.block_on(self.client.create_ip_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <a>RateBasedRule</a>. The <code>RateBasedRule</code> contains a <code>RateLimit</code>, which specifies the maximum number of requests that AWS WAF allows from a specified IP address in a five-minute period. The <code>RateBasedRule</code> also contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to count or block if these requests exceed the <code>RateLimit</code>.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request not only must exceed the <code>RateLimit</code>, but it also must match all the conditions to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 1,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that meet the conditions in the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions must be received at a rate of more than 1,000 requests every five minutes. If both conditions are met and the rate is exceeded, AWS WAF blocks the requests. If the rate drops below 1,000 for a five-minute period, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 1,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p> <p>To create and configure a <code>RateBasedRule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the rule. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRateBasedRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRateBasedRule</code> request to specify the predicates that you want to include in the rule.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RateBasedRule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_rate_based_rule`.
+    pub fn create_rate_based_rule(
+        &self,
+        input: CreateRateBasedRuleRequest,
+    ) -> Result<CreateRateBasedRuleResponse, RusotoError<CreateRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.create_rate_based_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <a>RegexMatchSet</a>. You then use <a>UpdateRegexMatchSet</a> to identify the part of a web request that you want AWS WAF to inspect, such as the values of the <code>User-Agent</code> header or the query string. For example, you can create a <code>RegexMatchSet</code> that contains a <code>RegexMatchTuple</code> that looks for any requests with <code>User-Agent</code> headers that match a <code>RegexPatternSet</code> with pattern <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexMatchSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value, using a <code>RegexPatternSet</code>, that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_regex_match_set`.
+    pub fn create_regex_match_set(
+        &self,
+        input: CreateRegexMatchSetRequest,
+    ) -> Result<CreateRegexMatchSetResponse, RusotoError<CreateRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_regex_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <code>RegexPatternSet</code>. You then use <a>UpdateRegexPatternSet</a> to specify the regular expression (regex) pattern that you want AWS WAF to search for, such as <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Submit a <code>CreateRegexPatternSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateRegexPatternSet</a> request to specify the string that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_regex_pattern_set`.
+    pub fn create_regex_pattern_set(
+        &self,
+        input: CreateRegexPatternSetRequest,
+    ) -> Result<CreateRegexPatternSetResponse, RusotoError<CreateRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.create_regex_pattern_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <code>Rule</code>, which contains the <code>IPSet</code> objects, <code>ByteMatchSet</code> objects, and other predicates that identify the requests that you want to block. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed or blocked. For example, suppose that you add the following to a <code>Rule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to blocks requests that satisfy the <code>Rule</code>. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>. For more information, see <a>CreateByteMatchSet</a>, <a>CreateIPSet</a>, and <a>CreateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateRule</code> request.</p> </li> <li> <p>Submit a <code>CreateRule</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to specify the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. For more information, see <a>CreateWebACL</a>.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_rule`.
+    pub fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<CreateRuleResponse, RusotoError<CreateRuleError>> {
+        self.runtime.block_on(self.client.create_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <code>RuleGroup</code>. A rule group is a collection of predefined rules that you add to a web ACL. You use <a>UpdateRuleGroup</a> to add rules to the rule group.</p> <p>Rule groups are subject to the following limits:</p> <ul> <li> <p>Three rule groups per account. You can request an increase to this limit by contacting customer support.</p> </li> <li> <p>One rule group per web ACL.</p> </li> <li> <p>Ten rules per rule group.</p> </li> </ul> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_rule_group`.
+    pub fn create_rule_group(
+        &self,
+        input: CreateRuleGroupRequest,
+    ) -> Result<CreateRuleGroupResponse, RusotoError<CreateRuleGroupError>> {
+        self.runtime.block_on(self.client.create_rule_group(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <code>SizeConstraintSet</code>. You then use <a>UpdateSizeConstraintSet</a> to identify the part of a web request that you want AWS WAF to check for length, such as the length of the <code>User-Agent</code> header or the length of the query string. For example, you can create a <code>SizeConstraintSet</code> that matches any requests that have a query string that is longer than 100 bytes. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSizeConstraintSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <a>UpdateSizeConstraintSet</a> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_size_constraint_set`.
+    pub fn create_size_constraint_set(
+        &self,
+        input: CreateSizeConstraintSetRequest,
+    ) -> Result<CreateSizeConstraintSetResponse, RusotoError<CreateSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.create_size_constraint_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <a>SqlInjectionMatchSet</a>, which you use to allow, block, or count requests that contain snippets of SQL code in a specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateSqlInjectionMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count malicious SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_sql_injection_match_set`.
+    pub fn create_sql_injection_match_set(
+        &self,
+        input: CreateSqlInjectionMatchSetRequest,
+    ) -> Result<CreateSqlInjectionMatchSetResponse, RusotoError<CreateSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.create_sql_injection_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates a <code>WebACL</code>, which contains the <code>Rules</code> that identify the CloudFront web requests that you want to allow, block, or count. AWS WAF evaluates <code>Rules</code> in order based on the value of <code>Priority</code> for each <code>Rule</code>.</p> <p>You also specify a default action, either <code>ALLOW</code> or <code>BLOCK</code>. If a web request doesn&#39;t match any of the <code>Rules</code> in a <code>WebACL</code>, AWS WAF responds to the request with the default action. </p> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>ByteMatchSet</code> objects and other predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateWebACL</code> request.</p> </li> <li> <p>Submit a <code>CreateWebACL</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <a>UpdateWebACL</a> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution.</p> </li> </ol> <p>For more information about how to use the AWS WAF API, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_web_acl`.
+    pub fn create_web_acl(
+        &self,
+        input: CreateWebACLRequest,
+    ) -> Result<CreateWebACLResponse, RusotoError<CreateWebACLError>> {
+        self.runtime.block_on(self.client.create_web_acl(input))
+    }
+
+    /// <p>Creates an AWS CloudFormation WAFV2 template for the specified web ACL in the specified Amazon S3 bucket. Then, in CloudFormation, you create a stack from the template, to create the web ACL and its resources in AWS WAFV2. Use this to migrate your AWS WAF Classic web ACL to the latest version of AWS WAF.</p> <p>This is part of a larger migration procedure for web ACLs from AWS WAF Classic to the latest version of AWS WAF. For the full procedure, including caveats and manual steps to complete the migration and switch over to the new web ACL, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-migrating-from-classic.html">Migrating your AWS WAF Classic resources to AWS WAF</a> in the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. </p>
+    ///
+    /// See `Waf::create_web_acl_migration_stack`.
+    pub fn create_web_acl_migration_stack(
+        &self,
+        input: CreateWebACLMigrationStackRequest,
+    ) -> Result<CreateWebACLMigrationStackResponse, RusotoError<CreateWebACLMigrationStackError>>
+    {
+        self.runtime
+            .block_on(self.client.create_web_acl_migration_stack(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Creates an <a>XssMatchSet</a>, which you use to allow, block, or count requests that contain cross-site scripting attacks in the specified part of web requests. AWS WAF searches for character sequences that are likely to be malicious strings.</p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>CreateXssMatchSet</code> request.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateXssMatchSet</a> request.</p> </li> <li> <p>Submit an <a>UpdateXssMatchSet</a> request to specify the parts of web requests in which you want to allow, block, or count cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::create_xss_match_set`.
+    pub fn create_xss_match_set(
+        &self,
+        input: CreateXssMatchSetRequest,
+    ) -> Result<CreateXssMatchSetResponse, RusotoError<CreateXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.create_xss_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>ByteMatchSet</a>. You can&#39;t delete a <code>ByteMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>ByteMatchTuple</a> objects (any filters).</p> <p>If you just want to remove a <code>ByteMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>ByteMatchSet</code> to remove filters, if any. For more information, see <a>UpdateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteByteMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteByteMatchSet</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_byte_match_set`.
+    pub fn delete_byte_match_set(
+        &self,
+        input: DeleteByteMatchSetRequest,
+    ) -> Result<DeleteByteMatchSetResponse, RusotoError<DeleteByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_byte_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>GeoMatchSet</a>. You can&#39;t delete a <code>GeoMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any countries.</p> <p>If you just want to remove a <code>GeoMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>GeoMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>GeoMatchSet</code> to remove any countries. For more information, see <a>UpdateGeoMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteGeoMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteGeoMatchSet</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_geo_match_set`.
+    pub fn delete_geo_match_set(
+        &self,
+        input: DeleteGeoMatchSetRequest,
+    ) -> Result<DeleteGeoMatchSetResponse, RusotoError<DeleteGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_geo_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes an <a>IPSet</a>. You can&#39;t delete an <code>IPSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any IP addresses.</p> <p>If you just want to remove an <code>IPSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>IPSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>IPSet</code> to remove IP address ranges, if any. For more information, see <a>UpdateIPSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteIPSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteIPSet</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_ip_set`.
+    pub fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+        self.runtime.block_on(self.client.delete_ip_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes the <a>LoggingConfiguration</a> from the specified web ACL.</p></p>
+    ///
+    /// See `Waf::delete_logging_configuration`.
+    pub fn delete_logging_configuration(
+        &self,
+        input: DeleteLoggingConfigurationRequest,
+    ) -> Result<DeleteLoggingConfigurationResponse, RusotoError<DeleteLoggingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_logging_configuration(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes an IAM policy from the specified RuleGroup.</p> <p>The user making the request must be the owner of the RuleGroup.</p></p>
+    ///
+    /// See `Waf::delete_permission_policy`.
+    pub fn delete_permission_policy(
+        &self,
+        input: DeletePermissionPolicyRequest,
+    ) -> Result<DeletePermissionPolicyResponse, RusotoError<DeletePermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_permission_policy(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>RateBasedRule</a>. You can&#39;t delete a rule if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a rule from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RateBasedRule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RateBasedRule</code> to remove predicates, if any. For more information, see <a>UpdateRateBasedRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRateBasedRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRateBasedRule</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_rate_based_rule`.
+    pub fn delete_rate_based_rule(
+        &self,
+        input: DeleteRateBasedRuleRequest,
+    ) -> Result<DeleteRateBasedRuleResponse, RusotoError<DeleteRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_rate_based_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>RegexMatchSet</a>. You can&#39;t delete a <code>RegexMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <code>RegexMatchTuples</code> objects (any filters).</p> <p>If you just want to remove a <code>RegexMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>RegexMatchSet</code> to remove filters, if any. For more information, see <a>UpdateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRegexMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteRegexMatchSet</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_regex_match_set`.
+    pub fn delete_regex_match_set(
+        &self,
+        input: DeleteRegexMatchSetRequest,
+    ) -> Result<DeleteRegexMatchSetResponse, RusotoError<DeleteRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_regex_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>RegexPatternSet</a>. You can&#39;t delete a <code>RegexPatternSet</code> if it&#39;s still used in any <code>RegexMatchSet</code> or if the <code>RegexPatternSet</code> is not empty. </p></p>
+    ///
+    /// See `Waf::delete_regex_pattern_set`.
+    pub fn delete_regex_pattern_set(
+        &self,
+        input: DeleteRegexPatternSetRequest,
+    ) -> Result<DeleteRegexPatternSetResponse, RusotoError<DeleteRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.delete_regex_pattern_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>Rule</a>. You can&#39;t delete a <code>Rule</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any predicates, such as <code>ByteMatchSet</code> objects.</p> <p>If you just want to remove a <code>Rule</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>Rule</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>Rule</code> to remove predicates, if any. For more information, see <a>UpdateRule</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRule</code> request.</p> </li> <li> <p>Submit a <code>DeleteRule</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_rule`.
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<DeleteRuleResponse, RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>RuleGroup</a>. You can&#39;t delete a <code>RuleGroup</code> if it&#39;s still used in any <code>WebACL</code> objects or if it still includes any rules.</p> <p>If you just want to remove a <code>RuleGroup</code> from a <code>WebACL</code>, use <a>UpdateWebACL</a>.</p> <p>To permanently delete a <code>RuleGroup</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>RuleGroup</code> to remove rules, if any. For more information, see <a>UpdateRuleGroup</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteRuleGroup</code> request.</p> </li> <li> <p>Submit a <code>DeleteRuleGroup</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_rule_group`.
+    pub fn delete_rule_group(
+        &self,
+        input: DeleteRuleGroupRequest,
+    ) -> Result<DeleteRuleGroupResponse, RusotoError<DeleteRuleGroupError>> {
+        self.runtime.block_on(self.client.delete_rule_group(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>SizeConstraintSet</a>. You can&#39;t delete a <code>SizeConstraintSet</code> if it&#39;s still used in any <code>Rules</code> or if it still includes any <a>SizeConstraint</a> objects (any filters).</p> <p>If you just want to remove a <code>SizeConstraintSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>SizeConstraintSet</code> to remove filters, if any. For more information, see <a>UpdateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSizeConstraintSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSizeConstraintSet</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_size_constraint_set`.
+    pub fn delete_size_constraint_set(
+        &self,
+        input: DeleteSizeConstraintSetRequest,
+    ) -> Result<DeleteSizeConstraintSetResponse, RusotoError<DeleteSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.delete_size_constraint_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>SqlInjectionMatchSet</a>. You can&#39;t delete a <code>SqlInjectionMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>SqlInjectionMatchTuple</a> objects.</p> <p>If you just want to remove a <code>SqlInjectionMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete a <code>SqlInjectionMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>SqlInjectionMatchSet</code> to remove filters, if any. For more information, see <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteSqlInjectionMatchSet</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_sql_injection_match_set`.
+    pub fn delete_sql_injection_match_set(
+        &self,
+        input: DeleteSqlInjectionMatchSetRequest,
+    ) -> Result<DeleteSqlInjectionMatchSetResponse, RusotoError<DeleteSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_sql_injection_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes a <a>WebACL</a>. You can&#39;t delete a <code>WebACL</code> if it still contains any <code>Rules</code>.</p> <p>To delete a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Update the <code>WebACL</code> to remove <code>Rules</code>, if any. For more information, see <a>UpdateWebACL</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteWebACL</code> request.</p> </li> <li> <p>Submit a <code>DeleteWebACL</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_web_acl`.
+    pub fn delete_web_acl(
+        &self,
+        input: DeleteWebACLRequest,
+    ) -> Result<DeleteWebACLResponse, RusotoError<DeleteWebACLError>> {
+        self.runtime.block_on(self.client.delete_web_acl(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Permanently deletes an <a>XssMatchSet</a>. You can&#39;t delete an <code>XssMatchSet</code> if it&#39;s still used in any <code>Rules</code> or if it still contains any <a>XssMatchTuple</a> objects.</p> <p>If you just want to remove an <code>XssMatchSet</code> from a <code>Rule</code>, use <a>UpdateRule</a>.</p> <p>To permanently delete an <code>XssMatchSet</code> from AWS WAF, perform the following steps:</p> <ol> <li> <p>Update the <code>XssMatchSet</code> to remove filters, if any. For more information, see <a>UpdateXssMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of a <code>DeleteXssMatchSet</code> request.</p> </li> <li> <p>Submit a <code>DeleteXssMatchSet</code> request.</p> </li> </ol></p>
+    ///
+    /// See `Waf::delete_xss_match_set`.
+    pub fn delete_xss_match_set(
+        &self,
+        input: DeleteXssMatchSetRequest,
+    ) -> Result<DeleteXssMatchSetResponse, RusotoError<DeleteXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.delete_xss_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>ByteMatchSet</a> specified by <code>ByteMatchSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_byte_match_set`.
+    pub fn get_byte_match_set(
+        &self,
+        input: GetByteMatchSetRequest,
+    ) -> Result<GetByteMatchSetResponse, RusotoError<GetByteMatchSetError>> {
+        self.runtime.block_on(self.client.get_byte_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>When you want to create, update, or delete AWS WAF objects, get a change token and include the change token in the create, update, or delete request. Change tokens ensure that your application doesn&#39;t submit conflicting requests to AWS WAF.</p> <p>Each create, update, or delete request must use a unique change token. If your application submits a <code>GetChangeToken</code> request and then submits a second <code>GetChangeToken</code> request before submitting a create, update, or delete request, the second <code>GetChangeToken</code> request returns the same value as the first <code>GetChangeToken</code> request.</p> <p>When you use a change token in a create, update, or delete request, the status of the change token changes to <code>PENDING</code>, which indicates that AWS WAF is propagating the change to all AWS WAF servers. Use <code>GetChangeTokenStatus</code> to determine the status of your change token.</p></p>
+    ///
+    /// See `Waf::get_change_token`.
+    pub fn get_change_token(
+        &self,
+    ) -> Result<GetChangeTokenResponse, RusotoError<GetChangeTokenError>> {
+        self.runtime.block_on(self.client.get_change_token())
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the status of a <code>ChangeToken</code> that you got by calling <a>GetChangeToken</a>. <code>ChangeTokenStatus</code> is one of the following values:</p> <ul> <li> <p> <code>PROVISIONED</code>: You requested the change token by calling <code>GetChangeToken</code>, but you haven&#39;t used it yet in a call to create, update, or delete an AWS WAF object.</p> </li> <li> <p> <code>PENDING</code>: AWS WAF is propagating the create, update, or delete request to all AWS WAF servers.</p> </li> <li> <p> <code>INSYNC</code>: Propagation is complete.</p> </li> </ul></p>
+    ///
+    /// See `Waf::get_change_token_status`.
+    pub fn get_change_token_status(
+        &self,
+        input: GetChangeTokenStatusRequest,
+    ) -> Result<GetChangeTokenStatusResponse, RusotoError<GetChangeTokenStatusError>> {
+        self.runtime
+            .block_on(self.client.get_change_token_status(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>GeoMatchSet</a> that is specified by <code>GeoMatchSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_geo_match_set`.
+    pub fn get_geo_match_set(
+        &self,
+        input: GetGeoMatchSetRequest,
+    ) -> Result<GetGeoMatchSetResponse, RusotoError<GetGeoMatchSetError>> {
+        self.runtime.block_on(self.client.get_geo_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>IPSet</a> that is specified by <code>IPSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_ip_set`.
+    pub fn get_ip_set(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+        self.runtime.block_on(self.client.get_ip_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>LoggingConfiguration</a> for the specified web ACL.</p></p>
+    ///
+    /// See `Waf::get_logging_configuration`.
+    pub fn get_logging_configuration(
+        &self,
+        input: GetLoggingConfigurationRequest,
+    ) -> Result<GetLoggingConfigurationResponse, RusotoError<GetLoggingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_logging_configuration(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the IAM policy attached to the RuleGroup.</p></p>
+    ///
+    /// See `Waf::get_permission_policy`.
+    pub fn get_permission_policy(
+        &self,
+        input: GetPermissionPolicyRequest,
+    ) -> Result<GetPermissionPolicyResponse, RusotoError<GetPermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_permission_policy(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>RateBasedRule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRateBasedRule</code> request.</p></p>
+    ///
+    /// See `Waf::get_rate_based_rule`.
+    pub fn get_rate_based_rule(
+        &self,
+        input: GetRateBasedRuleRequest,
+    ) -> Result<GetRateBasedRuleResponse, RusotoError<GetRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.get_rate_based_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of IP addresses currently being blocked by the <a>RateBasedRule</a> that is specified by the <code>RuleId</code>. The maximum number of managed keys that will be blocked is 10,000. If more than 10,000 addresses exceed the rate limit, the 10,000 addresses with the highest rates will be blocked.</p></p>
+    ///
+    /// See `Waf::get_rate_based_rule_managed_keys`.
+    pub fn get_rate_based_rule_managed_keys(
+        &self,
+        input: GetRateBasedRuleManagedKeysRequest,
+    ) -> Result<GetRateBasedRuleManagedKeysResponse, RusotoError<GetRateBasedRuleManagedKeysError>>
+    {
+        self.runtime
+            .block_on(self.client.get_rate_based_rule_managed_keys(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>RegexMatchSet</a> specified by <code>RegexMatchSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_regex_match_set`.
+    pub fn get_regex_match_set(
+        &self,
+        input: GetRegexMatchSetRequest,
+    ) -> Result<GetRegexMatchSetResponse, RusotoError<GetRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.get_regex_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>RegexPatternSet</a> specified by <code>RegexPatternSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_regex_pattern_set`.
+    pub fn get_regex_pattern_set(
+        &self,
+        input: GetRegexPatternSetRequest,
+    ) -> Result<GetRegexPatternSetResponse, RusotoError<GetRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.get_regex_pattern_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>Rule</a> that is specified by the <code>RuleId</code> that you included in the <code>GetRule</code> request.</p></p>
+    ///
+    /// See `Waf::get_rule`.
+    pub fn get_rule(
+        &self,
+        input: GetRuleRequest,
+    ) -> Result<GetRuleResponse, RusotoError<GetRuleError>> {
+        self.runtime.block_on(self.client.get_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>RuleGroup</a> that is specified by the <code>RuleGroupId</code> that you included in the <code>GetRuleGroup</code> request.</p> <p>To view the rules in a rule group, use <a>ListActivatedRulesInRuleGroup</a>.</p></p>
+    ///
+    /// See `Waf::get_rule_group`.
+    pub fn get_rule_group(
+        &self,
+        input: GetRuleGroupRequest,
+    ) -> Result<GetRuleGroupResponse, RusotoError<GetRuleGroupError>> {
+        self.runtime.block_on(self.client.get_rule_group(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Gets detailed information about a specified number of requests--a sample--that AWS WAF randomly selects from among the first 5,000 requests that your AWS resource received during a time range that you choose. You can specify a sample size of up to 500 requests, and you can specify any time range in the previous three hours.</p> <p> <code>GetSampledRequests</code> returns a time range, which is usually the time range that you specified. However, if your resource (such as a CloudFront distribution) received 5,000 requests before the specified time range elapsed, <code>GetSampledRequests</code> returns an updated time range. This new time range indicates the actual period during which AWS WAF selected the requests in the sample.</p></p>
+    ///
+    /// See `Waf::get_sampled_requests`.
+    pub fn get_sampled_requests(
+        &self,
+        input: GetSampledRequestsRequest,
+    ) -> Result<GetSampledRequestsResponse, RusotoError<GetSampledRequestsError>> {
+        self.runtime
+            .block_on(self.client.get_sampled_requests(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>SizeConstraintSet</a> specified by <code>SizeConstraintSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_size_constraint_set`.
+    pub fn get_size_constraint_set(
+        &self,
+        input: GetSizeConstraintSetRequest,
+    ) -> Result<GetSizeConstraintSetResponse, RusotoError<GetSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.get_size_constraint_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>SqlInjectionMatchSet</a> that is specified by <code>SqlInjectionMatchSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_sql_injection_match_set`.
+    pub fn get_sql_injection_match_set(
+        &self,
+        input: GetSqlInjectionMatchSetRequest,
+    ) -> Result<GetSqlInjectionMatchSetResponse, RusotoError<GetSqlInjectionMatchSetError>> {
+        self.runtime
+            .block_on(self.client.get_sql_injection_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>WebACL</a> that is specified by <code>WebACLId</code>.</p></p>
+    ///
+    /// See `Waf::get_web_acl`.
+    pub fn get_web_acl(
+        &self,
+        input: GetWebACLRequest,
+    ) -> Result<GetWebACLResponse, RusotoError<GetWebACLError>> {
+        self.runtime.block_on(self.client.get_web_acl(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns the <a>XssMatchSet</a> that is specified by <code>XssMatchSetId</code>.</p></p>
+    ///
+    /// See `Waf::get_xss_match_set`.
+    pub fn get_xss_match_set(
+        &self,
+        input: GetXssMatchSetRequest,
+    ) -> Result<GetXssMatchSetResponse, RusotoError<GetXssMatchSetError>> {
+        self.runtime.block_on(self.client.get_xss_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>ActivatedRule</a> objects.</p></p>
+    ///
+    /// See `Waf::list_activated_rules_in_rule_group`.
+    pub fn list_activated_rules_in_rule_group(
+        &self,
+        input: ListActivatedRulesInRuleGroupRequest,
+    ) -> Result<
+        ListActivatedRulesInRuleGroupResponse,
+        RusotoError<ListActivatedRulesInRuleGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_activated_rules_in_rule_group(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>ByteMatchSetSummary</a> objects.</p></p>
+    ///
+    /// See `Waf::list_byte_match_sets`.
+    pub fn list_byte_match_sets(
+        &self,
+        input: ListByteMatchSetsRequest,
+    ) -> Result<ListByteMatchSetsResponse, RusotoError<ListByteMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_byte_match_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>GeoMatchSetSummary</a> objects in the response.</p></p>
+    ///
+    /// See `Waf::list_geo_match_sets`.
+    pub fn list_geo_match_sets(
+        &self,
+        input: ListGeoMatchSetsRequest,
+    ) -> Result<ListGeoMatchSetsResponse, RusotoError<ListGeoMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_geo_match_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>IPSetSummary</a> objects in the response.</p></p>
+    ///
+    /// See `Waf::list_ip_sets`.
+    pub fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+        self.runtime.block_on(self.client.list_ip_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>LoggingConfiguration</a> objects.</p></p>
+    ///
+    /// See `Waf::list_logging_configurations`.
+    pub fn list_logging_configurations(
+        &self,
+        input: ListLoggingConfigurationsRequest,
+    ) -> Result<ListLoggingConfigurationsResponse, RusotoError<ListLoggingConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_logging_configurations(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>RuleSummary</a> objects.</p></p>
+    ///
+    /// See `Waf::list_rate_based_rules`.
+    pub fn list_rate_based_rules(
+        &self,
+        input: ListRateBasedRulesRequest,
+    ) -> Result<ListRateBasedRulesResponse, RusotoError<ListRateBasedRulesError>> {
+        self.runtime
+            .block_on(self.client.list_rate_based_rules(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>RegexMatchSetSummary</a> objects.</p></p>
+    ///
+    /// See `Waf::list_regex_match_sets`.
+    pub fn list_regex_match_sets(
+        &self,
+        input: ListRegexMatchSetsRequest,
+    ) -> Result<ListRegexMatchSetsResponse, RusotoError<ListRegexMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_regex_match_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>RegexPatternSetSummary</a> objects.</p></p>
+    ///
+    /// See `Waf::list_regex_pattern_sets`.
+    pub fn list_regex_pattern_sets(
+        &self,
+        input: ListRegexPatternSetsRequest,
+    ) -> Result<ListRegexPatternSetsResponse, RusotoError<ListRegexPatternSetsError>> {
+        self.runtime
+            .block_on(self.client.list_regex_pattern_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>RuleGroup</a> objects.</p></p>
+    ///
+    /// See `Waf::list_rule_groups`.
+    pub fn list_rule_groups(
+        &self,
+        input: ListRuleGroupsRequest,
+    ) -> Result<ListRuleGroupsResponse, RusotoError<ListRuleGroupsError>> {
+        self.runtime.block_on(self.client.list_rule_groups(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>RuleSummary</a> objects.</p></p>
+    ///
+    /// See `Waf::list_rules`.
+    pub fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+        self.runtime.block_on(self.client.list_rules(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>SizeConstraintSetSummary</a> objects.</p></p>
+    ///
+    /// See `Waf::list_size_constraint_sets`.
+    pub fn list_size_constraint_sets(
+        &self,
+        input: ListSizeConstraintSetsRequest,
+    ) -> Result<ListSizeConstraintSetsResponse, RusotoError<ListSizeConstraintSetsError>> {
+        self.runtime
+            .block_on(self.client.list_size_constraint_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>SqlInjectionMatchSet</a> objects.</p></p>
+    ///
+    /// See `Waf::list_sql_injection_match_sets`.
+    pub fn list_sql_injection_match_sets(
+        &self,
+        input: ListSqlInjectionMatchSetsRequest,
+    ) -> Result<ListSqlInjectionMatchSetsResponse, RusotoError<ListSqlInjectionMatchSetsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_sql_injection_match_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>RuleGroup</a> objects that you are subscribed to.</p></p>
+    ///
+    /// See `Waf::list_subscribed_rule_groups`.
+    pub fn list_subscribed_rule_groups(
+        &self,
+        input: ListSubscribedRuleGroupsRequest,
+    ) -> Result<ListSubscribedRuleGroupsResponse, RusotoError<ListSubscribedRuleGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_subscribed_rule_groups(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Retrieves the tags associated with the specified AWS resource. Tags are key:value pairs that you can use to categorize and manage your resources, for purposes like billing. For example, you might set the tag key to &quot;customer&quot; and the value to the customer name or ID. You can specify one or more tags to add to each AWS resource, up to 50 tags for a resource.</p> <p>Tagging is only available through the API, SDKs, and CLI. You can&#39;t manage or view tags through the AWS WAF Classic console. You can tag the AWS resources that you manage through AWS WAF Classic: web ACLs, rule groups, and rules. </p></p>
+    ///
+    /// See `Waf::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>WebACLSummary</a> objects in the response.</p></p>
+    ///
+    /// See `Waf::list_web_ac_ls`.
+    pub fn list_web_ac_ls(
+        &self,
+        input: ListWebACLsRequest,
+    ) -> Result<ListWebACLsResponse, RusotoError<ListWebACLsError>> {
+        self.runtime.block_on(self.client.list_web_ac_ls(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Returns an array of <a>XssMatchSet</a> objects.</p></p>
+    ///
+    /// See `Waf::list_xss_match_sets`.
+    pub fn list_xss_match_sets(
+        &self,
+        input: ListXssMatchSetsRequest,
+    ) -> Result<ListXssMatchSetsResponse, RusotoError<ListXssMatchSetsError>> {
+        self.runtime
+            .block_on(self.client.list_xss_match_sets(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Associates a <a>LoggingConfiguration</a> with a specified web ACL.</p> <p>You can access information about all traffic that AWS WAF inspects using the following steps:</p> <ol> <li> <p>Create an Amazon Kinesis Data Firehose. </p> <p>Create the data firehose with a PUT source and in the region that you are operating. However, if you are capturing logs for Amazon CloudFront, always create the firehose in US East (N. Virginia). </p> <note> <p>Do not create the data firehose using a <code>Kinesis stream</code> as your source.</p> </note> </li> <li> <p>Associate that firehose to your web ACL using a <code>PutLoggingConfiguration</code> request.</p> </li> </ol> <p>When you successfully enable logging using a <code>PutLoggingConfiguration</code> request, AWS WAF will create a service linked role with the necessary permissions to write logs to the Amazon Kinesis Data Firehose. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/logging.html">Logging Web ACL Traffic Information</a> in the <i>AWS WAF Developer Guide</i>.</p></p>
+    ///
+    /// See `Waf::put_logging_configuration`.
+    pub fn put_logging_configuration(
+        &self,
+        input: PutLoggingConfigurationRequest,
+    ) -> Result<PutLoggingConfigurationResponse, RusotoError<PutLoggingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_logging_configuration(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Attaches an IAM policy to the specified resource. The only supported use for this action is to share a RuleGroup across accounts.</p> <p>The <code>PutPermissionPolicy</code> is subject to the following restrictions:</p> <ul> <li> <p>You can attach only one policy with each <code>PutPermissionPolicy</code> request.</p> </li> <li> <p>The policy must include an <code>Effect</code>, <code>Action</code> and <code>Principal</code>. </p> </li> <li> <p> <code>Effect</code> must specify <code>Allow</code>.</p> </li> <li> <p>The <code>Action</code> in the policy must be <code>waf:UpdateWebACL</code>, <code>waf-regional:UpdateWebACL</code>, <code>waf:GetRuleGroup</code> and <code>waf-regional:GetRuleGroup</code> . Any extra or wildcard actions in the policy will be rejected.</p> </li> <li> <p>The policy cannot include a <code>Resource</code> parameter.</p> </li> <li> <p>The ARN in the request must be a valid WAF RuleGroup ARN and the RuleGroup must exist in the same region.</p> </li> <li> <p>The user making the request must be the owner of the RuleGroup.</p> </li> <li> <p>Your policy must be composed using IAM Policy version 2012-10-17.</p> </li> </ul> <p>For more information, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_policies.html">IAM Policies</a>. </p> <p>An example of a valid policy parameter is shown in the Examples section below.</p></p>
+    ///
+    /// See `Waf::put_permission_policy`.
+    pub fn put_permission_policy(
+        &self,
+        input: PutPermissionPolicyRequest,
+    ) -> Result<PutPermissionPolicyResponse, RusotoError<PutPermissionPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_permission_policy(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Associates tags with the specified AWS resource. Tags are key:value pairs that you can use to categorize and manage your resources, for purposes like billing. For example, you might set the tag key to &quot;customer&quot; and the value to the customer name or ID. You can specify one or more tags to add to each AWS resource, up to 50 tags for a resource.</p> <p>Tagging is only available through the API, SDKs, and CLI. You can&#39;t manage or view tags through the AWS WAF Classic console. You can use this action to tag the AWS resources that you manage through AWS WAF Classic: web ACLs, rule groups, and rules. </p></p>
+    ///
+    /// See `Waf::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p/></p>
+    ///
+    /// See `Waf::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>ByteMatchTuple</a> objects (filters) in a <a>ByteMatchSet</a>. For each <code>ByteMatchTuple</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>ByteMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspect, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The bytes (typically a string that corresponds with ASCII characters) that you want AWS WAF to look for. For more information, including how you specify the values for the AWS WAF API and the AWS CLI or SDKs, see <code>TargetString</code> in the <a>ByteMatchTuple</a> data type. </p> </li> <li> <p>Where to look, such as at the beginning or the end of a query string.</p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p>For example, you can add a <code>ByteMatchSetUpdate</code> object that matches web requests in which <code>User-Agent</code> headers contain the string <code>BadBot</code>. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>ByteMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>ByteMatchSet.</code> For more information, see <a>CreateByteMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateByteMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateByteMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_byte_match_set`.
+    pub fn update_byte_match_set(
+        &self,
+        input: UpdateByteMatchSetRequest,
+    ) -> Result<UpdateByteMatchSetResponse, RusotoError<UpdateByteMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_byte_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>GeoMatchConstraint</a> objects in an <code>GeoMatchSet</code>. For each <code>GeoMatchConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>GeoMatchConstraint</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The <code>Type</code>. The only valid value for <code>Type</code> is <code>Country</code>.</p> </li> <li> <p>The <code>Value</code>, which is a two character code for the country to add to the <code>GeoMatchConstraint</code> object. Valid codes are listed in <a>GeoMatchConstraint$Value</a>.</p> </li> </ul> <p>To create and configure an <code>GeoMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateGeoMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateGeoMatchSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateGeoMatchSet</code> request to specify the country that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>GeoMatchSet</code>, you specify the country that you want to add and/or the country that you want to delete. If you want to change a country, you delete the existing country and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_geo_match_set`.
+    pub fn update_geo_match_set(
+        &self,
+        input: UpdateGeoMatchSetRequest,
+    ) -> Result<UpdateGeoMatchSetResponse, RusotoError<UpdateGeoMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_geo_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>IPSetDescriptor</a> objects in an <code>IPSet</code>. For each <code>IPSetDescriptor</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change an <code>IPSetDescriptor</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The IP address version, <code>IPv4</code> or <code>IPv6</code>. </p> </li> <li> <p>The IP address in CIDR notation, for example, <code>192.0.2.0/24</code> (for the range of IP addresses from <code>192.0.2.0</code> to <code>192.0.2.255</code>) or <code>192.0.2.44/32</code> (for the individual IP address <code>192.0.2.44</code>). </p> </li> </ul> <p>AWS WAF supports IPv4 address ranges: /8 and any range between /16 through /32. AWS WAF supports IPv6 address ranges: /24, /32, /48, /56, /64, and /128. For more information about CIDR notation, see the Wikipedia entry <a href="https://en.wikipedia.org/wiki/Classless_Inter-Domain_Routing">Classless Inter-Domain Routing</a>.</p> <p>IPv6 addresses can be represented using any of the following formats:</p> <ul> <li> <p>1111:0000:0000:0000:0000:0000:0000:0111/128</p> </li> <li> <p>1111:0:0:0:0:0:0:0111/128</p> </li> <li> <p>1111::0111/128</p> </li> <li> <p>1111::111/128</p> </li> </ul> <p>You use an <code>IPSet</code> to specify which web requests you want to allow or block based on the IP addresses that the requests originated from. For example, if you&#39;re receiving a lot of requests from one or a small number of IP addresses and you want to block the requests, you can create an <code>IPSet</code> that specifies those IP addresses, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>IPSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateIPSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateIPSet</code> request to specify the IP addresses that you want AWS WAF to watch for.</p> </li> </ol> <p>When you update an <code>IPSet</code>, you specify the IP addresses that you want to add and/or the IP addresses that you want to delete. If you want to change an IP address, you delete the existing IP address and add the new one.</p> <p>You can insert a maximum of 1000 addresses in a single request.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_ip_set`.
+    pub fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+        self.runtime.block_on(self.client.update_ip_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>Predicate</a> objects in a rule and updates the <code>RateLimit</code> in the rule. </p> <p>Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to block or count. The <code>RateLimit</code> specifies the number of requests every five minutes that triggers the rule.</p> <p>If you add more than one predicate to a <code>RateBasedRule</code>, a request must match all the predicates and exceed the <code>RateLimit</code> to be counted or blocked. For example, suppose you add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44/32</code> </p> </li> <li> <p>A <code>ByteMatchSet</code> that matches <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 1,000.</p> <p>You then add the <code>RateBasedRule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the rule. For a request to be blocked, it must come from the IP address 192.0.2.44 <i>and</i> the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code>. Further, requests that match these two conditions much be received at a rate of more than 1,000 every five minutes. If the rate drops below this limit, AWS WAF no longer blocks the requests.</p> <p>As a second example, suppose you want to limit requests to a particular page on your site. To do this, you could add the following to a <code>RateBasedRule</code>:</p> <ul> <li> <p>A <code>ByteMatchSet</code> with <code>FieldToMatch</code> of <code>URI</code> </p> </li> <li> <p>A <code>PositionalConstraint</code> of <code>STARTS_WITH</code> </p> </li> <li> <p>A <code>TargetString</code> of <code>login</code> </p> </li> </ul> <p>Further, you specify a <code>RateLimit</code> of 1,000.</p> <p>By adding this <code>RateBasedRule</code> to a <code>WebACL</code>, you could limit requests to your login page without affecting the rest of your site.</p></p>
+    ///
+    /// See `Waf::update_rate_based_rule`.
+    pub fn update_rate_based_rule(
+        &self,
+        input: UpdateRateBasedRuleRequest,
+    ) -> Result<UpdateRateBasedRuleResponse, RusotoError<UpdateRateBasedRuleError>> {
+        self.runtime
+            .block_on(self.client.update_rate_based_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>RegexMatchTuple</a> objects (filters) in a <a>RegexMatchSet</a>. For each <code>RegexMatchSetUpdate</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>RegexMatchSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to inspectupdate, such as a query string or the value of the <code>User-Agent</code> header. </p> </li> <li> <p>The identifier of the pattern (a regular expression) that you want AWS WAF to look for. For more information, see <a>RegexPatternSet</a>. </p> </li> <li> <p>Whether to perform any conversions on the request, such as converting it to lowercase, before inspecting it for the specified string.</p> </li> </ul> <p> For example, you can create a <code>RegexPatternSet</code> that matches any requests with <code>User-Agent</code> headers that contain the string <code>B[a@]dB[o0]t</code>. You can then configure AWS WAF to reject those requests.</p> <p>To create and configure a <code>RegexMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexMatchSet.</code> For more information, see <a>CreateRegexMatchSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexMatchSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexMatchSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the identifier of the <code>RegexPatternSet</code> that contain the regular expression patters you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_regex_match_set`.
+    pub fn update_regex_match_set(
+        &self,
+        input: UpdateRegexMatchSetRequest,
+    ) -> Result<UpdateRegexMatchSetResponse, RusotoError<UpdateRegexMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_regex_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <code>RegexPatternString</code> objects in a <a>RegexPatternSet</a>. For each <code>RegexPatternString</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the <code>RegexPatternString</code>.</p> </li> <li> <p>The regular expression pattern that you want to insert or delete. For more information, see <a>RegexPatternSet</a>. </p> </li> </ul> <p> For example, you can create a <code>RegexPatternString</code> such as <code>B[a@]dB[o0]t</code>. AWS WAF will match this <code>RegexPatternString</code> to:</p> <ul> <li> <p>BadBot</p> </li> <li> <p>BadB0t</p> </li> <li> <p>B@dBot</p> </li> <li> <p>B@dB0t</p> </li> </ul> <p>To create and configure a <code>RegexPatternSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>RegexPatternSet.</code> For more information, see <a>CreateRegexPatternSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateRegexPatternSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateRegexPatternSet</code> request to specify the regular expression pattern that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_regex_pattern_set`.
+    pub fn update_regex_pattern_set(
+        &self,
+        input: UpdateRegexPatternSetRequest,
+    ) -> Result<UpdateRegexPatternSetResponse, RusotoError<UpdateRegexPatternSetError>> {
+        self.runtime
+            .block_on(self.client.update_regex_pattern_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>Predicate</a> objects in a <code>Rule</code>. Each <code>Predicate</code> object identifies a predicate, such as a <a>ByteMatchSet</a> or an <a>IPSet</a>, that specifies the web requests that you want to allow, block, or count. If you add more than one predicate to a <code>Rule</code>, a request must match all of the specifications to be allowed, blocked, or counted. For example, suppose that you add the following to a <code>Rule</code>: </p> <ul> <li> <p>A <code>ByteMatchSet</code> that matches the value <code>BadBot</code> in the <code>User-Agent</code> header</p> </li> <li> <p>An <code>IPSet</code> that matches the IP address <code>192.0.2.44</code> </p> </li> </ul> <p>You then add the <code>Rule</code> to a <code>WebACL</code> and specify that you want to block requests that satisfy the <code>Rule</code>. For a request to be blocked, the <code>User-Agent</code> header in the request must contain the value <code>BadBot</code> <i>and</i> the request must originate from the IP address 192.0.2.44.</p> <p>To create and configure a <code>Rule</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in the <code>Rule</code>.</p> </li> <li> <p>Create the <code>Rule</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRule</a> request.</p> </li> <li> <p>Submit an <code>UpdateRule</code> request to add predicates to the <code>Rule</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>Rule</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>ByteMatchSet</code> or <code>IPSet</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_rule`.
+    pub fn update_rule(
+        &self,
+        input: UpdateRuleRequest,
+    ) -> Result<UpdateRuleResponse, RusotoError<UpdateRuleError>> {
+        self.runtime.block_on(self.client.update_rule(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>RuleGroup</code>.</p> <p>You can only insert <code>REGULAR</code> rules into a rule group.</p> <p>You can have a maximum of ten rules per rule group.</p> <p>To create and configure a <code>RuleGroup</code>, perform the following steps:</p> <ol> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>RuleGroup</code>. See <a>CreateRule</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateRuleGroup</a> request.</p> </li> <li> <p>Submit an <code>UpdateRuleGroup</code> request to add <code>Rules</code> to the <code>RuleGroup</code>.</p> </li> <li> <p>Create and update a <code>WebACL</code> that contains the <code>RuleGroup</code>. See <a>CreateWebACL</a>.</p> </li> </ol> <p>If you want to replace one <code>Rule</code> with another, you delete the existing one and add the new one.</p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_rule_group`.
+    pub fn update_rule_group(
+        &self,
+        input: UpdateRuleGroupRequest,
+    ) -> Result<UpdateRuleGroupResponse, RusotoError<UpdateRuleGroupError>> {
+        self.runtime.block_on(self.client.update_rule_group(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>SizeConstraint</a> objects (filters) in a <a>SizeConstraintSet</a>. For each <code>SizeConstraint</code> object, you specify the following values: </p> <ul> <li> <p>Whether to insert or delete the object from the array. If you want to change a <code>SizeConstraintSetUpdate</code> object, you delete the existing object and add a new one.</p> </li> <li> <p>The part of a web request that you want AWS WAF to evaluate, such as the length of a query string or the length of the <code>User-Agent</code> header.</p> </li> <li> <p>Whether to perform any transformations on the request, such as converting it to lowercase, before checking its length. Note that transformations of the request body are not supported because the AWS resource forwards only the first <code>8192</code> bytes of your request to AWS WAF.</p> <p>You can only specify a single type of TextTransformation.</p> </li> <li> <p>A <code>ComparisonOperator</code> used for evaluating the selected part of the request against the specified <code>Size</code>, such as equals, greater than, less than, and so on.</p> </li> <li> <p>The length, in bytes, that you want AWS WAF to watch for in selected part of the request. The length is computed after applying the transformation.</p> </li> </ul> <p>For example, you can add a <code>SizeConstraintSetUpdate</code> object that matches web requests in which the length of the <code>User-Agent</code> header is greater than 100 bytes. You can then configure AWS WAF to block those requests.</p> <p>To create and configure a <code>SizeConstraintSet</code>, perform the following steps:</p> <ol> <li> <p>Create a <code>SizeConstraintSet.</code> For more information, see <a>CreateSizeConstraintSet</a>.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <code>UpdateSizeConstraintSet</code> request.</p> </li> <li> <p>Submit an <code>UpdateSizeConstraintSet</code> request to specify the part of the request that you want AWS WAF to inspect (for example, the header or the URI) and the value that you want AWS WAF to watch for.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_size_constraint_set`.
+    pub fn update_size_constraint_set(
+        &self,
+        input: UpdateSizeConstraintSetRequest,
+    ) -> Result<UpdateSizeConstraintSetResponse, RusotoError<UpdateSizeConstraintSetError>> {
+        self.runtime
+            .block_on(self.client.update_size_constraint_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>SqlInjectionMatchTuple</a> objects (filters) in a <a>SqlInjectionMatchSet</a>. For each <code>SqlInjectionMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change a <code>SqlInjectionMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for snippets of malicious SQL code.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>SqlInjectionMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you&#39;re receiving requests that contain snippets of SQL code in the query string and you want to block the requests, you can create a <code>SqlInjectionMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure a <code>SqlInjectionMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateSqlInjectionMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateSqlInjectionMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for snippets of SQL code.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_sql_injection_match_set`.
+    pub fn update_sql_injection_match_set(
+        &self,
+        input: UpdateSqlInjectionMatchSetRequest,
+    ) -> Result<UpdateSqlInjectionMatchSetResponse, RusotoError<UpdateSqlInjectionMatchSetError>>
+    {
+        self.runtime
+            .block_on(self.client.update_sql_injection_match_set(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>ActivatedRule</a> objects in a <code>WebACL</code>. Each <code>Rule</code> identifies web requests that you want to allow, block, or count. When you update a <code>WebACL</code>, you specify the following values:</p> <ul> <li> <p>A default action for the <code>WebACL</code>, either <code>ALLOW</code> or <code>BLOCK</code>. AWS WAF performs the default action if a request doesn&#39;t match the criteria in any of the <code>Rules</code> in a <code>WebACL</code>.</p> </li> <li> <p>The <code>Rules</code> that you want to add or delete. If you want to replace one <code>Rule</code> with another, you delete the existing <code>Rule</code> and add the new one.</p> </li> <li> <p>For each <code>Rule</code>, whether you want AWS WAF to allow requests, block requests, or count requests that match the conditions in the <code>Rule</code>.</p> </li> <li> <p>The order in which you want AWS WAF to evaluate the <code>Rules</code> in a <code>WebACL</code>. If you add more than one <code>Rule</code> to a <code>WebACL</code>, AWS WAF evaluates each request against the <code>Rules</code> in order based on the value of <code>Priority</code>. (The <code>Rule</code> that has the lowest value for <code>Priority</code> is evaluated first.) When a web request matches all the predicates (such as <code>ByteMatchSets</code> and <code>IPSets</code>) in a <code>Rule</code>, AWS WAF immediately takes the corresponding action, allow or block, and doesn&#39;t evaluate the request against the remaining <code>Rules</code> in the <code>WebACL</code>, if any. </p> </li> </ul> <p>To create and configure a <code>WebACL</code>, perform the following steps:</p> <ol> <li> <p>Create and update the predicates that you want to include in <code>Rules</code>. For more information, see <a>CreateByteMatchSet</a>, <a>UpdateByteMatchSet</a>, <a>CreateIPSet</a>, <a>UpdateIPSet</a>, <a>CreateSqlInjectionMatchSet</a>, and <a>UpdateSqlInjectionMatchSet</a>.</p> </li> <li> <p>Create and update the <code>Rules</code> that you want to include in the <code>WebACL</code>. For more information, see <a>CreateRule</a> and <a>UpdateRule</a>.</p> </li> <li> <p>Create a <code>WebACL</code>. See <a>CreateWebACL</a>.</p> </li> <li> <p>Use <code>GetChangeToken</code> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateWebACL</a> request.</p> </li> <li> <p>Submit an <code>UpdateWebACL</code> request to specify the <code>Rules</code> that you want to include in the <code>WebACL</code>, to specify the default action, and to associate the <code>WebACL</code> with a CloudFront distribution. </p> <p>The <code>ActivatedRule</code> can be a rule group. If you specify a rule group as your <code>ActivatedRule</code> , you can exclude specific rules from that rule group.</p> <p>If you already have a rule group associated with a web ACL and want to submit an <code>UpdateWebACL</code> request to exclude certain rules from that rule group, you must first remove the rule group from the web ACL, the re-insert it again, specifying the excluded rules. For details, see <a>ActivatedRule$ExcludedRules</a> . </p> </li> </ol> <p>Be aware that if you try to add a RATE_BASED rule to a web ACL without setting the rule type when first creating the rule, the <a>UpdateWebACL</a> request will fail because the request tries to add a REGULAR rule (the default rule type) with the specified ID, which does not exist. </p> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_web_acl`.
+    pub fn update_web_acl(
+        &self,
+        input: UpdateWebACLRequest,
+    ) -> Result<UpdateWebACLResponse, RusotoError<UpdateWebACLError>> {
+        self.runtime.block_on(self.client.update_web_acl(input))
+    }
+
+    /// <p><note> <p>This is <b>AWS WAF Classic</b> documentation. For more information, see <a href="https://docs.aws.amazon.com/waf/latest/developerguide/classic-waf-chapter.html">AWS WAF Classic</a> in the developer guide.</p> <p> <b>For the latest version of AWS WAF</b>, use the AWS WAFV2 API and see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/waf-chapter.html">AWS WAF Developer Guide</a>. With the latest version, AWS WAF has a single set of endpoints for regional and global use. </p> </note> <p>Inserts or deletes <a>XssMatchTuple</a> objects (filters) in an <a>XssMatchSet</a>. For each <code>XssMatchTuple</code> object, you specify the following values:</p> <ul> <li> <p> <code>Action</code>: Whether to insert the object into or delete the object from the array. To change an <code>XssMatchTuple</code>, you delete the existing object and add a new one.</p> </li> <li> <p> <code>FieldToMatch</code>: The part of web requests that you want AWS WAF to inspect and, if you want AWS WAF to inspect a header or custom query parameter, the name of the header or parameter.</p> </li> <li> <p> <code>TextTransformation</code>: Which text transformation, if any, to perform on the web request before inspecting the request for cross-site scripting attacks.</p> <p>You can only specify a single type of TextTransformation.</p> </li> </ul> <p>You use <code>XssMatchSet</code> objects to specify which CloudFront requests that you want to allow, block, or count. For example, if you&#39;re receiving requests that contain cross-site scripting attacks in the request body and you want to block the requests, you can create an <code>XssMatchSet</code> with the applicable settings, and then configure AWS WAF to block the requests. </p> <p>To create and configure an <code>XssMatchSet</code>, perform the following steps:</p> <ol> <li> <p>Submit a <a>CreateXssMatchSet</a> request.</p> </li> <li> <p>Use <a>GetChangeToken</a> to get the change token that you provide in the <code>ChangeToken</code> parameter of an <a>UpdateIPSet</a> request.</p> </li> <li> <p>Submit an <code>UpdateXssMatchSet</code> request to specify the parts of web requests that you want AWS WAF to inspect for cross-site scripting attacks.</p> </li> </ol> <p>For more information about how to use the AWS WAF API to allow or block HTTP requests, see the <a href="https://docs.aws.amazon.com/waf/latest/developerguide/">AWS WAF Developer Guide</a>.</p></p>
+    ///
+    /// See `Waf::update_xss_match_set`.
+    pub fn update_xss_match_set(
+        &self,
+        input: UpdateXssMatchSetRequest,
+    ) -> Result<UpdateXssMatchSetResponse, RusotoError<UpdateXssMatchSetError>> {
+        self.runtime
+            .block_on(self.client.update_xss_match_set(input))
+    }
+}
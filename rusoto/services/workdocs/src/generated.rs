@@ -1812,32 +1812,38 @@ impl AbortDocumentVersionUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(AbortDocumentVersionUploadError::EntityNotExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortDocumentVersionUploadError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(AbortDocumentVersionUploadError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortDocumentVersionUploadError::FailedDependency(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(AbortDocumentVersionUploadError::ProhibitedState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AbortDocumentVersionUploadError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AbortDocumentVersionUploadError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AbortDocumentVersionUploadError::UnauthorizedOperation(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AbortDocumentVersionUploadError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -1888,21 +1894,28 @@ impl ActivateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(ActivateUserError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, ActivateUserError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(ActivateUserError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, ActivateUserError::FailedDependency(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ActivateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ActivateUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(ActivateUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ActivateUserError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(ActivateUserError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ActivateUserError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1942,22 +1955,26 @@ impl AddResourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(AddResourcePermissionsError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddResourcePermissionsError::FailedDependency(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddResourcePermissionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AddResourcePermissionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddResourcePermissionsError::UnauthorizedOperation(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AddResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -2008,34 +2025,46 @@ impl CreateCommentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DocumentLockedForCommentsException" => {
-                    return RusotoError::Service(CreateCommentError::DocumentLockedForComments(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommentError::DocumentLockedForComments(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateCommentError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, CreateCommentError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateCommentError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommentError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidCommentOperationException" => {
-                    return RusotoError::Service(CreateCommentError::InvalidCommentOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommentError::InvalidCommentOperation(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(CreateCommentError::ProhibitedState(err.msg))
+                    return RusotoError::service(&res, CreateCommentError::ProhibitedState(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateCommentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateCommentError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommentError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateCommentError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCommentError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2084,37 +2113,44 @@ impl CreateCustomMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CustomMetadataLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCustomMetadataError::CustomMetadataLimitExceeded(err.msg),
                     )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::EntityNotExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomMetadataError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomMetadataError::FailedDependency(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::ProhibitedState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomMetadataError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomMetadataError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateCustomMetadataError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateCustomMetadataError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateCustomMetadataError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -2172,33 +2208,46 @@ impl CreateFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(CreateFolderError::ConflictingOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFolderError::ConflictingOperation(err.msg),
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFolderError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFolderError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, CreateFolderError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateFolderError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, CreateFolderError::FailedDependency(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFolderError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateFolderError::LimitExceeded(err.msg))
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(CreateFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service(&res, CreateFolderError::ProhibitedState(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFolderError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFolderError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateFolderError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateFolderError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2246,24 +2295,31 @@ impl CreateLabelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(CreateLabelsError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, CreateLabelsError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateLabelsError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, CreateLabelsError::FailedDependency(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLabelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLabelsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "TooManyLabelsException" => {
-                    return RusotoError::Service(CreateLabelsError::TooManyLabels(err.msg))
+                    return RusotoError::service(&res, CreateLabelsError::TooManyLabels(err.msg))
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateLabelsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateLabelsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateLabelsError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateLabelsError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2304,17 +2360,20 @@ impl CreateNotificationSubscriptionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNotificationSubscriptionError::ServiceUnavailable(err.msg),
                     )
                 }
                 "TooManySubscriptionsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNotificationSubscriptionError::TooManySubscriptions(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateNotificationSubscriptionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -2362,21 +2421,28 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(CreateUserError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, CreateUserError::FailedDependency(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ServiceUnavailable(err.msg))
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(CreateUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(CreateUserError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2418,23 +2484,34 @@ impl DeactivateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeactivateUserError::EntityNotExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateUserError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeactivateUserError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateUserError::FailedDependency(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeactivateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeactivateUserError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateUserError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeactivateUserError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeactivateUserError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2480,29 +2557,40 @@ impl DeleteCommentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DocumentLockedForCommentsException" => {
-                    return RusotoError::Service(DeleteCommentError::DocumentLockedForComments(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentError::DocumentLockedForComments(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteCommentError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, DeleteCommentError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteCommentError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentError::FailedDependency(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteCommentError::ProhibitedState(err.msg))
+                    return RusotoError::service(&res, DeleteCommentError::ProhibitedState(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCommentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteCommentError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteCommentError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCommentError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2548,32 +2636,38 @@ impl DeleteCustomMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::EntityNotExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomMetadataError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomMetadataError::FailedDependency(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::ProhibitedState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomMetadataError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomMetadataError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteCustomMetadataError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteCustomMetadataError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteCustomMetadataError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -2626,34 +2720,52 @@ impl DeleteDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDocumentError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(DeleteDocumentError::ConflictingOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::ConflictingOperation(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteDocumentError::EntityNotExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteDocumentError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::FailedDependency(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteDocumentError::ProhibitedState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteDocumentError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteDocumentError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteDocumentError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2704,30 +2816,43 @@ impl DeleteFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFolderError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(DeleteFolderError::ConflictingOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderError::ConflictingOperation(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, DeleteFolderError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteFolderError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, DeleteFolderError::FailedDependency(err.msg))
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service(&res, DeleteFolderError::ProhibitedState(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteFolderError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2776,37 +2901,44 @@ impl DeleteFolderContentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::ConflictingOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderContentsError::ConflictingOperation(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::EntityNotExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderContentsError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderContentsError::FailedDependency(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::ProhibitedState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderContentsError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderContentsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteFolderContentsError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFolderContentsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteFolderContentsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -2854,21 +2986,28 @@ impl DeleteLabelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteLabelsError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, DeleteLabelsError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteLabelsError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, DeleteLabelsError::FailedDependency(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLabelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLabelsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteLabelsError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLabelsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteLabelsError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteLabelsError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2910,22 +3049,26 @@ impl DeleteNotificationSubscriptionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteNotificationSubscriptionError::EntityNotExists(err.msg),
                     )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteNotificationSubscriptionError::ProhibitedState(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteNotificationSubscriptionError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteNotificationSubscriptionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -2976,21 +3119,25 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DeleteUserError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DeleteUserError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::FailedDependency(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::ServiceUnavailable(err.msg))
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DeleteUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DeleteUserError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3032,23 +3179,32 @@ impl DescribeActivitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeActivitiesError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivitiesError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeActivitiesError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivitiesError::InvalidArgument(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeActivitiesError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivitiesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeActivitiesError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeActivitiesError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeActivitiesError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -3096,26 +3252,40 @@ impl DescribeCommentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeCommentsError::EntityNotExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommentsError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeCommentsError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommentsError::FailedDependency(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DescribeCommentsError::ProhibitedState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommentsError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCommentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommentsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeCommentsError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommentsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DescribeCommentsError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeCommentsError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3162,37 +3332,44 @@ impl DescribeDocumentVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::EntityNotExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentVersionsError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentVersionsError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentVersionsError::InvalidArgument(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::ProhibitedState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentVersionsError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDocumentVersionsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDocumentVersionsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentVersionsError::UnauthorizedOperation(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDocumentVersionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -3244,32 +3421,38 @@ impl DescribeFolderContentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::EntityNotExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFolderContentsError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFolderContentsError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::InvalidArgument(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFolderContentsError::InvalidArgument(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::ProhibitedState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFolderContentsError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeFolderContentsError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFolderContentsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeFolderContentsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -3314,20 +3497,28 @@ impl DescribeGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeGroupsError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupsError::FailedDependency(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeGroupsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupsError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeGroupsError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupsError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DescribeGroupsError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupsError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3366,17 +3557,20 @@ impl DescribeNotificationSubscriptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNotificationSubscriptionsError::EntityNotExists(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNotificationSubscriptionsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeNotificationSubscriptionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -3424,22 +3618,26 @@ impl DescribeResourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeResourcePermissionsError::FailedDependency(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeResourcePermissionsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeResourcePermissionsError::UnauthorizedOperation(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -3488,25 +3686,32 @@ impl DescribeRootFoldersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRootFoldersError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::InvalidArgument(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRootFoldersError::InvalidArgument(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRootFoldersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeRootFoldersError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeRootFoldersError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeRootFoldersError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -3556,29 +3761,40 @@ impl DescribeUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(DescribeUsersError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, DescribeUsersError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(DescribeUsersError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUsersError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeUsersError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, DescribeUsersError::InvalidArgument(err.msg))
                 }
                 "RequestedEntityTooLargeException" => {
-                    return RusotoError::Service(DescribeUsersError::RequestedEntityTooLarge(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUsersError::RequestedEntityTooLarge(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUsersError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(DescribeUsersError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUsersError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(DescribeUsersError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUsersError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3622,23 +3838,34 @@ impl GetCurrentUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetCurrentUserError::EntityNotExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentUserError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetCurrentUserError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentUserError::FailedDependency(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetCurrentUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentUserError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetCurrentUserError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentUserError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetCurrentUserError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetCurrentUserError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3684,27 +3911,34 @@ impl GetDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetDocumentError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, GetDocumentError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetDocumentError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, GetDocumentError::FailedDependency(err.msg))
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetDocumentError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, GetDocumentError::InvalidArgument(err.msg))
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(GetDocumentError::InvalidPassword(err.msg))
+                    return RusotoError::service(&res, GetDocumentError::InvalidPassword(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetDocumentError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetDocumentError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3748,23 +3982,34 @@ impl GetDocumentPathError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetDocumentPathError::EntityNotExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentPathError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetDocumentPathError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentPathError::FailedDependency(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDocumentPathError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentPathError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetDocumentPathError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentPathError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetDocumentPathError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentPathError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3810,29 +4055,44 @@ impl GetDocumentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetDocumentVersionError::EntityNotExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentVersionError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetDocumentVersionError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentVersionError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(GetDocumentVersionError::InvalidPassword(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentVersionError::InvalidPassword(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(GetDocumentVersionError::ProhibitedState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentVersionError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDocumentVersionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentVersionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetDocumentVersionError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDocumentVersionError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDocumentVersionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -3884,27 +4144,31 @@ impl GetFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, GetFolderError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetFolderError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, GetFolderError::FailedDependency(err.msg))
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetFolderError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, GetFolderError::InvalidArgument(err.msg))
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(GetFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service(&res, GetFolderError::ProhibitedState(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, GetFolderError::ServiceUnavailable(err.msg))
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetFolderError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3948,21 +4212,31 @@ impl GetFolderPathError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(GetFolderPathError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, GetFolderPathError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetFolderPathError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderPathError::FailedDependency(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetFolderPathError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderPathError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetFolderPathError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderPathError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetFolderPathError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetFolderPathError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4004,21 +4278,28 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(GetResourcesError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::FailedDependency(err.msg))
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetResourcesError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, GetResourcesError::InvalidArgument(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourcesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcesError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(GetResourcesError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcesError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(GetResourcesError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetResourcesError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4074,57 +4355,68 @@ impl InitiateDocumentVersionUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DraftUploadOutOfSyncException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::DraftUploadOutOfSync(err.msg),
                     )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::EntityAlreadyExists(err.msg),
                     )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::EntityNotExists(err.msg),
                     )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::FailedDependency(err.msg),
                     )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::ProhibitedState(err.msg),
                     )
                 }
                 "ResourceAlreadyCheckedOutException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::ResourceAlreadyCheckedOut(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::ServiceUnavailable(err.msg),
                     )
                 }
                 "StorageLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::StorageLimitExceeded(err.msg),
                     )
                 }
                 "StorageLimitWillExceedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::StorageLimitWillExceed(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::UnauthorizedOperation(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         InitiateDocumentVersionUploadError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -4196,22 +4488,26 @@ impl RemoveAllResourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAllResourcePermissionsError::FailedDependency(err.msg),
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAllResourcePermissionsError::ServiceUnavailable(err.msg),
                     )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAllResourcePermissionsError::UnauthorizedOperation(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveAllResourcePermissionsError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -4260,22 +4556,26 @@ impl RemoveResourcePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FailedDependencyException" => {
-                    return RusotoError::Service(RemoveResourcePermissionError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveResourcePermissionError::FailedDependency(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveResourcePermissionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RemoveResourcePermissionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveResourcePermissionError::UnauthorizedOperation(err.msg),
                     )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RemoveResourcePermissionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -4332,40 +4632,61 @@ impl UpdateDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDocumentError::ConcurrentModification(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(UpdateDocumentError::ConflictingOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::ConflictingOperation(err.msg),
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateDocumentError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateDocumentError::EntityNotExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateDocumentError::FailedDependency(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::FailedDependency(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDocumentError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateDocumentError::LimitExceeded(err.msg))
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(UpdateDocumentError::ProhibitedState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateDocumentError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(UpdateDocumentError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4418,42 +4739,50 @@ impl UpdateDocumentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDocumentVersionError::ConcurrentModification(err.msg),
                     )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::EntityNotExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentVersionError::EntityNotExists(err.msg),
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::FailedDependency(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentVersionError::FailedDependency(err.msg),
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::InvalidOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentVersionError::InvalidOperation(err.msg),
+                    )
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::ProhibitedState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentVersionError::ProhibitedState(err.msg),
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::ServiceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentVersionError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateDocumentVersionError::UnauthorizedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDocumentVersionError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDocumentVersionError::UnauthorizedResourceAccess(err.msg),
                     )
                 }
@@ -4512,36 +4841,52 @@ impl UpdateFolderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFolderError::ConcurrentModification(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFolderError::ConcurrentModification(err.msg),
+                    )
                 }
                 "ConflictingOperationException" => {
-                    return RusotoError::Service(UpdateFolderError::ConflictingOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFolderError::ConflictingOperation(err.msg),
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateFolderError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFolderError::EntityAlreadyExists(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateFolderError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, UpdateFolderError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateFolderError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, UpdateFolderError::FailedDependency(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFolderError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, UpdateFolderError::LimitExceeded(err.msg))
                 }
                 "ProhibitedStateException" => {
-                    return RusotoError::Service(UpdateFolderError::ProhibitedState(err.msg))
+                    return RusotoError::service(&res, UpdateFolderError::ProhibitedState(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFolderError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFolderError::ServiceUnavailable(err.msg),
+                    )
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateFolderError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFolderError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(UpdateFolderError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFolderError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4594,32 +4939,37 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeactivatingLastSystemUserException" => {
-                    return RusotoError::Service(UpdateUserError::DeactivatingLastSystemUser(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserError::DeactivatingLastSystemUser(err.msg),
+                    )
                 }
                 "EntityNotExistsException" => {
-                    return RusotoError::Service(UpdateUserError::EntityNotExists(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::EntityNotExists(err.msg))
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(UpdateUserError::FailedDependency(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::FailedDependency(err.msg))
                 }
                 "IllegalUserStateException" => {
-                    return RusotoError::Service(UpdateUserError::IllegalUserState(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::IllegalUserState(err.msg))
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateUserError::InvalidArgument(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::InvalidArgument(err.msg))
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service(&res, UpdateUserError::ServiceUnavailable(err.msg))
                 }
                 "UnauthorizedOperationException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserError::UnauthorizedOperation(err.msg),
+                    )
                 }
                 "UnauthorizedResourceAccessException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedResourceAccess(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateUserError::UnauthorizedResourceAccess(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4920,6 +5270,7 @@ impl WorkdocsClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4935,6 +5286,7 @@ impl WorkdocsClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> WorkdocsClient {
         WorkdocsClient { client, region }
     }
@@ -4955,6 +5307,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("AbortDocumentVersionUpload");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -4987,6 +5342,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("ActivateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5020,6 +5378,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("AddResourcePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5056,6 +5417,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("CreateComment");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5091,6 +5455,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("CreateCustomMetadata");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5128,6 +5495,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/folders";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("CreateFolder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5163,6 +5533,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("CreateLabels");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5201,6 +5574,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("CreateNotificationSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5232,6 +5608,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5267,6 +5646,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeactivateUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5301,6 +5683,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteComment");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5333,6 +5718,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteCustomMetadata");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5379,6 +5767,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteDocument");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5408,6 +5799,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteFolder");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5440,6 +5834,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteFolderContents");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5472,6 +5869,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteLabels");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5516,6 +5916,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteNotificationSubscription");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5543,6 +5946,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5572,6 +5978,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/activities";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeActivities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5635,6 +6044,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeComments");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5676,6 +6088,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeDocumentVersions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5723,6 +6138,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeFolderContents");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5773,6 +6191,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/groups";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5821,6 +6242,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeNotificationSubscriptions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5864,6 +6288,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeResourcePermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -5905,6 +6332,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me/root";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeRootFolders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token.to_string());
@@ -5943,6 +6373,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/users";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("DescribeUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6002,6 +6435,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/me";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("GetCurrentUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("Authentication", &input.authentication_token.to_string());
@@ -6035,6 +6471,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("GetDocument");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6073,6 +6512,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("GetDocumentPath");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6118,6 +6560,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("GetDocumentVersion");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6156,6 +6601,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("GetFolder");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6194,6 +6642,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("GetFolderPath");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6235,6 +6686,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/resources";
 
         let mut request = SignedRequest::new("GET", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("GetResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6282,6 +6736,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = "/api/v1/documents";
 
         let mut request = SignedRequest::new("POST", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("InitiateDocumentVersionUpload");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6317,6 +6774,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("RemoveAllResourcePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6350,6 +6810,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("RemoveResourcePermission");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Authentication", input.authentication_token.as_ref());
@@ -6387,6 +6850,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("UpdateDocument");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6422,6 +6888,9 @@ impl Workdocs for WorkdocsClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("UpdateDocumentVersion");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6453,6 +6922,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/folders/{folder_id}", folder_id = input.folder_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("UpdateFolder");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6484,6 +6956,9 @@ impl Workdocs for WorkdocsClient {
         let request_uri = format!("/api/v1/users/{user_id}", user_id = input.user_id);
 
         let mut request = SignedRequest::new("PATCH", "workdocs", &self.region, &request_uri);
+        request.set_operation_name("UpdateUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6507,3 +6982,491 @@ impl Workdocs for WorkdocsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon WorkDocs API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `WorkdocsClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkdocsBlockingClient {
+    client: WorkdocsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkdocsBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorkdocsBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        WorkdocsBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkdocsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkdocsBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkdocsBlockingClient {
+        WorkdocsBlockingClient {
+            client: WorkdocsClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Aborts the upload of the specified document version that was previously initiated by <a>InitiateDocumentVersionUpload</a>. The client should make this call only when it no longer intends to upload the document version, or fails to do so.</p>
+    ///
+    /// See `Workdocs::abort_document_version_upload`.
+    pub fn abort_document_version_upload(
+        &self,
+        input: AbortDocumentVersionUploadRequest,
+    ) -> Result<(), RusotoError<AbortDocumentVersionUploadError>> {
+        self.runtime
+            .block_on(self.client.abort_document_version_upload(input))
+    }
+
+    /// <p>Activates the specified user. Only active users can access Amazon WorkDocs.</p>
+    ///
+    /// See `Workdocs::activate_user`.
+    pub fn activate_user(
+        &self,
+        input: ActivateUserRequest,
+    ) -> Result<ActivateUserResponse, RusotoError<ActivateUserError>> {
+        self.runtime.block_on(self.client.activate_user(input))
+    }
+
+    /// <p>Creates a set of permissions for the specified folder or document. The resource permissions are overwritten if the principals already have different permissions.</p>
+    ///
+    /// See `Workdocs::add_resource_permissions`.
+    pub fn add_resource_permissions(
+        &self,
+        input: AddResourcePermissionsRequest,
+    ) -> Result<AddResourcePermissionsResponse, RusotoError<AddResourcePermissionsError>> {
+        self.runtime
+            .block_on(self.client.add_resource_permissions(input))
+    }
+
+    /// <p>Adds a new comment to the specified document version.</p>
+    ///
+    /// See `Workdocs::create_comment`.
+    pub fn create_comment(
+        &self,
+        input: CreateCommentRequest,
+    ) -> Result<CreateCommentResponse, RusotoError<CreateCommentError>> {
+        self.runtime.block_on(self.client.create_comment(input))
+    }
+
+    /// <p>Adds one or more custom properties to the specified resource (a folder, document, or version).</p>
+    ///
+    /// See `Workdocs::create_custom_metadata`.
+    pub fn create_custom_metadata(
+        &self,
+        input: CreateCustomMetadataRequest,
+    ) -> Result<CreateCustomMetadataResponse, RusotoError<CreateCustomMetadataError>> {
+        self.runtime
+            .block_on(self.client.create_custom_metadata(input))
+    }
+
+    /// <p>Creates a folder with the specified name and parent folder.</p>
+    ///
+    /// See `Workdocs::create_folder`.
+    pub fn create_folder(
+        &self,
+        input: CreateFolderRequest,
+    ) -> Result<CreateFolderResponse, RusotoError<CreateFolderError>> {
+        self.runtime.block_on(self.client.create_folder(input))
+    }
+
+    /// <p>Adds the specified list of labels to the given resource (a document or folder)</p>
+    ///
+    /// See `Workdocs::create_labels`.
+    pub fn create_labels(
+        &self,
+        input: CreateLabelsRequest,
+    ) -> Result<CreateLabelsResponse, RusotoError<CreateLabelsError>> {
+        self.runtime.block_on(self.client.create_labels(input))
+    }
+
+    /// <p>Configure Amazon WorkDocs to use Amazon SNS notifications. The endpoint receives a confirmation message, and must confirm the subscription.</p> <p>For more information, see <a href="https://docs.aws.amazon.com/workdocs/latest/developerguide/subscribe-notifications.html">Subscribe to Notifications</a> in the <i>Amazon WorkDocs Developer Guide</i>.</p>
+    ///
+    /// See `Workdocs::create_notification_subscription`.
+    pub fn create_notification_subscription(
+        &self,
+        input: CreateNotificationSubscriptionRequest,
+    ) -> Result<
+        CreateNotificationSubscriptionResponse,
+        RusotoError<CreateNotificationSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_notification_subscription(input))
+    }
+
+    /// <p>Creates a user in a Simple AD or Microsoft AD directory. The status of a newly created user is "ACTIVE". New users can access Amazon WorkDocs.</p>
+    ///
+    /// See `Workdocs::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Deactivates the specified user, which revokes the user's access to Amazon WorkDocs.</p>
+    ///
+    /// See `Workdocs::deactivate_user`.
+    pub fn deactivate_user(
+        &self,
+        input: DeactivateUserRequest,
+    ) -> Result<(), RusotoError<DeactivateUserError>> {
+        self.runtime.block_on(self.client.deactivate_user(input))
+    }
+
+    /// <p>Deletes the specified comment from the document version.</p>
+    ///
+    /// See `Workdocs::delete_comment`.
+    pub fn delete_comment(
+        &self,
+        input: DeleteCommentRequest,
+    ) -> Result<(), RusotoError<DeleteCommentError>> {
+        self.runtime.block_on(self.client.delete_comment(input))
+    }
+
+    /// <p>Deletes custom metadata from the specified resource.</p>
+    ///
+    /// See `Workdocs::delete_custom_metadata`.
+    pub fn delete_custom_metadata(
+        &self,
+        input: DeleteCustomMetadataRequest,
+    ) -> Result<DeleteCustomMetadataResponse, RusotoError<DeleteCustomMetadataError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_metadata(input))
+    }
+
+    /// <p>Permanently deletes the specified document and its associated metadata.</p>
+    ///
+    /// See `Workdocs::delete_document`.
+    pub fn delete_document(
+        &self,
+        input: DeleteDocumentRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentError>> {
+        self.runtime.block_on(self.client.delete_document(input))
+    }
+
+    /// <p>Permanently deletes the specified folder and its contents.</p>
+    ///
+    /// See `Workdocs::delete_folder`.
+    pub fn delete_folder(
+        &self,
+        input: DeleteFolderRequest,
+    ) -> Result<(), RusotoError<DeleteFolderError>> {
+        self.runtime.block_on(self.client.delete_folder(input))
+    }
+
+    /// <p>Deletes the contents of the specified folder.</p>
+    ///
+    /// See `Workdocs::delete_folder_contents`.
+    pub fn delete_folder_contents(
+        &self,
+        input: DeleteFolderContentsRequest,
+    ) -> Result<(), RusotoError<DeleteFolderContentsError>> {
+        self.runtime
+            .block_on(self.client.delete_folder_contents(input))
+    }
+
+    /// <p>Deletes the specified list of labels from a resource.</p>
+    ///
+    /// See `Workdocs::delete_labels`.
+    pub fn delete_labels(
+        &self,
+        input: DeleteLabelsRequest,
+    ) -> Result<DeleteLabelsResponse, RusotoError<DeleteLabelsError>> {
+        self.runtime.block_on(self.client.delete_labels(input))
+    }
+
+    /// <p>Deletes the specified subscription from the specified organization.</p>
+    ///
+    /// See `Workdocs::delete_notification_subscription`.
+    pub fn delete_notification_subscription(
+        &self,
+        input: DeleteNotificationSubscriptionRequest,
+    ) -> Result<(), RusotoError<DeleteNotificationSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_subscription(input))
+    }
+
+    /// <p>Deletes the specified user from a Simple AD or Microsoft AD directory.</p>
+    ///
+    /// See `Workdocs::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Describes the user activities in a specified time period.</p>
+    ///
+    /// See `Workdocs::describe_activities`.
+    pub fn describe_activities(
+        &self,
+        input: DescribeActivitiesRequest,
+    ) -> Result<DescribeActivitiesResponse, RusotoError<DescribeActivitiesError>> {
+        self.runtime
+            .block_on(self.client.describe_activities(input))
+    }
+
+    /// <p>List all the comments for the specified document version.</p>
+    ///
+    /// See `Workdocs::describe_comments`.
+    pub fn describe_comments(
+        &self,
+        input: DescribeCommentsRequest,
+    ) -> Result<DescribeCommentsResponse, RusotoError<DescribeCommentsError>> {
+        self.runtime.block_on(self.client.describe_comments(input))
+    }
+
+    /// <p>Retrieves the document versions for the specified document.</p> <p>By default, only active versions are returned.</p>
+    ///
+    /// See `Workdocs::describe_document_versions`.
+    pub fn describe_document_versions(
+        &self,
+        input: DescribeDocumentVersionsRequest,
+    ) -> Result<DescribeDocumentVersionsResponse, RusotoError<DescribeDocumentVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_document_versions(input))
+    }
+
+    /// <p>Describes the contents of the specified folder, including its documents and subfolders.</p> <p>By default, Amazon WorkDocs returns the first 100 active document and folder metadata items. If there are more results, the response includes a marker that you can use to request the next set of results. You can also request initialized documents.</p>
+    ///
+    /// See `Workdocs::describe_folder_contents`.
+    pub fn describe_folder_contents(
+        &self,
+        input: DescribeFolderContentsRequest,
+    ) -> Result<DescribeFolderContentsResponse, RusotoError<DescribeFolderContentsError>> {
+        self.runtime
+            .block_on(self.client.describe_folder_contents(input))
+    }
+
+    /// <p>Describes the groups specified by the query. Groups are defined by the underlying Active Directory.</p>
+    ///
+    /// See `Workdocs::describe_groups`.
+    pub fn describe_groups(
+        &self,
+        input: DescribeGroupsRequest,
+    ) -> Result<DescribeGroupsResponse, RusotoError<DescribeGroupsError>> {
+        self.runtime.block_on(self.client.describe_groups(input))
+    }
+
+    /// <p>Lists the specified notification subscriptions.</p>
+    ///
+    /// See `Workdocs::describe_notification_subscriptions`.
+    pub fn describe_notification_subscriptions(
+        &self,
+        input: DescribeNotificationSubscriptionsRequest,
+    ) -> Result<
+        DescribeNotificationSubscriptionsResponse,
+        RusotoError<DescribeNotificationSubscriptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_notification_subscriptions(input))
+    }
+
+    /// <p>Describes the permissions of a specified resource.</p>
+    ///
+    /// See `Workdocs::describe_resource_permissions`.
+    pub fn describe_resource_permissions(
+        &self,
+        input: DescribeResourcePermissionsRequest,
+    ) -> Result<DescribeResourcePermissionsResponse, RusotoError<DescribeResourcePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_resource_permissions(input))
+    }
+
+    /// <p>Describes the current user's special folders; the <code>RootFolder</code> and the <code>RecycleBin</code>. <code>RootFolder</code> is the root of user's files and folders and <code>RecycleBin</code> is the root of recycled items. This is not a valid action for SigV4 (administrative API) clients.</p> <p>This action requires an authentication token. To get an authentication token, register an application with Amazon WorkDocs. For more information, see <a href="https://docs.aws.amazon.com/workdocs/latest/developerguide/wd-auth-user.html">Authentication and Access Control for User Applications</a> in the <i>Amazon WorkDocs Developer Guide</i>.</p>
+    ///
+    /// See `Workdocs::describe_root_folders`.
+    pub fn describe_root_folders(
+        &self,
+        input: DescribeRootFoldersRequest,
+    ) -> Result<DescribeRootFoldersResponse, RusotoError<DescribeRootFoldersError>> {
+        self.runtime
+            .block_on(self.client.describe_root_folders(input))
+    }
+
+    /// <p>Describes the specified users. You can describe all users or filter the results (for example, by status or organization).</p> <p>By default, Amazon WorkDocs returns the first 24 active or pending users. If there are more results, the response includes a marker that you can use to request the next set of results.</p>
+    ///
+    /// See `Workdocs::describe_users`.
+    pub fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> Result<DescribeUsersResponse, RusotoError<DescribeUsersError>> {
+        self.runtime.block_on(self.client.describe_users(input))
+    }
+
+    /// <p>Retrieves details of the current user for whom the authentication token was generated. This is not a valid action for SigV4 (administrative API) clients.</p> <p>This action requires an authentication token. To get an authentication token, register an application with Amazon WorkDocs. For more information, see <a href="https://docs.aws.amazon.com/workdocs/latest/developerguide/wd-auth-user.html">Authentication and Access Control for User Applications</a> in the <i>Amazon WorkDocs Developer Guide</i>.</p>
+    ///
+    /// See `Workdocs::get_current_user`.
+    pub fn get_current_user(
+        &self,
+        input: GetCurrentUserRequest,
+    ) -> Result<GetCurrentUserResponse, RusotoError<GetCurrentUserError>> {
+        self.runtime.block_on(self.client.get_current_user(input))
+    }
+
+    /// <p>Retrieves details of a document.</p>
+    ///
+    /// See `Workdocs::get_document`.
+    pub fn get_document(
+        &self,
+        input: GetDocumentRequest,
+    ) -> Result<GetDocumentResponse, RusotoError<GetDocumentError>> {
+        self.runtime.block_on(self.client.get_document(input))
+    }
+
+    /// <p>Retrieves the path information (the hierarchy from the root folder) for the requested document.</p> <p>By default, Amazon WorkDocs returns a maximum of 100 levels upwards from the requested document and only includes the IDs of the parent folders in the path. You can limit the maximum number of levels. You can also request the names of the parent folders.</p>
+    ///
+    /// See `Workdocs::get_document_path`.
+    pub fn get_document_path(
+        &self,
+        input: GetDocumentPathRequest,
+    ) -> Result<GetDocumentPathResponse, RusotoError<GetDocumentPathError>> {
+        self.runtime.block_on(self.client.get_document_path(input))
+    }
+
+    /// <p>Retrieves version metadata for the specified document.</p>
+    ///
+    /// See `Workdocs::get_document_version`.
+    pub fn get_document_version(
+        &self,
+        input: GetDocumentVersionRequest,
+    ) -> Result<GetDocumentVersionResponse, RusotoError<GetDocumentVersionError>> {
+        self.runtime
+            .block_on(self.client.get_document_version(input))
+    }
+
+    /// <p>Retrieves the metadata of the specified folder.</p>
+    ///
+    /// See `Workdocs::get_folder`.
+    pub fn get_folder(
+        &self,
+        input: GetFolderRequest,
+    ) -> Result<GetFolderResponse, RusotoError<GetFolderError>> {
+        self.runtime.block_on(self.client.get_folder(input))
+    }
+
+    /// <p>Retrieves the path information (the hierarchy from the root folder) for the specified folder.</p> <p>By default, Amazon WorkDocs returns a maximum of 100 levels upwards from the requested folder and only includes the IDs of the parent folders in the path. You can limit the maximum number of levels. You can also request the parent folder names.</p>
+    ///
+    /// See `Workdocs::get_folder_path`.
+    pub fn get_folder_path(
+        &self,
+        input: GetFolderPathRequest,
+    ) -> Result<GetFolderPathResponse, RusotoError<GetFolderPathError>> {
+        self.runtime.block_on(self.client.get_folder_path(input))
+    }
+
+    /// <p>Retrieves a collection of resources, including folders and documents. The only <code>CollectionType</code> supported is <code>SHARED_WITH_ME</code>.</p>
+    ///
+    /// See `Workdocs::get_resources`.
+    pub fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> Result<GetResourcesResponse, RusotoError<GetResourcesError>> {
+        self.runtime.block_on(self.client.get_resources(input))
+    }
+
+    /// <p>Creates a new document object and version object.</p> <p>The client specifies the parent folder ID and name of the document to upload. The ID is optionally specified when creating a new version of an existing document. This is the first step to upload a document. Next, upload the document to the URL returned from the call, and then call <a>UpdateDocumentVersion</a>.</p> <p>To cancel the document upload, call <a>AbortDocumentVersionUpload</a>.</p>
+    ///
+    /// See `Workdocs::initiate_document_version_upload`.
+    pub fn initiate_document_version_upload(
+        &self,
+        input: InitiateDocumentVersionUploadRequest,
+    ) -> Result<
+        InitiateDocumentVersionUploadResponse,
+        RusotoError<InitiateDocumentVersionUploadError>,
+    > {
+        self.runtime
+            .block_on(self.client.initiate_document_version_upload(input))
+    }
+
+    /// <p>Removes all the permissions from the specified resource.</p>
+    ///
+    /// See `Workdocs::remove_all_resource_permissions`.
+    pub fn remove_all_resource_permissions(
+        &self,
+        input: RemoveAllResourcePermissionsRequest,
+    ) -> Result<(), RusotoError<RemoveAllResourcePermissionsError>> {
+        self.runtime
+            .block_on(self.client.remove_all_resource_permissions(input))
+    }
+
+    /// <p>Removes the permission for the specified principal from the specified resource.</p>
+    ///
+    /// See `Workdocs::remove_resource_permission`.
+    pub fn remove_resource_permission(
+        &self,
+        input: RemoveResourcePermissionRequest,
+    ) -> Result<(), RusotoError<RemoveResourcePermissionError>> {
+        self.runtime
+            .block_on(self.client.remove_resource_permission(input))
+    }
+
+    /// <p>Updates the specified attributes of a document. The user must have access to both the document and its parent folder, if applicable.</p>
+    ///
+    /// See `Workdocs::update_document`.
+    pub fn update_document(
+        &self,
+        input: UpdateDocumentRequest,
+    ) -> Result<(), RusotoError<UpdateDocumentError>> {
+        self.runtime.block_on(self.client.update_document(input))
+    }
+
+    /// <p>Changes the status of the document version to ACTIVE. </p> <p>Amazon WorkDocs also sets its document container to ACTIVE. This is the last step in a document upload, after the client uploads the document to an S3-presigned URL returned by <a>InitiateDocumentVersionUpload</a>. </p>
+    ///
+    /// See `Workdocs::update_document_version`.
+    pub fn update_document_version(
+        &self,
+        input: UpdateDocumentVersionRequest,
+    ) -> Result<(), RusotoError<UpdateDocumentVersionError>> {
+        self.runtime
+            .block_on(self.client.update_document_version(input))
+    }
+
+    /// <p>Updates the specified attributes of the specified folder. The user must have access to both the folder and its parent folder, if applicable.</p>
+    ///
+    /// See `Workdocs::update_folder`.
+    pub fn update_folder(
+        &self,
+        input: UpdateFolderRequest,
+    ) -> Result<(), RusotoError<UpdateFolderError>> {
+        self.runtime.block_on(self.client.update_folder(input))
+    }
+
+    /// <p>Updates the specified attributes of the specified user, and grants or revokes administrative privileges to the Amazon WorkDocs site.</p>
+    ///
+    /// See `Workdocs::update_user`.
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+}
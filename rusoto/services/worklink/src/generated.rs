@@ -903,24 +903,37 @@ impl AssociateDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateDomainError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDomainError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateDomainError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDomainError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(AssociateDomainError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDomainError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AssociateDomainError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDomainError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AssociateDomainError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, AssociateDomainError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -967,32 +980,38 @@ impl AssociateWebsiteAuthorizationProviderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteAuthorizationProviderError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteAuthorizationProviderError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteAuthorizationProviderError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteAuthorizationProviderError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteAuthorizationProviderError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteAuthorizationProviderError::Unauthorized(err.msg),
                     )
                 }
@@ -1053,32 +1072,38 @@ impl AssociateWebsiteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteCertificateAuthorityError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteCertificateAuthorityError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteCertificateAuthorityError::ResourceAlreadyExists(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteCertificateAuthorityError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteCertificateAuthorityError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateWebsiteCertificateAuthorityError::Unauthorized(err.msg),
                     )
                 }
@@ -1137,22 +1162,28 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFleetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::InvalidRequest(err.msg))
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateFleetError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFleetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFleetError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, CreateFleetError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1195,19 +1226,22 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteFleetError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFleetError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFleetError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DeleteFleetError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1251,27 +1285,32 @@ impl DescribeAuditStreamConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditStreamConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditStreamConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditStreamConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditStreamConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeAuditStreamConfigurationError::Unauthorized(err.msg),
                     )
                 }
@@ -1327,27 +1366,32 @@ impl DescribeCompanyNetworkConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCompanyNetworkConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCompanyNetworkConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCompanyNetworkConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCompanyNetworkConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeCompanyNetworkConfigurationError::Unauthorized(err.msg),
                     )
                 }
@@ -1401,19 +1445,28 @@ impl DescribeDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeDeviceError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeviceError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeDeviceError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeviceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDeviceError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDeviceError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeDeviceError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1457,27 +1510,32 @@ impl DescribeDevicePolicyConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDevicePolicyConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDevicePolicyConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDevicePolicyConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDevicePolicyConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeDevicePolicyConfigurationError::Unauthorized(err.msg),
                     )
                 }
@@ -1531,19 +1589,28 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeDomainError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDomainError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DescribeDomainError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDomainError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeDomainError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDomainError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, DescribeDomainError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1585,27 +1652,34 @@ impl DescribeFleetMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetMetadataError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::InvalidRequest(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetMetadataError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetMetadataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetMetadataError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetMetadataError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeFleetMetadataError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1649,27 +1723,32 @@ impl DescribeIdentityProviderConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIdentityProviderConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIdentityProviderConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIdentityProviderConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIdentityProviderConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeIdentityProviderConfigurationError::Unauthorized(err.msg),
                     )
                 }
@@ -1725,27 +1804,32 @@ impl DescribeWebsiteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWebsiteCertificateAuthorityError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWebsiteCertificateAuthorityError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWebsiteCertificateAuthorityError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWebsiteCertificateAuthorityError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWebsiteCertificateAuthorityError::Unauthorized(err.msg),
                     )
                 }
@@ -1799,21 +1883,34 @@ impl DisassociateDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DisassociateDomainError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDomainError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisassociateDomainError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDomainError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDomainError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisassociateDomainError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDomainError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DisassociateDomainError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateDomainError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1859,34 +1956,40 @@ impl DisassociateWebsiteAuthorizationProviderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteAuthorizationProviderError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteAuthorizationProviderError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteAuthorizationProviderError::ResourceAlreadyExists(
                             err.msg,
                         ),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteAuthorizationProviderError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteAuthorizationProviderError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteAuthorizationProviderError::Unauthorized(err.msg),
                     )
                 }
@@ -1945,27 +2048,32 @@ impl DisassociateWebsiteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteCertificateAuthorityError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteCertificateAuthorityError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteCertificateAuthorityError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteCertificateAuthorityError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateWebsiteCertificateAuthorityError::Unauthorized(err.msg),
                     )
                 }
@@ -2019,19 +2127,22 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDevicesError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDevicesError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDevicesError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListDevicesError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2073,19 +2184,22 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDomainsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListDomainsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDomainsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListDomainsError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDomainsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, ListDomainsError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDomainsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListDomainsError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDomainsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListDomainsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2125,16 +2239,19 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFleetsError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListFleetsError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::InvalidRequest(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFleetsError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFleetsError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, ListFleetsError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2167,7 +2284,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::InvalidRequest(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2207,27 +2327,32 @@ impl ListWebsiteAuthorizationProvidersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteAuthorizationProvidersError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteAuthorizationProvidersError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteAuthorizationProvidersError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteAuthorizationProvidersError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteAuthorizationProvidersError::Unauthorized(err.msg),
                     )
                 }
@@ -2281,22 +2406,26 @@ impl ListWebsiteCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteCertificateAuthoritiesError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteCertificateAuthoritiesError::InvalidRequest(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteCertificateAuthoritiesError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListWebsiteCertificateAuthoritiesError::Unauthorized(err.msg),
                     )
                 }
@@ -2347,23 +2476,34 @@ impl RestoreDomainAccessError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreDomainAccessError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreDomainAccessError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreDomainAccessError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreDomainAccessError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RestoreDomainAccessError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreDomainAccessError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2405,21 +2545,34 @@ impl RevokeDomainAccessError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeDomainAccessError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeDomainAccessError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeDomainAccessError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeDomainAccessError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RevokeDomainAccessError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeDomainAccessError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2461,19 +2614,22 @@ impl SignOutUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(SignOutUserError::InternalServerError(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        SignOutUserError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SignOutUserError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, SignOutUserError::InvalidRequest(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SignOutUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, SignOutUserError::ResourceNotFound(err.msg))
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SignOutUserError::TooManyRequests(err.msg))
+                    return RusotoError::service(&res, SignOutUserError::TooManyRequests(err.msg))
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SignOutUserError::Unauthorized(err.msg))
+                    return RusotoError::service(&res, SignOutUserError::Unauthorized(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2507,7 +2663,7 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, TagResourceError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2537,7 +2693,7 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UntagResourceError::InvalidRequest(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2577,29 +2733,34 @@ impl UpdateAuditStreamConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAuditStreamConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAuditStreamConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAuditStreamConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateAuditStreamConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAuditStreamConfigurationError::Unauthorized(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateAuditStreamConfigurationError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2651,27 +2812,32 @@ impl UpdateCompanyNetworkConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCompanyNetworkConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCompanyNetworkConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCompanyNetworkConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCompanyNetworkConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateCompanyNetworkConfigurationError::Unauthorized(err.msg),
                     )
                 }
@@ -2727,27 +2893,32 @@ impl UpdateDevicePolicyConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDevicePolicyConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDevicePolicyConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDevicePolicyConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDevicePolicyConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateDevicePolicyConfigurationError::Unauthorized(err.msg),
                     )
                 }
@@ -2799,25 +2970,34 @@ impl UpdateDomainMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainMetadataError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainMetadataError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainMetadataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::TooManyRequests(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainMetadataError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainMetadataError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateDomainMetadataError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2859,23 +3039,34 @@ impl UpdateFleetMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::InternalServerError(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetMetadataError::InternalServerError(err.msg),
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetMetadataError::InvalidRequest(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetMetadataError::ResourceNotFound(err.msg),
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::TooManyRequests(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetMetadataError::TooManyRequests(err.msg),
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetMetadataError::Unauthorized(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateFleetMetadataError::Unauthorized(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2919,27 +3110,32 @@ impl UpdateIdentityProviderConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIdentityProviderConfigurationError::InternalServerError(err.msg),
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIdentityProviderConfigurationError::InvalidRequest(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIdentityProviderConfigurationError::ResourceNotFound(err.msg),
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIdentityProviderConfigurationError::TooManyRequests(err.msg),
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdateIdentityProviderConfigurationError::Unauthorized(err.msg),
                     )
                 }
@@ -3237,6 +3433,7 @@ impl WorklinkClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3252,6 +3449,7 @@ impl WorklinkClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> WorklinkClient {
         WorklinkClient { client, region }
     }
@@ -3268,6 +3466,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("AssociateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3302,6 +3503,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("AssociateWebsiteAuthorizationProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3338,6 +3542,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/associateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("AssociateWebsiteCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3371,6 +3578,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("CreateFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3402,6 +3612,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DeleteFleet");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3436,6 +3649,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeAuditStreamConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3472,6 +3688,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeCompanyNetworkConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3505,6 +3724,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevice";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeDevice");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3539,6 +3761,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeDevicePolicyConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3572,6 +3797,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeDomain");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3603,6 +3831,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeFleetMetadata");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3637,6 +3868,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeIdentityProviderConfiguration");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3673,6 +3907,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/describeWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DescribeWebsiteCertificateAuthority");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3706,6 +3943,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateDomain";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DisassociateDomain");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3740,6 +3980,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteAuthorizationProvider";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DisassociateWebsiteAuthorizationProvider");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3774,6 +4017,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/disassociateWebsiteCertificateAuthority";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("DisassociateWebsiteCertificateAuthority");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3807,6 +4053,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDevices";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("ListDevices");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3838,6 +4087,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listDomains";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("ListDomains");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3869,6 +4121,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("ListFleets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3900,6 +4155,9 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "worklink", &self.region, &request_uri);
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let mut response = self
@@ -3931,6 +4189,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteAuthorizationProviders";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("ListWebsiteAuthorizationProviders");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3967,6 +4228,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/listWebsiteCertificateAuthorities";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("ListWebsiteCertificateAuthorities");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4000,6 +4264,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/restoreDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("RestoreDomainAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4031,6 +4298,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/revokeDomainAccess";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("RevokeDomainAccess");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4062,6 +4332,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/signOutUser";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("SignOutUser");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4093,6 +4366,9 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4124,6 +4400,9 @@ impl Worklink for WorklinkClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "worklink", &self.region, &request_uri);
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.set_content_type("application/json".to_owned());
 
         let mut params = Params::new();
@@ -4161,6 +4440,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateAuditStreamConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("UpdateAuditStreamConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4195,6 +4477,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateCompanyNetworkConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("UpdateCompanyNetworkConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4231,6 +4516,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDevicePolicyConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("UpdateDevicePolicyConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4264,6 +4552,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateDomainMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("UpdateDomainMetadata");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4295,6 +4586,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/UpdateFleetMetadata";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("UpdateFleetMetadata");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4329,6 +4623,9 @@ impl Worklink for WorklinkClient {
         let request_uri = "/updateIdentityProviderConfiguration";
 
         let mut request = SignedRequest::new("POST", "worklink", &self.region, &request_uri);
+        request.set_operation_name("UpdateIdentityProviderConfiguration");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/json".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4353,3 +4650,454 @@ impl Worklink for WorklinkClient {
         }
     }
 }
+
+/// A blocking client for the WorkLink API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `WorklinkClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorklinkBlockingClient {
+    client: WorklinkClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorklinkBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorklinkBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        WorklinkBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorklinkBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorklinkBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorklinkBlockingClient {
+        WorklinkBlockingClient {
+            client: WorklinkClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Specifies a domain to be associated to Amazon WorkLink.</p>
+    ///
+    /// See `Worklink::associate_domain`.
+    pub fn associate_domain(
+        &self,
+        input: AssociateDomainRequest,
+    ) -> Result<AssociateDomainResponse, RusotoError<AssociateDomainError>> {
+        self.runtime.block_on(self.client.associate_domain(input))
+    }
+
+    /// <p>Associates a website authorization provider with a specified fleet. This is used to authorize users against associated websites in the company network.</p>
+    ///
+    /// See `Worklink::associate_website_authorization_provider`.
+    pub fn associate_website_authorization_provider(
+        &self,
+        input: AssociateWebsiteAuthorizationProviderRequest,
+    ) -> Result<
+        AssociateWebsiteAuthorizationProviderResponse,
+        RusotoError<AssociateWebsiteAuthorizationProviderError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_website_authorization_provider(input))
+    }
+
+    /// <p>Imports the root certificate of a certificate authority (CA) used to obtain TLS certificates used by associated websites within the company network.</p>
+    ///
+    /// See `Worklink::associate_website_certificate_authority`.
+    pub fn associate_website_certificate_authority(
+        &self,
+        input: AssociateWebsiteCertificateAuthorityRequest,
+    ) -> Result<
+        AssociateWebsiteCertificateAuthorityResponse,
+        RusotoError<AssociateWebsiteCertificateAuthorityError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_website_certificate_authority(input))
+    }
+
+    /// <p>Creates a fleet. A fleet consists of resources and the configuration that delivers associated websites to authorized users who download and set up the Amazon WorkLink app.</p>
+    ///
+    /// See `Worklink::create_fleet`.
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResponse, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// <p>Deletes a fleet. Prevents users from accessing previously associated websites. </p>
+    ///
+    /// See `Worklink::delete_fleet`.
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResponse, RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// <p>Describes the configuration for delivering audit streams to the customer account.</p>
+    ///
+    /// See `Worklink::describe_audit_stream_configuration`.
+    pub fn describe_audit_stream_configuration(
+        &self,
+        input: DescribeAuditStreamConfigurationRequest,
+    ) -> Result<
+        DescribeAuditStreamConfigurationResponse,
+        RusotoError<DescribeAuditStreamConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_audit_stream_configuration(input))
+    }
+
+    /// <p>Describes the networking configuration to access the internal websites associated with the specified fleet.</p>
+    ///
+    /// See `Worklink::describe_company_network_configuration`.
+    pub fn describe_company_network_configuration(
+        &self,
+        input: DescribeCompanyNetworkConfigurationRequest,
+    ) -> Result<
+        DescribeCompanyNetworkConfigurationResponse,
+        RusotoError<DescribeCompanyNetworkConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_company_network_configuration(input))
+    }
+
+    /// <p>Provides information about a user's device.</p>
+    ///
+    /// See `Worklink::describe_device`.
+    pub fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
+        self.runtime.block_on(self.client.describe_device(input))
+    }
+
+    /// <p>Describes the device policy configuration for the specified fleet.</p>
+    ///
+    /// See `Worklink::describe_device_policy_configuration`.
+    pub fn describe_device_policy_configuration(
+        &self,
+        input: DescribeDevicePolicyConfigurationRequest,
+    ) -> Result<
+        DescribeDevicePolicyConfigurationResponse,
+        RusotoError<DescribeDevicePolicyConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_device_policy_configuration(input))
+    }
+
+    /// <p>Provides information about the domain.</p>
+    ///
+    /// See `Worklink::describe_domain`.
+    pub fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
+        self.runtime.block_on(self.client.describe_domain(input))
+    }
+
+    /// <p>Provides basic information for the specified fleet, excluding identity provider, networking, and device configuration details.</p>
+    ///
+    /// See `Worklink::describe_fleet_metadata`.
+    pub fn describe_fleet_metadata(
+        &self,
+        input: DescribeFleetMetadataRequest,
+    ) -> Result<DescribeFleetMetadataResponse, RusotoError<DescribeFleetMetadataError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_metadata(input))
+    }
+
+    /// <p>Describes the identity provider configuration of the specified fleet.</p>
+    ///
+    /// See `Worklink::describe_identity_provider_configuration`.
+    pub fn describe_identity_provider_configuration(
+        &self,
+        input: DescribeIdentityProviderConfigurationRequest,
+    ) -> Result<
+        DescribeIdentityProviderConfigurationResponse,
+        RusotoError<DescribeIdentityProviderConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_identity_provider_configuration(input))
+    }
+
+    /// <p>Provides information about the certificate authority.</p>
+    ///
+    /// See `Worklink::describe_website_certificate_authority`.
+    pub fn describe_website_certificate_authority(
+        &self,
+        input: DescribeWebsiteCertificateAuthorityRequest,
+    ) -> Result<
+        DescribeWebsiteCertificateAuthorityResponse,
+        RusotoError<DescribeWebsiteCertificateAuthorityError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_website_certificate_authority(input))
+    }
+
+    /// <p>Disassociates a domain from Amazon WorkLink. End users lose the ability to access the domain with Amazon WorkLink. </p>
+    ///
+    /// See `Worklink::disassociate_domain`.
+    pub fn disassociate_domain(
+        &self,
+        input: DisassociateDomainRequest,
+    ) -> Result<DisassociateDomainResponse, RusotoError<DisassociateDomainError>> {
+        self.runtime
+            .block_on(self.client.disassociate_domain(input))
+    }
+
+    /// <p>Disassociates a website authorization provider from a specified fleet. After the disassociation, users can't load any associated websites that require this authorization provider.</p>
+    ///
+    /// See `Worklink::disassociate_website_authorization_provider`.
+    pub fn disassociate_website_authorization_provider(
+        &self,
+        input: DisassociateWebsiteAuthorizationProviderRequest,
+    ) -> Result<
+        DisassociateWebsiteAuthorizationProviderResponse,
+        RusotoError<DisassociateWebsiteAuthorizationProviderError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_website_authorization_provider(input),
+        )
+    }
+
+    /// <p>Removes a certificate authority (CA).</p>
+    ///
+    /// See `Worklink::disassociate_website_certificate_authority`.
+    pub fn disassociate_website_certificate_authority(
+        &self,
+        input: DisassociateWebsiteCertificateAuthorityRequest,
+    ) -> Result<
+        DisassociateWebsiteCertificateAuthorityResponse,
+        RusotoError<DisassociateWebsiteCertificateAuthorityError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_website_certificate_authority(input),
+        )
+    }
+
+    /// <p>Retrieves a list of devices registered with the specified fleet.</p>
+    ///
+    /// See `Worklink::list_devices`.
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// <p>Retrieves a list of domains associated to a specified fleet.</p>
+    ///
+    /// See `Worklink::list_domains`.
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// <p>Retrieves a list of fleets for the current account and Region.</p>
+    ///
+    /// See `Worklink::list_fleets`.
+    pub fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> Result<ListFleetsResponse, RusotoError<ListFleetsError>> {
+        self.runtime.block_on(self.client.list_fleets(input))
+    }
+
+    /// <p>Retrieves a list of tags for the specified resource.</p>
+    ///
+    /// See `Worklink::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Retrieves a list of website authorization providers associated with a specified fleet.</p>
+    ///
+    /// See `Worklink::list_website_authorization_providers`.
+    pub fn list_website_authorization_providers(
+        &self,
+        input: ListWebsiteAuthorizationProvidersRequest,
+    ) -> Result<
+        ListWebsiteAuthorizationProvidersResponse,
+        RusotoError<ListWebsiteAuthorizationProvidersError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_website_authorization_providers(input))
+    }
+
+    /// <p>Retrieves a list of certificate authorities added for the current account and Region.</p>
+    ///
+    /// See `Worklink::list_website_certificate_authorities`.
+    pub fn list_website_certificate_authorities(
+        &self,
+        input: ListWebsiteCertificateAuthoritiesRequest,
+    ) -> Result<
+        ListWebsiteCertificateAuthoritiesResponse,
+        RusotoError<ListWebsiteCertificateAuthoritiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_website_certificate_authorities(input))
+    }
+
+    /// <p>Moves a domain to ACTIVE status if it was in the INACTIVE status.</p>
+    ///
+    /// See `Worklink::restore_domain_access`.
+    pub fn restore_domain_access(
+        &self,
+        input: RestoreDomainAccessRequest,
+    ) -> Result<RestoreDomainAccessResponse, RusotoError<RestoreDomainAccessError>> {
+        self.runtime
+            .block_on(self.client.restore_domain_access(input))
+    }
+
+    /// <p>Moves a domain to INACTIVE status if it was in the ACTIVE status.</p>
+    ///
+    /// See `Worklink::revoke_domain_access`.
+    pub fn revoke_domain_access(
+        &self,
+        input: RevokeDomainAccessRequest,
+    ) -> Result<RevokeDomainAccessResponse, RusotoError<RevokeDomainAccessError>> {
+        self.runtime
+            .block_on(self.client.revoke_domain_access(input))
+    }
+
+    /// <p>Signs the user out from all of their devices. The user can sign in again if they have valid credentials.</p>
+    ///
+    /// See `Worklink::sign_out_user`.
+    pub fn sign_out_user(
+        &self,
+        input: SignOutUserRequest,
+    ) -> Result<SignOutUserResponse, RusotoError<SignOutUserError>> {
+        self.runtime.block_on(self.client.sign_out_user(input))
+    }
+
+    /// <p>Adds or overwrites one or more tags for the specified resource, such as a fleet. Each tag consists of a key and an optional value. If a resource already has a tag with the same key, this operation updates its value.</p>
+    ///
+    /// See `Worklink::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Removes one or more tags from the specified resource.</p>
+    ///
+    /// See `Worklink::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates the audit stream configuration for the fleet.</p>
+    ///
+    /// See `Worklink::update_audit_stream_configuration`.
+    pub fn update_audit_stream_configuration(
+        &self,
+        input: UpdateAuditStreamConfigurationRequest,
+    ) -> Result<
+        UpdateAuditStreamConfigurationResponse,
+        RusotoError<UpdateAuditStreamConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_audit_stream_configuration(input))
+    }
+
+    /// <p>Updates the company network configuration for the fleet.</p>
+    ///
+    /// See `Worklink::update_company_network_configuration`.
+    pub fn update_company_network_configuration(
+        &self,
+        input: UpdateCompanyNetworkConfigurationRequest,
+    ) -> Result<
+        UpdateCompanyNetworkConfigurationResponse,
+        RusotoError<UpdateCompanyNetworkConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_company_network_configuration(input))
+    }
+
+    /// <p>Updates the device policy configuration for the fleet.</p>
+    ///
+    /// See `Worklink::update_device_policy_configuration`.
+    pub fn update_device_policy_configuration(
+        &self,
+        input: UpdateDevicePolicyConfigurationRequest,
+    ) -> Result<
+        UpdateDevicePolicyConfigurationResponse,
+        RusotoError<UpdateDevicePolicyConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_device_policy_configuration(input))
+    }
+
+    /// <p>Updates domain metadata, such as DisplayName.</p>
+    ///
+    /// See `Worklink::update_domain_metadata`.
+    pub fn update_domain_metadata(
+        &self,
+        input: UpdateDomainMetadataRequest,
+    ) -> Result<UpdateDomainMetadataResponse, RusotoError<UpdateDomainMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_domain_metadata(input))
+    }
+
+    /// <p>Updates fleet metadata, such as DisplayName.</p>
+    ///
+    /// See `Worklink::update_fleet_metadata`.
+    pub fn update_fleet_metadata(
+        &self,
+        input: UpdateFleetMetadataRequest,
+    ) -> Result<UpdateFleetMetadataResponse, RusotoError<UpdateFleetMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_metadata(input))
+    }
+
+    /// <p>Updates the identity provider configuration for the fleet.</p>
+    ///
+    /// See `Worklink::update_identity_provider_configuration`.
+    pub fn update_identity_provider_configuration(
+        &self,
+        input: UpdateIdentityProviderConfigurationRequest,
+    ) -> Result<
+        UpdateIdentityProviderConfigurationResponse,
+        RusotoError<UpdateIdentityProviderConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_identity_provider_configuration(input))
+    }
+}
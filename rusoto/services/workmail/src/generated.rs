@@ -1391,27 +1391,32 @@ impl AssociateDelegateToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(AssociateDelegateToResourceError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDelegateToResourceError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(AssociateDelegateToResourceError::EntityState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateDelegateToResourceError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDelegateToResourceError::InvalidParameter(err.msg),
                     )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDelegateToResourceError::OrganizationNotFound(err.msg),
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateDelegateToResourceError::OrganizationState(err.msg),
                     )
                 }
@@ -1465,42 +1470,52 @@ impl AssociateMemberToGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         AssociateMemberToGroupError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberToGroupError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberToGroupError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::EntityState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberToGroupError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberToGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberToGroupError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberToGroupError::OrganizationState(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AssociateMemberToGroupError::UnsupportedOperation(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateMemberToGroupError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1555,31 +1570,37 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(CreateAliasError::EmailAddressInUse(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::EmailAddressInUse(err.msg))
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::EntityNotFound(err.msg))
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(CreateAliasError::EntityState(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::InvalidParameter(err.msg))
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::LimitExceeded(err.msg))
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::MailDomainNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAliasError::MailDomainNotFound(err.msg),
+                    )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(CreateAliasError::MailDomainState(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::MailDomainState(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateAliasError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateAliasError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, CreateAliasError::OrganizationState(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1631,30 +1652,40 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateGroupError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateGroupError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::InvalidParameter(err.msg))
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(CreateGroupError::NameAvailability(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::NameAvailability(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateGroupError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::OrganizationState(err.msg))
                 }
                 "ReservedNameException" => {
-                    return RusotoError::Service(CreateGroupError::ReservedName(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::ReservedName(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateGroupError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateGroupError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1705,27 +1736,43 @@ impl CreateResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateResourceError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateResourceError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(CreateResourceError::NameAvailability(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceError::NameAvailability(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateResourceError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateResourceError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateResourceError::OrganizationState(err.msg),
+                    )
                 }
                 "ReservedNameException" => {
-                    return RusotoError::Service(CreateResourceError::ReservedName(err.msg))
+                    return RusotoError::service(&res, CreateResourceError::ReservedName(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1779,33 +1826,43 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         CreateUserError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, CreateUserError::InvalidParameter(err.msg))
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(CreateUserError::InvalidPassword(err.msg))
+                    return RusotoError::service(&res, CreateUserError::InvalidPassword(err.msg))
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(CreateUserError::NameAvailability(err.msg))
+                    return RusotoError::service(&res, CreateUserError::NameAvailability(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(CreateUserError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(CreateUserError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, CreateUserError::OrganizationState(err.msg))
                 }
                 "ReservedNameException" => {
-                    return RusotoError::Service(CreateUserError::ReservedName(err.msg))
+                    return RusotoError::service(&res, CreateUserError::ReservedName(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateUserError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateUserError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1847,14 +1904,16 @@ impl DeleteAccessControlRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteAccessControlRuleError::OrganizationNotFound(err.msg),
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteAccessControlRuleError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAccessControlRuleError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1893,19 +1952,22 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::EntityNotFound(err.msg))
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteAliasError::EntityState(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::InvalidParameter(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteAliasError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteAliasError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, DeleteAliasError::OrganizationState(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1951,27 +2013,37 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteGroupError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteGroupError::EntityState(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::InvalidParameter(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteGroupError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::OrganizationState(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteGroupError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteGroupError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2017,29 +2089,34 @@ impl DeleteMailboxPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMailboxPermissionsError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::EntityState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMailboxPermissionsError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMailboxPermissionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteMailboxPermissionsError::OrganizationNotFound(err.msg),
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteMailboxPermissionsError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteMailboxPermissionsError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2081,16 +2158,25 @@ impl DeleteResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteResourceError::EntityState(err.msg))
+                    return RusotoError::service(&res, DeleteResourceError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteResourceError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteResourceError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteResourceError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2127,19 +2213,22 @@ impl DeleteRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRetentionPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRetentionPolicyError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteRetentionPolicyError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2183,27 +2272,37 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeleteUserError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeleteUserError::EntityState(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::InvalidParameter(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeleteUserError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, DeleteUserError::OrganizationState(err.msg))
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteUserError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteUserError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2249,27 +2348,34 @@ impl DeregisterFromWorkMailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterFromWorkMailError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::EntityState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterFromWorkMailError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterFromWorkMailError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterFromWorkMailError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DeregisterFromWorkMailError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterFromWorkMailError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2309,16 +2415,25 @@ impl DescribeGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeGroupError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeGroupError::EntityNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeGroupError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DescribeGroupError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeGroupError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2353,14 +2468,16 @@ impl DescribeOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeOrganizationError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeOrganizationError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeOrganizationError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2397,18 +2514,28 @@ impl DescribeResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DescribeResourceError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeResourceError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2447,16 +2574,22 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::EntityNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, DescribeUserError::InvalidParameter(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(DescribeUserError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DescribeUserError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2499,27 +2632,32 @@ impl DisassociateDelegateFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDelegateFromResourceError::EntityNotFound(err.msg),
                     )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDelegateFromResourceError::EntityState(err.msg),
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDelegateFromResourceError::InvalidParameter(err.msg),
                     )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDelegateFromResourceError::OrganizationNotFound(err.msg),
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateDelegateFromResourceError::OrganizationState(err.msg),
                     )
                 }
@@ -2579,44 +2717,52 @@ impl DisassociateMemberFromGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateMemberFromGroupError::DirectoryServiceAuthenticationFailed(
                             err.msg,
                         ),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateMemberFromGroupError::DirectoryUnavailable(err.msg),
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DisassociateMemberFromGroupError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMemberFromGroupError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(DisassociateMemberFromGroupError::EntityState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateMemberFromGroupError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateMemberFromGroupError::InvalidParameter(err.msg),
                     )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateMemberFromGroupError::OrganizationNotFound(err.msg),
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateMemberFromGroupError::OrganizationState(err.msg),
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DisassociateMemberFromGroupError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -2671,24 +2817,28 @@ impl GetAccessControlEffectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAccessControlEffectError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAccessControlEffectError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAccessControlEffectError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(GetAccessControlEffectError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetAccessControlEffectError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2727,24 +2877,28 @@ impl GetDefaultRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDefaultRetentionPolicyError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDefaultRetentionPolicyError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDefaultRetentionPolicyError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDefaultRetentionPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetDefaultRetentionPolicyError::OrganizationNotFound(err.msg),
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(GetDefaultRetentionPolicyError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetDefaultRetentionPolicyError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2783,15 +2937,22 @@ impl GetMailboxDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMailboxDetailsError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMailboxDetailsError::EntityNotFound(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(GetMailboxDetailsError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetMailboxDetailsError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(GetMailboxDetailsError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetMailboxDetailsError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2825,14 +2986,16 @@ impl ListAccessControlRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListAccessControlRulesError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAccessControlRulesError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListAccessControlRulesError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListAccessControlRulesError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2871,19 +3034,22 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::EntityNotFound(err.msg))
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ListAliasesError::EntityState(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::InvalidParameter(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListAliasesError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListAliasesError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, ListAliasesError::OrganizationState(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2925,21 +3091,31 @@ impl ListGroupMembersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListGroupMembersError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembersError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ListGroupMembersError::EntityState(err.msg))
+                    return RusotoError::service(&res, ListGroupMembersError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroupMembersError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembersError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListGroupMembersError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembersError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListGroupMembersError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupMembersError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2979,16 +3155,19 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::EntityNotFound(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::InvalidParameter(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListGroupsError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListGroupsError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, ListGroupsError::OrganizationState(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3027,24 +3206,28 @@ impl ListMailboxPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMailboxPermissionsError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMailboxPermissionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMailboxPermissionsError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListMailboxPermissionsError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListMailboxPermissionsError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3077,7 +3260,10 @@ impl ListOrganizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListOrganizationsError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListOrganizationsError::InvalidParameter(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3115,27 +3301,34 @@ impl ListResourceDelegatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDelegatesError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::EntityState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDelegatesError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDelegatesError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDelegatesError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListResourceDelegatesError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListResourceDelegatesError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3173,13 +3366,22 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListResourcesError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListResourcesError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListResourcesError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3211,9 +3413,10 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ListTagsForResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3247,13 +3450,16 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service(&res, ListUsersError::InvalidParameter(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ListUsersError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ListUsersError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, ListUsersError::OrganizationState(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3293,25 +3499,34 @@ impl PutAccessControlRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutAccessControlRuleError::EntityNotFound(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAccessControlRuleError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutAccessControlRuleError::LimitExceeded(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAccessControlRuleError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(PutAccessControlRuleError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutAccessControlRuleError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3353,27 +3568,34 @@ impl PutMailboxPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutMailboxPermissionsError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::EntityState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutMailboxPermissionsError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutMailboxPermissionsError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutMailboxPermissionsError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(PutMailboxPermissionsError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutMailboxPermissionsError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3413,20 +3635,28 @@ impl PutRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::InvalidParameter(err.msg),
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::LimitExceeded(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        PutRetentionPolicyError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3479,51 +3709,70 @@ impl RegisterToWorkMailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterToWorkMailError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::DirectoryUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EmailAddressInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::EmailAddressInUse(err.msg),
+                    )
                 }
                 "EntityAlreadyRegisteredException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EntityAlreadyRegistered(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::EntityAlreadyRegistered(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::EntityState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::InvalidParameter(err.msg),
+                    )
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::MailDomainNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::MailDomainNotFound(err.msg),
+                    )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::MailDomainState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::MailDomainState(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(RegisterToWorkMailError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterToWorkMailError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3581,33 +3830,49 @@ impl ResetPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ResetPasswordError::DirectoryServiceAuthenticationFailed(err.msg),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(ResetPasswordError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPasswordError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ResetPasswordError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, ResetPasswordError::EntityNotFound(err.msg))
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(ResetPasswordError::EntityState(err.msg))
+                    return RusotoError::service(&res, ResetPasswordError::EntityState(err.msg))
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ResetPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPasswordError::InvalidParameter(err.msg),
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ResetPasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service(&res, ResetPasswordError::InvalidPassword(err.msg))
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(ResetPasswordError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPasswordError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(ResetPasswordError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPasswordError::OrganizationState(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ResetPasswordError::UnsupportedOperation(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ResetPasswordError::UnsupportedOperation(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3651,13 +3916,13 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OrganizationStateException" => {
-                    return RusotoError::Service(TagResourceError::OrganizationState(err.msg))
+                    return RusotoError::service(&res, TagResourceError::OrganizationState(err.msg))
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, TagResourceError::ResourceNotFound(err.msg))
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service(&res, TagResourceError::TooManyTags(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3689,7 +3954,10 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UntagResourceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3727,23 +3995,34 @@ impl UpdateMailboxQuotaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::EntityNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMailboxQuotaError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::EntityState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMailboxQuotaError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::InvalidParameter(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMailboxQuotaError::InvalidParameter(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::OrganizationNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMailboxQuotaError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(UpdateMailboxQuotaError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateMailboxQuotaError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3797,59 +4076,70 @@ impl UpdatePrimaryEmailAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryServiceAuthenticationFailedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePrimaryEmailAddressError::DirectoryServiceAuthenticationFailed(
                             err.msg,
                         ),
                     )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePrimaryEmailAddressError::DirectoryUnavailable(err.msg),
                     )
                 }
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::EmailAddressInUse(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePrimaryEmailAddressError::EmailAddressInUse(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::EntityNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePrimaryEmailAddressError::EntityNotFound(err.msg),
+                    )
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::EntityState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePrimaryEmailAddressError::EntityState(err.msg),
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::InvalidParameter(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePrimaryEmailAddressError::InvalidParameter(err.msg),
+                    )
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePrimaryEmailAddressError::MailDomainNotFound(err.msg),
                     )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::MailDomainState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePrimaryEmailAddressError::MailDomainState(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePrimaryEmailAddressError::OrganizationNotFound(err.msg),
                     )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(UpdatePrimaryEmailAddressError::OrganizationState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdatePrimaryEmailAddressError::OrganizationState(err.msg),
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         UpdatePrimaryEmailAddressError::UnsupportedOperation(err.msg),
                     )
                 }
@@ -3917,34 +4207,58 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(UpdateResourceError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::DirectoryUnavailable(err.msg),
+                    )
                 }
                 "EmailAddressInUseException" => {
-                    return RusotoError::Service(UpdateResourceError::EmailAddressInUse(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::EmailAddressInUse(err.msg),
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::EntityNotFound(err.msg))
                 }
                 "EntityStateException" => {
-                    return RusotoError::Service(UpdateResourceError::EntityState(err.msg))
+                    return RusotoError::service(&res, UpdateResourceError::EntityState(err.msg))
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(UpdateResourceError::InvalidConfiguration(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::InvalidConfiguration(err.msg),
+                    )
                 }
                 "MailDomainNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::MailDomainNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::MailDomainNotFound(err.msg),
+                    )
                 }
                 "MailDomainStateException" => {
-                    return RusotoError::Service(UpdateResourceError::MailDomainState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::MailDomainState(err.msg),
+                    )
                 }
                 "NameAvailabilityException" => {
-                    return RusotoError::Service(UpdateResourceError::NameAvailability(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::NameAvailability(err.msg),
+                    )
                 }
                 "OrganizationNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::OrganizationNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::OrganizationNotFound(err.msg),
+                    )
                 }
                 "OrganizationStateException" => {
-                    return RusotoError::Service(UpdateResourceError::OrganizationState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateResourceError::OrganizationState(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -4253,6 +4567,7 @@ impl WorkmailClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -4268,6 +4583,7 @@ impl WorkmailClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> WorkmailClient {
         WorkmailClient { client, region }
     }
@@ -4282,6 +4598,9 @@ impl Workmail for WorkmailClient {
     ) -> Result<AssociateDelegateToResourceResponse, RusotoError<AssociateDelegateToResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateDelegateToResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkMailService.AssociateDelegateToResource",
@@ -4304,6 +4623,9 @@ impl Workmail for WorkmailClient {
         input: AssociateMemberToGroupRequest,
     ) -> Result<AssociateMemberToGroupResponse, RusotoError<AssociateMemberToGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateMemberToGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.AssociateMemberToGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4323,6 +4645,9 @@ impl Workmail for WorkmailClient {
         input: CreateAliasRequest,
     ) -> Result<CreateAliasResponse, RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4341,6 +4666,9 @@ impl Workmail for WorkmailClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.CreateGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4359,6 +4687,9 @@ impl Workmail for WorkmailClient {
         input: CreateResourceRequest,
     ) -> Result<CreateResourceResponse, RusotoError<CreateResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.CreateResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4377,6 +4708,9 @@ impl Workmail for WorkmailClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4395,6 +4729,9 @@ impl Workmail for WorkmailClient {
         input: DeleteAccessControlRuleRequest,
     ) -> Result<DeleteAccessControlRuleResponse, RusotoError<DeleteAccessControlRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAccessControlRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkMailService.DeleteAccessControlRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4414,6 +4751,9 @@ impl Workmail for WorkmailClient {
         input: DeleteAliasRequest,
     ) -> Result<DeleteAliasResponse, RusotoError<DeleteAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteAlias");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4432,6 +4772,9 @@ impl Workmail for WorkmailClient {
         input: DeleteGroupRequest,
     ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DeleteGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4450,6 +4793,9 @@ impl Workmail for WorkmailClient {
         input: DeleteMailboxPermissionsRequest,
     ) -> Result<DeleteMailboxPermissionsResponse, RusotoError<DeleteMailboxPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteMailboxPermissions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DeleteMailboxPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4469,6 +4815,9 @@ impl Workmail for WorkmailClient {
         input: DeleteResourceRequest,
     ) -> Result<DeleteResourceResponse, RusotoError<DeleteResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DeleteResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4487,6 +4836,9 @@ impl Workmail for WorkmailClient {
         input: DeleteRetentionPolicyRequest,
     ) -> Result<DeleteRetentionPolicyResponse, RusotoError<DeleteRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteRetentionPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DeleteRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4506,6 +4858,9 @@ impl Workmail for WorkmailClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteUser");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4524,6 +4879,9 @@ impl Workmail for WorkmailClient {
         input: DeregisterFromWorkMailRequest,
     ) -> Result<DeregisterFromWorkMailResponse, RusotoError<DeregisterFromWorkMailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterFromWorkMail");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DeregisterFromWorkMail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4543,6 +4901,9 @@ impl Workmail for WorkmailClient {
         input: DescribeGroupRequest,
     ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DescribeGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4561,6 +4922,9 @@ impl Workmail for WorkmailClient {
         input: DescribeOrganizationRequest,
     ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeOrganization");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DescribeOrganization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4580,6 +4944,9 @@ impl Workmail for WorkmailClient {
         input: DescribeResourceRequest,
     ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DescribeResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4598,6 +4965,9 @@ impl Workmail for WorkmailClient {
         input: DescribeUserRequest,
     ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeUser");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.DescribeUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4619,6 +4989,9 @@ impl Workmail for WorkmailClient {
         RusotoError<DisassociateDelegateFromResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateDelegateFromResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkMailService.DisassociateDelegateFromResource",
@@ -4645,6 +5018,9 @@ impl Workmail for WorkmailClient {
     ) -> Result<DisassociateMemberFromGroupResponse, RusotoError<DisassociateMemberFromGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateMemberFromGroup");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkMailService.DisassociateMemberFromGroup",
@@ -4667,6 +5043,9 @@ impl Workmail for WorkmailClient {
         input: GetAccessControlEffectRequest,
     ) -> Result<GetAccessControlEffectResponse, RusotoError<GetAccessControlEffectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetAccessControlEffect");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.GetAccessControlEffect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4687,6 +5066,9 @@ impl Workmail for WorkmailClient {
     ) -> Result<GetDefaultRetentionPolicyResponse, RusotoError<GetDefaultRetentionPolicyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetDefaultRetentionPolicy");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.GetDefaultRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4706,6 +5088,9 @@ impl Workmail for WorkmailClient {
         input: GetMailboxDetailsRequest,
     ) -> Result<GetMailboxDetailsResponse, RusotoError<GetMailboxDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("GetMailboxDetails");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.GetMailboxDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4724,6 +5109,9 @@ impl Workmail for WorkmailClient {
         input: ListAccessControlRulesRequest,
     ) -> Result<ListAccessControlRulesResponse, RusotoError<ListAccessControlRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAccessControlRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListAccessControlRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4743,6 +5131,9 @@ impl Workmail for WorkmailClient {
         input: ListAliasesRequest,
     ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAliases");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4761,6 +5152,9 @@ impl Workmail for WorkmailClient {
         input: ListGroupMembersRequest,
     ) -> Result<ListGroupMembersResponse, RusotoError<ListGroupMembersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGroupMembers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListGroupMembers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4779,6 +5173,9 @@ impl Workmail for WorkmailClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4797,6 +5194,9 @@ impl Workmail for WorkmailClient {
         input: ListMailboxPermissionsRequest,
     ) -> Result<ListMailboxPermissionsResponse, RusotoError<ListMailboxPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListMailboxPermissions");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListMailboxPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4816,6 +5216,9 @@ impl Workmail for WorkmailClient {
         input: ListOrganizationsRequest,
     ) -> Result<ListOrganizationsResponse, RusotoError<ListOrganizationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListOrganizations");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListOrganizations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4834,6 +5237,9 @@ impl Workmail for WorkmailClient {
         input: ListResourceDelegatesRequest,
     ) -> Result<ListResourceDelegatesResponse, RusotoError<ListResourceDelegatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResourceDelegates");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListResourceDelegates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4853,6 +5259,9 @@ impl Workmail for WorkmailClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListResources");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4871,6 +5280,9 @@ impl Workmail for WorkmailClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListTagsForResource");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4889,6 +5301,9 @@ impl Workmail for WorkmailClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListUsers");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ListUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4907,6 +5322,9 @@ impl Workmail for WorkmailClient {
         input: PutAccessControlRuleRequest,
     ) -> Result<PutAccessControlRuleResponse, RusotoError<PutAccessControlRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutAccessControlRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkMailService.PutAccessControlRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4926,6 +5344,9 @@ impl Workmail for WorkmailClient {
         input: PutMailboxPermissionsRequest,
     ) -> Result<PutMailboxPermissionsResponse, RusotoError<PutMailboxPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutMailboxPermissions");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.PutMailboxPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4945,6 +5366,9 @@ impl Workmail for WorkmailClient {
         input: PutRetentionPolicyRequest,
     ) -> Result<PutRetentionPolicyResponse, RusotoError<PutRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("PutRetentionPolicy");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.PutRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4963,6 +5387,9 @@ impl Workmail for WorkmailClient {
         input: RegisterToWorkMailRequest,
     ) -> Result<RegisterToWorkMailResponse, RusotoError<RegisterToWorkMailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterToWorkMail");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.RegisterToWorkMail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4981,6 +5408,9 @@ impl Workmail for WorkmailClient {
         input: ResetPasswordRequest,
     ) -> Result<ResetPasswordResponse, RusotoError<ResetPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ResetPassword");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.ResetPassword");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4999,6 +5429,9 @@ impl Workmail for WorkmailClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkMailService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5017,6 +5450,9 @@ impl Workmail for WorkmailClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UntagResource");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkMailService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5035,6 +5471,9 @@ impl Workmail for WorkmailClient {
         input: UpdateMailboxQuotaRequest,
     ) -> Result<UpdateMailboxQuotaResponse, RusotoError<UpdateMailboxQuotaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateMailboxQuota");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.UpdateMailboxQuota");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5054,6 +5493,9 @@ impl Workmail for WorkmailClient {
     ) -> Result<UpdatePrimaryEmailAddressResponse, RusotoError<UpdatePrimaryEmailAddressError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdatePrimaryEmailAddress");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.UpdatePrimaryEmailAddress");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5073,6 +5515,9 @@ impl Workmail for WorkmailClient {
         input: UpdateResourceRequest,
     ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateResource");
+        request.set_read_only(false);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkMailService.UpdateResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5085,3 +5530,512 @@ impl Workmail for WorkmailClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResourceResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon WorkMail API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `WorkmailClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkmailBlockingClient {
+    client: WorkmailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkmailBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorkmailBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        WorkmailBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkmailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkmailBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkmailBlockingClient {
+        WorkmailBlockingClient {
+            client: WorkmailClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Adds a member (user or group) to the resource's set of delegates.</p>
+    ///
+    /// See `Workmail::associate_delegate_to_resource`.
+    pub fn associate_delegate_to_resource(
+        &self,
+        input: AssociateDelegateToResourceRequest,
+    ) -> Result<AssociateDelegateToResourceResponse, RusotoError<AssociateDelegateToResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_delegate_to_resource(input))
+    }
+
+    /// <p>Adds a member (user or group) to the group's set.</p>
+    ///
+    /// See `Workmail::associate_member_to_group`.
+    pub fn associate_member_to_group(
+        &self,
+        input: AssociateMemberToGroupRequest,
+    ) -> Result<AssociateMemberToGroupResponse, RusotoError<AssociateMemberToGroupError>> {
+        self.runtime
+            .block_on(self.client.associate_member_to_group(input))
+    }
+
+    /// <p>Adds an alias to the set of a given member (user or group) of Amazon WorkMail.</p>
+    ///
+    /// See `Workmail::create_alias`.
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<CreateAliasResponse, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// <p>Creates a group that can be used in Amazon WorkMail by calling the <a>RegisterToWorkMail</a> operation.</p>
+    ///
+    /// See `Workmail::create_group`.
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// <p>Creates a new Amazon WorkMail resource. </p>
+    ///
+    /// See `Workmail::create_resource`.
+    pub fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> Result<CreateResourceResponse, RusotoError<CreateResourceError>> {
+        self.runtime.block_on(self.client.create_resource(input))
+    }
+
+    /// <p>Creates a user who can be used in Amazon WorkMail by calling the <a>RegisterToWorkMail</a> operation.</p>
+    ///
+    /// See `Workmail::create_user`.
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// <p>Deletes an access control rule for the specified WorkMail organization.</p>
+    ///
+    /// See `Workmail::delete_access_control_rule`.
+    pub fn delete_access_control_rule(
+        &self,
+        input: DeleteAccessControlRuleRequest,
+    ) -> Result<DeleteAccessControlRuleResponse, RusotoError<DeleteAccessControlRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_access_control_rule(input))
+    }
+
+    /// <p>Remove one or more specified aliases from a set of aliases for a given user.</p>
+    ///
+    /// See `Workmail::delete_alias`.
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<DeleteAliasResponse, RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// <p>Deletes a group from Amazon WorkMail.</p>
+    ///
+    /// See `Workmail::delete_group`.
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// <p>Deletes permissions granted to a member (user or group).</p>
+    ///
+    /// See `Workmail::delete_mailbox_permissions`.
+    pub fn delete_mailbox_permissions(
+        &self,
+        input: DeleteMailboxPermissionsRequest,
+    ) -> Result<DeleteMailboxPermissionsResponse, RusotoError<DeleteMailboxPermissionsError>> {
+        self.runtime
+            .block_on(self.client.delete_mailbox_permissions(input))
+    }
+
+    /// <p>Deletes the specified resource. </p>
+    ///
+    /// See `Workmail::delete_resource`.
+    pub fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> Result<DeleteResourceResponse, RusotoError<DeleteResourceError>> {
+        self.runtime.block_on(self.client.delete_resource(input))
+    }
+
+    /// <p>Deletes the specified retention policy from the specified organization.</p>
+    ///
+    /// See `Workmail::delete_retention_policy`.
+    pub fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> Result<DeleteRetentionPolicyResponse, RusotoError<DeleteRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_retention_policy(input))
+    }
+
+    /// <p>Deletes a user from Amazon WorkMail and all subsequent systems. Before you can delete a user, the user state must be <code>DISABLED</code>. Use the <a>DescribeUser</a> action to confirm the user state.</p> <p>Deleting a user is permanent and cannot be undone. WorkMail archives user mailboxes for 30 days before they are permanently removed.</p>
+    ///
+    /// See `Workmail::delete_user`.
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// <p>Mark a user, group, or resource as no longer used in Amazon WorkMail. This action disassociates the mailbox and schedules it for clean-up. WorkMail keeps mailboxes for 30 days before they are permanently removed. The functionality in the console is <i>Disable</i>.</p>
+    ///
+    /// See `Workmail::deregister_from_work_mail`.
+    pub fn deregister_from_work_mail(
+        &self,
+        input: DeregisterFromWorkMailRequest,
+    ) -> Result<DeregisterFromWorkMailResponse, RusotoError<DeregisterFromWorkMailError>> {
+        self.runtime
+            .block_on(self.client.deregister_from_work_mail(input))
+    }
+
+    /// <p>Returns the data available for the group.</p>
+    ///
+    /// See `Workmail::describe_group`.
+    pub fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
+        self.runtime.block_on(self.client.describe_group(input))
+    }
+
+    /// <p>Provides more information regarding a given organization based on its identifier.</p>
+    ///
+    /// See `Workmail::describe_organization`.
+    pub fn describe_organization(
+        &self,
+        input: DescribeOrganizationRequest,
+    ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
+        self.runtime
+            .block_on(self.client.describe_organization(input))
+    }
+
+    /// <p>Returns the data available for the resource.</p>
+    ///
+    /// See `Workmail::describe_resource`.
+    pub fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
+        self.runtime.block_on(self.client.describe_resource(input))
+    }
+
+    /// <p>Provides information regarding the user.</p>
+    ///
+    /// See `Workmail::describe_user`.
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// <p>Removes a member from the resource's set of delegates.</p>
+    ///
+    /// See `Workmail::disassociate_delegate_from_resource`.
+    pub fn disassociate_delegate_from_resource(
+        &self,
+        input: DisassociateDelegateFromResourceRequest,
+    ) -> Result<
+        DisassociateDelegateFromResourceResponse,
+        RusotoError<DisassociateDelegateFromResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_delegate_from_resource(input))
+    }
+
+    /// <p>Removes a member from a group.</p>
+    ///
+    /// See `Workmail::disassociate_member_from_group`.
+    pub fn disassociate_member_from_group(
+        &self,
+        input: DisassociateMemberFromGroupRequest,
+    ) -> Result<DisassociateMemberFromGroupResponse, RusotoError<DisassociateMemberFromGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_member_from_group(input))
+    }
+
+    /// <p>Gets the effects of an organization's access control rules as they apply to a specified IPv4 address, access protocol action, or user ID. </p>
+    ///
+    /// See `Workmail::get_access_control_effect`.
+    pub fn get_access_control_effect(
+        &self,
+        input: GetAccessControlEffectRequest,
+    ) -> Result<GetAccessControlEffectResponse, RusotoError<GetAccessControlEffectError>> {
+        self.runtime
+            .block_on(self.client.get_access_control_effect(input))
+    }
+
+    /// <p>Gets the default retention policy details for the specified organization.</p>
+    ///
+    /// See `Workmail::get_default_retention_policy`.
+    pub fn get_default_retention_policy(
+        &self,
+        input: GetDefaultRetentionPolicyRequest,
+    ) -> Result<GetDefaultRetentionPolicyResponse, RusotoError<GetDefaultRetentionPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_default_retention_policy(input))
+    }
+
+    /// <p>Requests a user's mailbox details for a specified organization and user.</p>
+    ///
+    /// See `Workmail::get_mailbox_details`.
+    pub fn get_mailbox_details(
+        &self,
+        input: GetMailboxDetailsRequest,
+    ) -> Result<GetMailboxDetailsResponse, RusotoError<GetMailboxDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_mailbox_details(input))
+    }
+
+    /// <p>Lists the access control rules for the specified organization.</p>
+    ///
+    /// See `Workmail::list_access_control_rules`.
+    pub fn list_access_control_rules(
+        &self,
+        input: ListAccessControlRulesRequest,
+    ) -> Result<ListAccessControlRulesResponse, RusotoError<ListAccessControlRulesError>> {
+        self.runtime
+            .block_on(self.client.list_access_control_rules(input))
+    }
+
+    /// <p>Creates a paginated call to list the aliases associated with a given entity.</p>
+    ///
+    /// See `Workmail::list_aliases`.
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// <p>Returns an overview of the members of a group. Users and groups can be members of a group.</p>
+    ///
+    /// See `Workmail::list_group_members`.
+    pub fn list_group_members(
+        &self,
+        input: ListGroupMembersRequest,
+    ) -> Result<ListGroupMembersResponse, RusotoError<ListGroupMembersError>> {
+        self.runtime.block_on(self.client.list_group_members(input))
+    }
+
+    /// <p>Returns summaries of the organization's groups.</p>
+    ///
+    /// See `Workmail::list_groups`.
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// <p>Lists the mailbox permissions associated with a user, group, or resource mailbox.</p>
+    ///
+    /// See `Workmail::list_mailbox_permissions`.
+    pub fn list_mailbox_permissions(
+        &self,
+        input: ListMailboxPermissionsRequest,
+    ) -> Result<ListMailboxPermissionsResponse, RusotoError<ListMailboxPermissionsError>> {
+        self.runtime
+            .block_on(self.client.list_mailbox_permissions(input))
+    }
+
+    /// <p>Returns summaries of the customer's organizations.</p>
+    ///
+    /// See `Workmail::list_organizations`.
+    pub fn list_organizations(
+        &self,
+        input: ListOrganizationsRequest,
+    ) -> Result<ListOrganizationsResponse, RusotoError<ListOrganizationsError>> {
+        self.runtime.block_on(self.client.list_organizations(input))
+    }
+
+    /// <p>Lists the delegates associated with a resource. Users and groups can be resource delegates and answer requests on behalf of the resource.</p>
+    ///
+    /// See `Workmail::list_resource_delegates`.
+    pub fn list_resource_delegates(
+        &self,
+        input: ListResourceDelegatesRequest,
+    ) -> Result<ListResourceDelegatesResponse, RusotoError<ListResourceDelegatesError>> {
+        self.runtime
+            .block_on(self.client.list_resource_delegates(input))
+    }
+
+    /// <p>Returns summaries of the organization's resources.</p>
+    ///
+    /// See `Workmail::list_resources`.
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// <p>Lists the tags applied to an Amazon WorkMail organization resource.</p>
+    ///
+    /// See `Workmail::list_tags_for_resource`.
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// <p>Returns summaries of the organization's users.</p>
+    ///
+    /// See `Workmail::list_users`.
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// <p>Adds a new access control rule for the specified organization. The rule allows or denies access to the organization for the specified IPv4 addresses, access protocol actions, and user IDs. Adding a new rule with the same name as an existing rule replaces the older rule.</p>
+    ///
+    /// See `Workmail::put_access_control_rule`.
+    pub fn put_access_control_rule(
+        &self,
+        input: PutAccessControlRuleRequest,
+    ) -> Result<PutAccessControlRuleResponse, RusotoError<PutAccessControlRuleError>> {
+        self.runtime
+            .block_on(self.client.put_access_control_rule(input))
+    }
+
+    /// <p>Sets permissions for a user, group, or resource. This replaces any pre-existing permissions.</p>
+    ///
+    /// See `Workmail::put_mailbox_permissions`.
+    pub fn put_mailbox_permissions(
+        &self,
+        input: PutMailboxPermissionsRequest,
+    ) -> Result<PutMailboxPermissionsResponse, RusotoError<PutMailboxPermissionsError>> {
+        self.runtime
+            .block_on(self.client.put_mailbox_permissions(input))
+    }
+
+    /// <p>Puts a retention policy to the specified organization.</p>
+    ///
+    /// See `Workmail::put_retention_policy`.
+    pub fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> Result<PutRetentionPolicyResponse, RusotoError<PutRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_retention_policy(input))
+    }
+
+    /// <p>Registers an existing and disabled user, group, or resource for Amazon WorkMail use by associating a mailbox and calendaring capabilities. It performs no change if the user, group, or resource is enabled and fails if the user, group, or resource is deleted. This operation results in the accumulation of costs. For more information, see <a href="https://aws.amazon.com/workmail/pricing">Pricing</a>. The equivalent console functionality for this operation is <i>Enable</i>. </p> <p>Users can either be created by calling the <a>CreateUser</a> API operation or they can be synchronized from your directory. For more information, see <a>DeregisterFromWorkMail</a>.</p>
+    ///
+    /// See `Workmail::register_to_work_mail`.
+    pub fn register_to_work_mail(
+        &self,
+        input: RegisterToWorkMailRequest,
+    ) -> Result<RegisterToWorkMailResponse, RusotoError<RegisterToWorkMailError>> {
+        self.runtime
+            .block_on(self.client.register_to_work_mail(input))
+    }
+
+    /// <p>Allows the administrator to reset the password for a user.</p>
+    ///
+    /// See `Workmail::reset_password`.
+    pub fn reset_password(
+        &self,
+        input: ResetPasswordRequest,
+    ) -> Result<ResetPasswordResponse, RusotoError<ResetPasswordError>> {
+        self.runtime.block_on(self.client.reset_password(input))
+    }
+
+    /// <p>Applies the specified tags to the specified Amazon WorkMail organization resource.</p>
+    ///
+    /// See `Workmail::tag_resource`.
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// <p>Untags the specified tags from the specified Amazon WorkMail organization resource.</p>
+    ///
+    /// See `Workmail::untag_resource`.
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// <p>Updates a user's current mailbox quota for a specified organization and user.</p>
+    ///
+    /// See `Workmail::update_mailbox_quota`.
+    pub fn update_mailbox_quota(
+        &self,
+        input: UpdateMailboxQuotaRequest,
+    ) -> Result<UpdateMailboxQuotaResponse, RusotoError<UpdateMailboxQuotaError>> {
+        self.runtime
+            .block_on(self.client.update_mailbox_quota(input))
+    }
+
+    /// <p>Updates the primary email for a user, group, or resource. The current email is moved into the list of aliases (or swapped between an existing alias and the current primary email), and the email provided in the input is promoted as the primary.</p>
+    ///
+    /// See `Workmail::update_primary_email_address`.
+    pub fn update_primary_email_address(
+        &self,
+        input: UpdatePrimaryEmailAddressRequest,
+    ) -> Result<UpdatePrimaryEmailAddressResponse, RusotoError<UpdatePrimaryEmailAddressError>>
+    {
+        self.runtime
+            .block_on(self.client.update_primary_email_address(input))
+    }
+
+    /// <p>Updates data for the resource. To have the latest information, it must be preceded by a <a>DescribeResource</a> call. The dataset in the request should be the one expected when performing another <code>DescribeResource</code> call.</p>
+    ///
+    /// See `Workmail::update_resource`.
+    pub fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
+        self.runtime.block_on(self.client.update_resource(input))
+    }
+}
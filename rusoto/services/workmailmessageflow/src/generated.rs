@@ -49,9 +49,10 @@ impl GetRawMessageContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRawMessageContentError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetRawMessageContentError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -96,6 +97,7 @@ impl WorkmailMessageFlowClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -111,6 +113,7 @@ impl WorkmailMessageFlowClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> WorkmailMessageFlowClient {
         WorkmailMessageFlowClient { client, region }
     }
@@ -128,6 +131,9 @@ impl WorkmailMessageFlow for WorkmailMessageFlowClient {
 
         let mut request =
             SignedRequest::new("GET", "workmailmessageflow", &self.region, &request_uri);
+        request.set_operation_name("GetRawMessageContent");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -148,3 +154,71 @@ impl WorkmailMessageFlow for WorkmailMessageFlowClient {
         }
     }
 }
+
+/// A blocking client for the Amazon WorkMail Message Flow API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `WorkmailMessageFlowClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkmailMessageFlowBlockingClient {
+    client: WorkmailMessageFlowClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkmailMessageFlowBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorkmailMessageFlowBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        WorkmailMessageFlowBlockingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        )
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkmailMessageFlowBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkmailMessageFlowBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> WorkmailMessageFlowBlockingClient {
+        WorkmailMessageFlowBlockingClient {
+            client: WorkmailMessageFlowClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Retrieves the raw content of an in-transit email message, in MIME format. </p>
+    ///
+    /// See `WorkmailMessageFlow::get_raw_message_content`.
+    pub fn get_raw_message_content(
+        &self,
+        input: GetRawMessageContentRequest,
+    ) -> Result<GetRawMessageContentResponse, RusotoError<GetRawMessageContentError>> {
+        self.runtime
+            .block_on(self.client.get_raw_message_content(input))
+    }
+}
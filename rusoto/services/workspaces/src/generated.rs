@@ -1552,30 +1552,40 @@ impl AssociateIpGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateIpGroupsError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateIpGroupsError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::InvalidResourceState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateIpGroupsError::InvalidResourceState(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateIpGroupsError::OperationNotSupported(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AssociateIpGroupsError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateIpGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AssociateIpGroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1618,25 +1628,31 @@ impl AuthorizeIpRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, AuthorizeIpRulesError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AuthorizeIpRulesError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::InvalidResourceState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AuthorizeIpRulesError::InvalidResourceState(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        AuthorizeIpRulesError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AuthorizeIpRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        AuthorizeIpRulesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1682,35 +1698,46 @@ impl CopyWorkspaceImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopyWorkspaceImageError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyWorkspaceImageError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyWorkspaceImageError::OperationNotSupported(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyWorkspaceImageError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyWorkspaceImageError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CopyWorkspaceImageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CopyWorkspaceImageError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CopyWorkspaceImageError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1754,23 +1781,31 @@ impl CreateIpGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIpGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, CreateIpGroupError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CreateIpGroupError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIpGroupError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateIpGroupError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIpGroupError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceCreationFailedException" => {
-                    return RusotoError::Service(CreateIpGroupError::ResourceCreationFailed(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateIpGroupError::ResourceCreationFailed(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateIpGroupError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateIpGroupError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1808,13 +1843,19 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameterValues(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagsError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateTagsError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateTagsError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, CreateTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1848,14 +1889,16 @@ impl CreateWorkspacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(CreateWorkspacesError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkspacesError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateWorkspacesError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateWorkspacesError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1892,18 +1935,25 @@ impl DeleteIpGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIpGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DeleteIpGroupError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DeleteIpGroupError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIpGroupError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteIpGroupError::ResourceAssociated(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIpGroupError::ResourceAssociated(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIpGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteIpGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1938,10 +1988,13 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameterValues(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteTagsError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DeleteTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1976,17 +2029,22 @@ impl DeleteWorkspaceImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteWorkspaceImageError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkspaceImageError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(DeleteWorkspaceImageError::InvalidResourceState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkspaceImageError::InvalidResourceState(err.msg),
+                    )
                 }
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteWorkspaceImageError::ResourceAssociated(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeleteWorkspaceImageError::ResourceAssociated(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2028,27 +2086,32 @@ impl DeregisterWorkspaceDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeregisterWorkspaceDirectoryError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DeregisterWorkspaceDirectoryError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterWorkspaceDirectoryError::InvalidParameterValues(err.msg),
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterWorkspaceDirectoryError::InvalidResourceState(err.msg),
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterWorkspaceDirectoryError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DeregisterWorkspaceDirectoryError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2092,7 +2155,7 @@ impl DescribeAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeAccountError::AccessDenied(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2124,9 +2187,10 @@ impl DescribeAccountModificationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountModificationsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeAccountModificationsError::AccessDenied(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2160,19 +2224,22 @@ impl DescribeClientPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeClientPropertiesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClientPropertiesError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeClientPropertiesError::InvalidParameterValues(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeClientPropertiesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeClientPropertiesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2208,12 +2275,13 @@ impl DescribeIpGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIpGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, DescribeIpGroupsError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DescribeIpGroupsError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeIpGroupsError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2244,7 +2312,7 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service(&res, DescribeTagsError::ResourceNotFound(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2274,7 +2342,8 @@ impl DescribeWorkspaceBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkspaceBundlesError::InvalidParameterValues(err.msg),
                     )
                 }
@@ -2310,7 +2379,8 @@ impl DescribeWorkspaceDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkspaceDirectoriesError::InvalidParameterValues(err.msg),
                     )
                 }
@@ -2344,9 +2414,10 @@ impl DescribeWorkspaceImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeWorkspaceImagesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeWorkspaceImagesError::AccessDenied(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2382,19 +2453,22 @@ impl DescribeWorkspaceSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeWorkspaceSnapshotsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeWorkspaceSnapshotsError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkspaceSnapshotsError::InvalidParameterValues(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeWorkspaceSnapshotsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeWorkspaceSnapshotsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2430,14 +2504,16 @@ impl DescribeWorkspacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DescribeWorkspacesError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeWorkspacesError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeWorkspacesError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DescribeWorkspacesError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2470,7 +2546,8 @@ impl DescribeWorkspacesConnectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         DescribeWorkspacesConnectionStatusError::InvalidParameterValues(err.msg),
                     )
                 }
@@ -2510,22 +2587,28 @@ impl DisassociateIpGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateIpGroupsError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateIpGroupsError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::InvalidResourceState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateIpGroupsError::InvalidResourceState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateIpGroupsError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        DisassociateIpGroupsError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2568,32 +2651,40 @@ impl ImportWorkspaceImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ImportWorkspaceImageError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportWorkspaceImageError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportWorkspaceImageError::OperationNotSupported(err.msg),
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::ResourceAlreadyExists(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportWorkspaceImageError::ResourceAlreadyExists(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportWorkspaceImageError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportWorkspaceImageError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ImportWorkspaceImageError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2632,12 +2723,14 @@ impl ListAvailableManagementCidrRangesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAvailableManagementCidrRangesError::AccessDenied(err.msg),
                     )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ListAvailableManagementCidrRangesError::InvalidParameterValues(err.msg),
                     )
                 }
@@ -2684,30 +2777,37 @@ impl MigrateWorkspaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, MigrateWorkspaceError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MigrateWorkspaceError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "OperationInProgressException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::OperationInProgress(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MigrateWorkspaceError::OperationInProgress(err.msg),
+                    )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::OperationNotSupported(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MigrateWorkspaceError::OperationNotSupported(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        MigrateWorkspaceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(MigrateWorkspaceError::ResourceUnavailable(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        MigrateWorkspaceError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2750,21 +2850,31 @@ impl ModifyAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifyAccountError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, ModifyAccountError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(ModifyAccountError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyAccountError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(ModifyAccountError::InvalidResourceState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyAccountError::InvalidResourceState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyAccountError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ModifyAccountError::ResourceUnavailable(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyAccountError::ResourceUnavailable(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2802,17 +2912,22 @@ impl ModifyClientPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifyClientPropertiesError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        ModifyClientPropertiesError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyClientPropertiesError::InvalidParameterValues(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyClientPropertiesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyClientPropertiesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2852,17 +2967,20 @@ impl ModifySelfservicePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifySelfservicePermissionsError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifySelfservicePermissionsError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifySelfservicePermissionsError::InvalidParameterValues(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifySelfservicePermissionsError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2904,12 +3022,14 @@ impl ModifyWorkspaceAccessPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspaceAccessPropertiesError::AccessDenied(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspaceAccessPropertiesError::ResourceNotFound(err.msg),
                     )
                 }
@@ -2950,17 +3070,20 @@ impl ModifyWorkspaceCreationPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspaceCreationPropertiesError::AccessDenied(err.msg),
                     )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspaceCreationPropertiesError::InvalidParameterValues(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspaceCreationPropertiesError::ResourceNotFound(err.msg),
                     )
                 }
@@ -3012,37 +3135,44 @@ impl ModifyWorkspacePropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ModifyWorkspacePropertiesError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyWorkspacePropertiesError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspacePropertiesError::InvalidParameterValues(err.msg),
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspacePropertiesError::InvalidResourceState(err.msg),
                     )
                 }
                 "OperationInProgressException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspacePropertiesError::OperationInProgress(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyWorkspacePropertiesError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyWorkspacePropertiesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspacePropertiesError::ResourceUnavailable(err.msg),
                     )
                 }
                 "UnsupportedWorkspaceConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         ModifyWorkspacePropertiesError::UnsupportedWorkspaceConfiguration(err.msg),
                     )
                 }
@@ -3094,19 +3224,22 @@ impl ModifyWorkspaceStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(ModifyWorkspaceStateError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyWorkspaceStateError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(ModifyWorkspaceStateError::InvalidResourceState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyWorkspaceStateError::InvalidResourceState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyWorkspaceStateError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        ModifyWorkspaceStateError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3198,42 +3331,50 @@ impl RegisterWorkspaceDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterWorkspaceDirectoryError::AccessDenied(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterWorkspaceDirectoryError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWorkspaceDirectoryError::InvalidParameterValues(err.msg),
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWorkspaceDirectoryError::InvalidResourceState(err.msg),
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWorkspaceDirectoryError::OperationNotSupported(err.msg),
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWorkspaceDirectoryError::ResourceLimitExceeded(err.msg),
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterWorkspaceDirectoryError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RegisterWorkspaceDirectoryError::ResourceNotFound(err.msg),
+                    )
                 }
                 "UnsupportedNetworkConfigurationException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWorkspaceDirectoryError::UnsupportedNetworkConfiguration(err.msg),
                     )
                 }
                 "WorkspacesDefaultRoleNotFoundException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         RegisterWorkspaceDirectoryError::WorkspacesDefaultRoleNotFound(err.msg),
                     )
                 }
@@ -3288,15 +3429,19 @@ impl RestoreWorkspaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RestoreWorkspaceError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, RestoreWorkspaceError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(RestoreWorkspaceError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RestoreWorkspaceError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreWorkspaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RestoreWorkspaceError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3334,18 +3479,25 @@ impl RevokeIpRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RevokeIpRulesError::AccessDenied(err.msg))
+                    return RusotoError::service(&res, RevokeIpRulesError::AccessDenied(err.msg))
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(RevokeIpRulesError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        RevokeIpRulesError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(RevokeIpRulesError::InvalidResourceState(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeIpRulesError::InvalidResourceState(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeIpRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        RevokeIpRulesError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3452,27 +3604,34 @@ impl UpdateRulesOfIpGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::AccessDenied(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRulesOfIpGroupError::AccessDenied(err.msg),
+                    )
                 }
                 "InvalidParameterValuesException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::InvalidParameterValues(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRulesOfIpGroupError::InvalidParameterValues(err.msg),
+                    )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::InvalidResourceState(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRulesOfIpGroupError::InvalidResourceState(err.msg),
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::ResourceLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRulesOfIpGroupError::ResourceLimitExceeded(err.msg),
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRulesOfIpGroupError::ResourceNotFound(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        UpdateRulesOfIpGroupError::ResourceNotFound(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -3772,6 +3931,7 @@ impl WorkspacesClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -3787,6 +3947,7 @@ impl WorkspacesClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> WorkspacesClient {
         WorkspacesClient { client, region }
     }
@@ -3800,6 +3961,9 @@ impl Workspaces for WorkspacesClient {
         input: AssociateIpGroupsRequest,
     ) -> Result<AssociateIpGroupsResult, RusotoError<AssociateIpGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AssociateIpGroups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.AssociateIpGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3818,6 +3982,9 @@ impl Workspaces for WorkspacesClient {
         input: AuthorizeIpRulesRequest,
     ) -> Result<AuthorizeIpRulesResult, RusotoError<AuthorizeIpRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("AuthorizeIpRules");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.AuthorizeIpRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3836,6 +4003,9 @@ impl Workspaces for WorkspacesClient {
         input: CopyWorkspaceImageRequest,
     ) -> Result<CopyWorkspaceImageResult, RusotoError<CopyWorkspaceImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CopyWorkspaceImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.CopyWorkspaceImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3854,6 +4024,9 @@ impl Workspaces for WorkspacesClient {
         input: CreateIpGroupRequest,
     ) -> Result<CreateIpGroupResult, RusotoError<CreateIpGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateIpGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.CreateIpGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3872,6 +4045,9 @@ impl Workspaces for WorkspacesClient {
         input: CreateTagsRequest,
     ) -> Result<CreateTagsResult, RusotoError<CreateTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.CreateTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3890,6 +4066,9 @@ impl Workspaces for WorkspacesClient {
         input: CreateWorkspacesRequest,
     ) -> Result<CreateWorkspacesResult, RusotoError<CreateWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("CreateWorkspaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.CreateWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3908,6 +4087,9 @@ impl Workspaces for WorkspacesClient {
         input: DeleteIpGroupRequest,
     ) -> Result<DeleteIpGroupResult, RusotoError<DeleteIpGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteIpGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.DeleteIpGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3926,6 +4108,9 @@ impl Workspaces for WorkspacesClient {
         input: DeleteTagsRequest,
     ) -> Result<DeleteTagsResult, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteTags");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3944,6 +4129,9 @@ impl Workspaces for WorkspacesClient {
         input: DeleteWorkspaceImageRequest,
     ) -> Result<DeleteWorkspaceImageResult, RusotoError<DeleteWorkspaceImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeleteWorkspaceImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.DeleteWorkspaceImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3963,6 +4151,9 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DeregisterWorkspaceDirectoryResult, RusotoError<DeregisterWorkspaceDirectoryError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DeregisterWorkspaceDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DeregisterWorkspaceDirectory",
@@ -3984,6 +4175,9 @@ impl Workspaces for WorkspacesClient {
         &self,
     ) -> Result<DescribeAccountResult, RusotoError<DescribeAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAccount");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkspacesService.DescribeAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -4002,6 +4196,9 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeAccountModificationsResult, RusotoError<DescribeAccountModificationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeAccountModifications");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeAccountModifications",
@@ -4024,6 +4221,9 @@ impl Workspaces for WorkspacesClient {
         input: DescribeClientPropertiesRequest,
     ) -> Result<DescribeClientPropertiesResult, RusotoError<DescribeClientPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeClientProperties");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkspacesService.DescribeClientProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4043,6 +4243,9 @@ impl Workspaces for WorkspacesClient {
         input: DescribeIpGroupsRequest,
     ) -> Result<DescribeIpGroupsResult, RusotoError<DescribeIpGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeIpGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkspacesService.DescribeIpGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4061,6 +4264,9 @@ impl Workspaces for WorkspacesClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeTags");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkspacesService.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4079,6 +4285,9 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspaceBundlesRequest,
     ) -> Result<DescribeWorkspaceBundlesResult, RusotoError<DescribeWorkspaceBundlesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkspaceBundles");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceBundles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4099,6 +4308,9 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeWorkspaceDirectoriesResult, RusotoError<DescribeWorkspaceDirectoriesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkspaceDirectories");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeWorkspaceDirectories",
@@ -4121,6 +4333,9 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspaceImagesRequest,
     ) -> Result<DescribeWorkspaceImagesResult, RusotoError<DescribeWorkspaceImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkspaceImages");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaceImages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4141,6 +4356,9 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<DescribeWorkspaceSnapshotsResult, RusotoError<DescribeWorkspaceSnapshotsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkspaceSnapshots");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeWorkspaceSnapshots",
@@ -4163,6 +4381,9 @@ impl Workspaces for WorkspacesClient {
         input: DescribeWorkspacesRequest,
     ) -> Result<DescribeWorkspacesResult, RusotoError<DescribeWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkspaces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header("x-amz-target", "WorkspacesService.DescribeWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4184,6 +4405,9 @@ impl Workspaces for WorkspacesClient {
         RusotoError<DescribeWorkspacesConnectionStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DescribeWorkspacesConnectionStatus");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.DescribeWorkspacesConnectionStatus",
@@ -4209,6 +4433,9 @@ impl Workspaces for WorkspacesClient {
         input: DisassociateIpGroupsRequest,
     ) -> Result<DisassociateIpGroupsResult, RusotoError<DisassociateIpGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("DisassociateIpGroups");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.DisassociateIpGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4227,6 +4454,9 @@ impl Workspaces for WorkspacesClient {
         input: ImportWorkspaceImageRequest,
     ) -> Result<ImportWorkspaceImageResult, RusotoError<ImportWorkspaceImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ImportWorkspaceImage");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.ImportWorkspaceImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4248,6 +4478,9 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ListAvailableManagementCidrRangesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ListAvailableManagementCidrRanges");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ListAvailableManagementCidrRanges",
@@ -4273,6 +4506,9 @@ impl Workspaces for WorkspacesClient {
         input: MigrateWorkspaceRequest,
     ) -> Result<MigrateWorkspaceResult, RusotoError<MigrateWorkspaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("MigrateWorkspace");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.MigrateWorkspace");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4291,6 +4527,9 @@ impl Workspaces for WorkspacesClient {
         input: ModifyAccountRequest,
     ) -> Result<ModifyAccountResult, RusotoError<ModifyAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyAccount");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.ModifyAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4309,6 +4548,9 @@ impl Workspaces for WorkspacesClient {
         input: ModifyClientPropertiesRequest,
     ) -> Result<ModifyClientPropertiesResult, RusotoError<ModifyClientPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyClientProperties");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.ModifyClientProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4329,6 +4571,9 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<ModifySelfservicePermissionsResult, RusotoError<ModifySelfservicePermissionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifySelfservicePermissions");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifySelfservicePermissions",
@@ -4354,6 +4599,9 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ModifyWorkspaceAccessPropertiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyWorkspaceAccessProperties");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifyWorkspaceAccessProperties",
@@ -4379,6 +4627,9 @@ impl Workspaces for WorkspacesClient {
         RusotoError<ModifyWorkspaceCreationPropertiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyWorkspaceCreationProperties");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifyWorkspaceCreationProperties",
@@ -4404,6 +4655,9 @@ impl Workspaces for WorkspacesClient {
         input: ModifyWorkspacePropertiesRequest,
     ) -> Result<ModifyWorkspacePropertiesResult, RusotoError<ModifyWorkspacePropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyWorkspaceProperties");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.ModifyWorkspaceProperties",
@@ -4426,6 +4680,9 @@ impl Workspaces for WorkspacesClient {
         input: ModifyWorkspaceStateRequest,
     ) -> Result<ModifyWorkspaceStateResult, RusotoError<ModifyWorkspaceStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("ModifyWorkspaceState");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.ModifyWorkspaceState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4444,6 +4701,9 @@ impl Workspaces for WorkspacesClient {
         input: RebootWorkspacesRequest,
     ) -> Result<RebootWorkspacesResult, RusotoError<RebootWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RebootWorkspaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.RebootWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4462,6 +4722,9 @@ impl Workspaces for WorkspacesClient {
         input: RebuildWorkspacesRequest,
     ) -> Result<RebuildWorkspacesResult, RusotoError<RebuildWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RebuildWorkspaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.RebuildWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4481,6 +4744,9 @@ impl Workspaces for WorkspacesClient {
     ) -> Result<RegisterWorkspaceDirectoryResult, RusotoError<RegisterWorkspaceDirectoryError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RegisterWorkspaceDirectory");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header(
             "x-amz-target",
             "WorkspacesService.RegisterWorkspaceDirectory",
@@ -4503,6 +4769,9 @@ impl Workspaces for WorkspacesClient {
         input: RestoreWorkspaceRequest,
     ) -> Result<RestoreWorkspaceResult, RusotoError<RestoreWorkspaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RestoreWorkspace");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.RestoreWorkspace");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4521,6 +4790,9 @@ impl Workspaces for WorkspacesClient {
         input: RevokeIpRulesRequest,
     ) -> Result<RevokeIpRulesResult, RusotoError<RevokeIpRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("RevokeIpRules");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.RevokeIpRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4539,6 +4811,9 @@ impl Workspaces for WorkspacesClient {
         input: StartWorkspacesRequest,
     ) -> Result<StartWorkspacesResult, RusotoError<StartWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StartWorkspaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.StartWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4557,6 +4832,9 @@ impl Workspaces for WorkspacesClient {
         input: StopWorkspacesRequest,
     ) -> Result<StopWorkspacesResult, RusotoError<StopWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("StopWorkspaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.StopWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4575,6 +4853,9 @@ impl Workspaces for WorkspacesClient {
         input: TerminateWorkspacesRequest,
     ) -> Result<TerminateWorkspacesResult, RusotoError<TerminateWorkspacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("TerminateWorkspaces");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.TerminateWorkspaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4593,6 +4874,9 @@ impl Workspaces for WorkspacesClient {
         input: UpdateRulesOfIpGroupRequest,
     ) -> Result<UpdateRulesOfIpGroupResult, RusotoError<UpdateRulesOfIpGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation_name("UpdateRulesOfIpGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.add_header("x-amz-target", "WorkspacesService.UpdateRulesOfIpGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4605,3 +4889,504 @@ impl Workspaces for WorkspacesClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateRulesOfIpGroupResult, _>()
     }
 }
+
+/// A blocking client for the Amazon WorkSpaces API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `WorkspacesClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct WorkspacesBlockingClient {
+    client: WorkspacesClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl WorkspacesBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> WorkspacesBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        WorkspacesBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> WorkspacesBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        WorkspacesBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> WorkspacesBlockingClient {
+        WorkspacesBlockingClient {
+            client: WorkspacesClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Associates the specified IP access control group with the specified directory.</p>
+    ///
+    /// See `Workspaces::associate_ip_groups`.
+    pub fn associate_ip_groups(
+        &self,
+        input: AssociateIpGroupsRequest,
+    ) -> Result<AssociateIpGroupsResult, RusotoError<AssociateIpGroupsError>> {
+        self.runtime
+            .block_on(self.client.associate_ip_groups(input))
+    }
+
+    /// <p>Adds one or more rules to the specified IP access control group.</p> <p>This action gives users permission to access their WorkSpaces from the CIDR address ranges specified in the rules.</p>
+    ///
+    /// See `Workspaces::authorize_ip_rules`.
+    pub fn authorize_ip_rules(
+        &self,
+        input: AuthorizeIpRulesRequest,
+    ) -> Result<AuthorizeIpRulesResult, RusotoError<AuthorizeIpRulesError>> {
+        self.runtime.block_on(self.client.authorize_ip_rules(input))
+    }
+
+    /// <p>Copies the specified image from the specified Region to the current Region.</p>
+    ///
+    /// See `Workspaces::copy_workspace_image`.
+    pub fn copy_workspace_image(
+        &self,
+        input: CopyWorkspaceImageRequest,
+    ) -> Result<CopyWorkspaceImageResult, RusotoError<CopyWorkspaceImageError>> {
+        self.runtime
+            .block_on(self.client.copy_workspace_image(input))
+    }
+
+    /// <p>Creates an IP access control group.</p> <p>An IP access control group provides you with the ability to control the IP addresses from which users are allowed to access their WorkSpaces. To specify the CIDR address ranges, add rules to your IP access control group and then associate the group with your directory. You can add rules when you create the group or at any time using <a>AuthorizeIpRules</a>.</p> <p>There is a default IP access control group associated with your directory. If you don't associate an IP access control group with your directory, the default group is used. The default group includes a default rule that allows users to access their WorkSpaces from anywhere. You cannot modify the default IP access control group for your directory.</p>
+    ///
+    /// See `Workspaces::create_ip_group`.
+    pub fn create_ip_group(
+        &self,
+        input: CreateIpGroupRequest,
+    ) -> Result<CreateIpGroupResult, RusotoError<CreateIpGroupError>> {
+        self.runtime.block_on(self.client.create_ip_group(input))
+    }
+
+    /// <p>Creates the specified tags for the specified WorkSpaces resource.</p>
+    ///
+    /// See `Workspaces::create_tags`.
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<CreateTagsResult, RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// <p>Creates one or more WorkSpaces.</p> <p>This operation is asynchronous and returns before the WorkSpaces are created.</p>
+    ///
+    /// See `Workspaces::create_workspaces`.
+    pub fn create_workspaces(
+        &self,
+        input: CreateWorkspacesRequest,
+    ) -> Result<CreateWorkspacesResult, RusotoError<CreateWorkspacesError>> {
+        self.runtime.block_on(self.client.create_workspaces(input))
+    }
+
+    /// <p>Deletes the specified IP access control group.</p> <p>You cannot delete an IP access control group that is associated with a directory.</p>
+    ///
+    /// See `Workspaces::delete_ip_group`.
+    pub fn delete_ip_group(
+        &self,
+        input: DeleteIpGroupRequest,
+    ) -> Result<DeleteIpGroupResult, RusotoError<DeleteIpGroupError>> {
+        self.runtime.block_on(self.client.delete_ip_group(input))
+    }
+
+    /// <p>Deletes the specified tags from the specified WorkSpaces resource.</p>
+    ///
+    /// See `Workspaces::delete_tags`.
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<DeleteTagsResult, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// <p>Deletes the specified image from your account. To delete an image, you must first delete any bundles that are associated with the image and un-share the image if it is shared with other accounts. </p>
+    ///
+    /// See `Workspaces::delete_workspace_image`.
+    pub fn delete_workspace_image(
+        &self,
+        input: DeleteWorkspaceImageRequest,
+    ) -> Result<DeleteWorkspaceImageResult, RusotoError<DeleteWorkspaceImageError>> {
+        self.runtime
+            .block_on(self.client.delete_workspace_image(input))
+    }
+
+    /// <p>Deregisters the specified directory. This operation is asynchronous and returns before the WorkSpace directory is deregistered. If any WorkSpaces are registered to this directory, you must remove them before you can deregister the directory.</p>
+    ///
+    /// See `Workspaces::deregister_workspace_directory`.
+    pub fn deregister_workspace_directory(
+        &self,
+        input: DeregisterWorkspaceDirectoryRequest,
+    ) -> Result<DeregisterWorkspaceDirectoryResult, RusotoError<DeregisterWorkspaceDirectoryError>>
+    {
+        self.runtime
+            .block_on(self.client.deregister_workspace_directory(input))
+    }
+
+    /// <p>Retrieves a list that describes the configuration of Bring Your Own License (BYOL) for the specified account.</p>
+    ///
+    /// See `Workspaces::describe_account`.
+    pub fn describe_account(
+        &self,
+    ) -> Result<DescribeAccountResult, RusotoError<DescribeAccountError>> {
+        self.runtime.block_on(self.client.describe_account())
+    }
+
+    /// <p>Retrieves a list that describes modifications to the configuration of Bring Your Own License (BYOL) for the specified account.</p>
+    ///
+    /// See `Workspaces::describe_account_modifications`.
+    pub fn describe_account_modifications(
+        &self,
+        input: DescribeAccountModificationsRequest,
+    ) -> Result<DescribeAccountModificationsResult, RusotoError<DescribeAccountModificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_account_modifications(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified Amazon WorkSpaces clients.</p>
+    ///
+    /// See `Workspaces::describe_client_properties`.
+    pub fn describe_client_properties(
+        &self,
+        input: DescribeClientPropertiesRequest,
+    ) -> Result<DescribeClientPropertiesResult, RusotoError<DescribeClientPropertiesError>> {
+        self.runtime
+            .block_on(self.client.describe_client_properties(input))
+    }
+
+    /// <p>Describes one or more of your IP access control groups.</p>
+    ///
+    /// See `Workspaces::describe_ip_groups`.
+    pub fn describe_ip_groups(
+        &self,
+        input: DescribeIpGroupsRequest,
+    ) -> Result<DescribeIpGroupsResult, RusotoError<DescribeIpGroupsError>> {
+        self.runtime.block_on(self.client.describe_ip_groups(input))
+    }
+
+    /// <p>Describes the specified tags for the specified WorkSpaces resource.</p>
+    ///
+    /// See `Workspaces::describe_tags`.
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// <p>Retrieves a list that describes the available WorkSpace bundles.</p> <p>You can filter the results using either bundle ID or owner, but not both.</p>
+    ///
+    /// See `Workspaces::describe_workspace_bundles`.
+    pub fn describe_workspace_bundles(
+        &self,
+        input: DescribeWorkspaceBundlesRequest,
+    ) -> Result<DescribeWorkspaceBundlesResult, RusotoError<DescribeWorkspaceBundlesError>> {
+        self.runtime
+            .block_on(self.client.describe_workspace_bundles(input))
+    }
+
+    /// <p>Describes the available directories that are registered with Amazon WorkSpaces.</p>
+    ///
+    /// See `Workspaces::describe_workspace_directories`.
+    pub fn describe_workspace_directories(
+        &self,
+        input: DescribeWorkspaceDirectoriesRequest,
+    ) -> Result<DescribeWorkspaceDirectoriesResult, RusotoError<DescribeWorkspaceDirectoriesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_workspace_directories(input))
+    }
+
+    /// <p>Retrieves a list that describes one or more specified images, if the image identifiers are provided. Otherwise, all images in the account are described. </p>
+    ///
+    /// See `Workspaces::describe_workspace_images`.
+    pub fn describe_workspace_images(
+        &self,
+        input: DescribeWorkspaceImagesRequest,
+    ) -> Result<DescribeWorkspaceImagesResult, RusotoError<DescribeWorkspaceImagesError>> {
+        self.runtime
+            .block_on(self.client.describe_workspace_images(input))
+    }
+
+    /// <p>Describes the snapshots for the specified WorkSpace.</p>
+    ///
+    /// See `Workspaces::describe_workspace_snapshots`.
+    pub fn describe_workspace_snapshots(
+        &self,
+        input: DescribeWorkspaceSnapshotsRequest,
+    ) -> Result<DescribeWorkspaceSnapshotsResult, RusotoError<DescribeWorkspaceSnapshotsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_workspace_snapshots(input))
+    }
+
+    /// <p>Describes the specified WorkSpaces.</p> <p>You can filter the results by using the bundle identifier, directory identifier, or owner, but you can specify only one filter at a time.</p>
+    ///
+    /// See `Workspaces::describe_workspaces`.
+    pub fn describe_workspaces(
+        &self,
+        input: DescribeWorkspacesRequest,
+    ) -> Result<DescribeWorkspacesResult, RusotoError<DescribeWorkspacesError>> {
+        self.runtime
+            .block_on(self.client.describe_workspaces(input))
+    }
+
+    /// <p>Describes the connection status of the specified WorkSpaces.</p>
+    ///
+    /// See `Workspaces::describe_workspaces_connection_status`.
+    pub fn describe_workspaces_connection_status(
+        &self,
+        input: DescribeWorkspacesConnectionStatusRequest,
+    ) -> Result<
+        DescribeWorkspacesConnectionStatusResult,
+        RusotoError<DescribeWorkspacesConnectionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_workspaces_connection_status(input))
+    }
+
+    /// <p>Disassociates the specified IP access control group from the specified directory.</p>
+    ///
+    /// See `Workspaces::disassociate_ip_groups`.
+    pub fn disassociate_ip_groups(
+        &self,
+        input: DisassociateIpGroupsRequest,
+    ) -> Result<DisassociateIpGroupsResult, RusotoError<DisassociateIpGroupsError>> {
+        self.runtime
+            .block_on(self.client.disassociate_ip_groups(input))
+    }
+
+    /// <p>Imports the specified Windows 7 or Windows 10 Bring Your Own License (BYOL) image into Amazon WorkSpaces. The image must be an already licensed EC2 image that is in your AWS account, and you must own the image. </p>
+    ///
+    /// See `Workspaces::import_workspace_image`.
+    pub fn import_workspace_image(
+        &self,
+        input: ImportWorkspaceImageRequest,
+    ) -> Result<ImportWorkspaceImageResult, RusotoError<ImportWorkspaceImageError>> {
+        self.runtime
+            .block_on(self.client.import_workspace_image(input))
+    }
+
+    /// <p>Retrieves a list of IP address ranges, specified as IPv4 CIDR blocks, that you can use for the network management interface when you enable Bring Your Own License (BYOL). </p> <p>The management network interface is connected to a secure Amazon WorkSpaces management network. It is used for interactive streaming of the WorkSpace desktop to Amazon WorkSpaces clients, and to allow Amazon WorkSpaces to manage the WorkSpace.</p>
+    ///
+    /// See `Workspaces::list_available_management_cidr_ranges`.
+    pub fn list_available_management_cidr_ranges(
+        &self,
+        input: ListAvailableManagementCidrRangesRequest,
+    ) -> Result<
+        ListAvailableManagementCidrRangesResult,
+        RusotoError<ListAvailableManagementCidrRangesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_available_management_cidr_ranges(input))
+    }
+
+    /// <p>Migrates a WorkSpace from one operating system or bundle type to another, while retaining the data on the user volume.</p> <p>The migration process recreates the WorkSpace by using a new root volume from the target bundle image and the user volume from the last available snapshot of the original WorkSpace. During migration, the original <code>D:\Users\%USERNAME%</code> user profile folder is renamed to <code>D:\Users\%USERNAME%MMddyyTHHmmss%.NotMigrated</code>. A new <code>D:\Users\%USERNAME%\</code> folder is generated by the new OS. Certain files in the old user profile are moved to the new user profile.</p> <p>For available migration scenarios, details about what happens during migration, and best practices, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/migrate-workspaces.html">Migrate a WorkSpace</a>.</p>
+    ///
+    /// See `Workspaces::migrate_workspace`.
+    pub fn migrate_workspace(
+        &self,
+        input: MigrateWorkspaceRequest,
+    ) -> Result<MigrateWorkspaceResult, RusotoError<MigrateWorkspaceError>> {
+        self.runtime.block_on(self.client.migrate_workspace(input))
+    }
+
+    /// <p>Modifies the configuration of Bring Your Own License (BYOL) for the specified account.</p>
+    ///
+    /// See `Workspaces::modify_account`.
+    pub fn modify_account(
+        &self,
+        input: ModifyAccountRequest,
+    ) -> Result<ModifyAccountResult, RusotoError<ModifyAccountError>> {
+        self.runtime.block_on(self.client.modify_account(input))
+    }
+
+    /// <p>Modifies the properties of the specified Amazon WorkSpaces clients.</p>
+    ///
+    /// See `Workspaces::modify_client_properties`.
+    pub fn modify_client_properties(
+        &self,
+        input: ModifyClientPropertiesRequest,
+    ) -> Result<ModifyClientPropertiesResult, RusotoError<ModifyClientPropertiesError>> {
+        self.runtime
+            .block_on(self.client.modify_client_properties(input))
+    }
+
+    /// <p>Modifies the self-service WorkSpace management capabilities for your users. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/enable-user-self-service-workspace-management.html">Enable Self-Service WorkSpace Management Capabilities for Your Users</a>.</p>
+    ///
+    /// See `Workspaces::modify_selfservice_permissions`.
+    pub fn modify_selfservice_permissions(
+        &self,
+        input: ModifySelfservicePermissionsRequest,
+    ) -> Result<ModifySelfservicePermissionsResult, RusotoError<ModifySelfservicePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_selfservice_permissions(input))
+    }
+
+    /// <p>Specifies which devices and operating systems users can use to access their WorkSpaces. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/update-directory-details.html#control-device-access"> Control Device Access</a>.</p>
+    ///
+    /// See `Workspaces::modify_workspace_access_properties`.
+    pub fn modify_workspace_access_properties(
+        &self,
+        input: ModifyWorkspaceAccessPropertiesRequest,
+    ) -> Result<
+        ModifyWorkspaceAccessPropertiesResult,
+        RusotoError<ModifyWorkspaceAccessPropertiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_workspace_access_properties(input))
+    }
+
+    /// <p>Modify the default properties used to create WorkSpaces.</p>
+    ///
+    /// See `Workspaces::modify_workspace_creation_properties`.
+    pub fn modify_workspace_creation_properties(
+        &self,
+        input: ModifyWorkspaceCreationPropertiesRequest,
+    ) -> Result<
+        ModifyWorkspaceCreationPropertiesResult,
+        RusotoError<ModifyWorkspaceCreationPropertiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_workspace_creation_properties(input))
+    }
+
+    /// <p>Modifies the specified WorkSpace properties.</p>
+    ///
+    /// See `Workspaces::modify_workspace_properties`.
+    pub fn modify_workspace_properties(
+        &self,
+        input: ModifyWorkspacePropertiesRequest,
+    ) -> Result<ModifyWorkspacePropertiesResult, RusotoError<ModifyWorkspacePropertiesError>> {
+        self.runtime
+            .block_on(self.client.modify_workspace_properties(input))
+    }
+
+    /// <p>Sets the state of the specified WorkSpace.</p> <p>To maintain a WorkSpace without being interrupted, set the WorkSpace state to <code>ADMIN_MAINTENANCE</code>. WorkSpaces in this state do not respond to requests to reboot, stop, start, rebuild, or restore. An AutoStop WorkSpace in this state is not stopped. Users cannot log into a WorkSpace in the <code>ADMIN_MAINTENANCE</code> state.</p>
+    ///
+    /// See `Workspaces::modify_workspace_state`.
+    pub fn modify_workspace_state(
+        &self,
+        input: ModifyWorkspaceStateRequest,
+    ) -> Result<ModifyWorkspaceStateResult, RusotoError<ModifyWorkspaceStateError>> {
+        self.runtime
+            .block_on(self.client.modify_workspace_state(input))
+    }
+
+    /// <p>Reboots the specified WorkSpaces.</p> <p>You cannot reboot a WorkSpace unless its state is <code>AVAILABLE</code> or <code>UNHEALTHY</code>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have rebooted.</p>
+    ///
+    /// See `Workspaces::reboot_workspaces`.
+    pub fn reboot_workspaces(
+        &self,
+        input: RebootWorkspacesRequest,
+    ) -> Result<RebootWorkspacesResult, RusotoError<RebootWorkspacesError>> {
+        self.runtime.block_on(self.client.reboot_workspaces(input))
+    }
+
+    /// <p>Rebuilds the specified WorkSpace.</p> <p>You cannot rebuild a WorkSpace unless its state is <code>AVAILABLE</code>, <code>ERROR</code>, <code>UNHEALTHY</code>, or <code>STOPPED</code>.</p> <p>Rebuilding a WorkSpace is a potentially destructive action that can result in the loss of data. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/reset-workspace.html">Rebuild a WorkSpace</a>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have been completely rebuilt.</p>
+    ///
+    /// See `Workspaces::rebuild_workspaces`.
+    pub fn rebuild_workspaces(
+        &self,
+        input: RebuildWorkspacesRequest,
+    ) -> Result<RebuildWorkspacesResult, RusotoError<RebuildWorkspacesError>> {
+        self.runtime.block_on(self.client.rebuild_workspaces(input))
+    }
+
+    /// <p>Registers the specified directory. This operation is asynchronous and returns before the WorkSpace directory is registered. If this is the first time you are registering a directory, you will need to create the workspaces_DefaultRole role before you can register a directory. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/workspaces-access-control.html#create-default-role"> Creating the workspaces_DefaultRole Role</a>.</p>
+    ///
+    /// See `Workspaces::register_workspace_directory`.
+    pub fn register_workspace_directory(
+        &self,
+        input: RegisterWorkspaceDirectoryRequest,
+    ) -> Result<RegisterWorkspaceDirectoryResult, RusotoError<RegisterWorkspaceDirectoryError>>
+    {
+        self.runtime
+            .block_on(self.client.register_workspace_directory(input))
+    }
+
+    /// <p>Restores the specified WorkSpace to its last known healthy state.</p> <p>You cannot restore a WorkSpace unless its state is <code> AVAILABLE</code>, <code>ERROR</code>, <code>UNHEALTHY</code>, or <code>STOPPED</code>.</p> <p>Restoring a WorkSpace is a potentially destructive action that can result in the loss of data. For more information, see <a href="https://docs.aws.amazon.com/workspaces/latest/adminguide/restore-workspace.html">Restore a WorkSpace</a>.</p> <p>This operation is asynchronous and returns before the WorkSpace is completely restored.</p>
+    ///
+    /// See `Workspaces::restore_workspace`.
+    pub fn restore_workspace(
+        &self,
+        input: RestoreWorkspaceRequest,
+    ) -> Result<RestoreWorkspaceResult, RusotoError<RestoreWorkspaceError>> {
+        self.runtime.block_on(self.client.restore_workspace(input))
+    }
+
+    /// <p>Removes one or more rules from the specified IP access control group.</p>
+    ///
+    /// See `Workspaces::revoke_ip_rules`.
+    pub fn revoke_ip_rules(
+        &self,
+        input: RevokeIpRulesRequest,
+    ) -> Result<RevokeIpRulesResult, RusotoError<RevokeIpRulesError>> {
+        self.runtime.block_on(self.client.revoke_ip_rules(input))
+    }
+
+    /// <p>Starts the specified WorkSpaces.</p> <p>You cannot start a WorkSpace unless it has a running mode of <code>AutoStop</code> and a state of <code>STOPPED</code>.</p>
+    ///
+    /// See `Workspaces::start_workspaces`.
+    pub fn start_workspaces(
+        &self,
+        input: StartWorkspacesRequest,
+    ) -> Result<StartWorkspacesResult, RusotoError<StartWorkspacesError>> {
+        self.runtime.block_on(self.client.start_workspaces(input))
+    }
+
+    /// <p> Stops the specified WorkSpaces.</p> <p>You cannot stop a WorkSpace unless it has a running mode of <code>AutoStop</code> and a state of <code>AVAILABLE</code>, <code>IMPAIRED</code>, <code>UNHEALTHY</code>, or <code>ERROR</code>.</p>
+    ///
+    /// See `Workspaces::stop_workspaces`.
+    pub fn stop_workspaces(
+        &self,
+        input: StopWorkspacesRequest,
+    ) -> Result<StopWorkspacesResult, RusotoError<StopWorkspacesError>> {
+        self.runtime.block_on(self.client.stop_workspaces(input))
+    }
+
+    /// <p>Terminates the specified WorkSpaces.</p> <p>Terminating a WorkSpace is a permanent action and cannot be undone. The user's data is destroyed. If you need to archive any user data, contact Amazon Web Services before terminating the WorkSpace.</p> <p>You can terminate a WorkSpace that is in any state except <code>SUSPENDED</code>.</p> <p>This operation is asynchronous and returns before the WorkSpaces have been completely terminated.</p>
+    ///
+    /// See `Workspaces::terminate_workspaces`.
+    pub fn terminate_workspaces(
+        &self,
+        input: TerminateWorkspacesRequest,
+    ) -> Result<TerminateWorkspacesResult, RusotoError<TerminateWorkspacesError>> {
+        self.runtime
+            .block_on(self.client.terminate_workspaces(input))
+    }
+
+    /// <p>Replaces the current rules of the specified IP access control group with the specified rules.</p>
+    ///
+    /// See `Workspaces::update_rules_of_ip_group`.
+    pub fn update_rules_of_ip_group(
+        &self,
+        input: UpdateRulesOfIpGroupRequest,
+    ) -> Result<UpdateRulesOfIpGroupResult, RusotoError<UpdateRulesOfIpGroupError>> {
+        self.runtime
+            .block_on(self.client.update_rules_of_ip_group(input))
+    }
+}
@@ -1573,10 +1573,10 @@ impl BatchGetTracesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetTracesError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, BatchGetTracesError::InvalidRequest(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(BatchGetTracesError::Throttled(err.msg))
+                    return RusotoError::service(&res, BatchGetTracesError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1609,10 +1609,10 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::InvalidRequest(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(CreateGroupError::Throttled(err.msg))
+                    return RusotoError::service(&res, CreateGroupError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1647,15 +1647,19 @@ impl CreateSamplingRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSamplingRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        CreateSamplingRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "RuleLimitExceededException" => {
-                    return RusotoError::Service(CreateSamplingRuleError::RuleLimitExceeded(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        CreateSamplingRuleError::RuleLimitExceeded(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(CreateSamplingRuleError::Throttled(err.msg))
+                    return RusotoError::service(&res, CreateSamplingRuleError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1689,10 +1693,10 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::InvalidRequest(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(DeleteGroupError::Throttled(err.msg))
+                    return RusotoError::service(&res, DeleteGroupError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1725,10 +1729,13 @@ impl DeleteSamplingRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSamplingRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        DeleteSamplingRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(DeleteSamplingRuleError::Throttled(err.msg))
+                    return RusotoError::service(&res, DeleteSamplingRuleError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1761,10 +1768,13 @@ impl GetEncryptionConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetEncryptionConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetEncryptionConfigError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetEncryptionConfigError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetEncryptionConfigError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1797,10 +1807,10 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetGroupError::InvalidRequest(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetGroupError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetGroupError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1833,10 +1843,10 @@ impl GetGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetGroupsError::InvalidRequest(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetGroupsError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetGroupsError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1869,10 +1879,13 @@ impl GetSamplingRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSamplingRulesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSamplingRulesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetSamplingRulesError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetSamplingRulesError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1907,14 +1920,16 @@ impl GetSamplingStatisticSummariesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetSamplingStatisticSummariesError::InvalidRequest(err.msg),
                     )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetSamplingStatisticSummariesError::Throttled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetSamplingStatisticSummariesError::Throttled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1947,10 +1962,13 @@ impl GetSamplingTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSamplingTargetsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetSamplingTargetsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetSamplingTargetsError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetSamplingTargetsError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -1983,10 +2001,13 @@ impl GetServiceGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetServiceGraphError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetServiceGraphError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetServiceGraphError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetServiceGraphError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2021,14 +2042,16 @@ impl GetTimeSeriesServiceStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
+                    return RusotoError::service(
+                        &res,
                         GetTimeSeriesServiceStatisticsError::InvalidRequest(err.msg),
                     )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTimeSeriesServiceStatisticsError::Throttled(
-                        err.msg,
-                    ))
+                    return RusotoError::service(
+                        &res,
+                        GetTimeSeriesServiceStatisticsError::Throttled(err.msg),
+                    )
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2063,10 +2086,10 @@ impl GetTraceGraphError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTraceGraphError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, GetTraceGraphError::InvalidRequest(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTraceGraphError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetTraceGraphError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2099,10 +2122,13 @@ impl GetTraceSummariesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTraceSummariesError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        GetTraceSummariesError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTraceSummariesError::Throttled(err.msg))
+                    return RusotoError::service(&res, GetTraceSummariesError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2135,10 +2161,13 @@ impl PutEncryptionConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutEncryptionConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutEncryptionConfigError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(PutEncryptionConfigError::Throttled(err.msg))
+                    return RusotoError::service(&res, PutEncryptionConfigError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2171,10 +2200,13 @@ impl PutTelemetryRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutTelemetryRecordsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutTelemetryRecordsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(PutTelemetryRecordsError::Throttled(err.msg))
+                    return RusotoError::service(&res, PutTelemetryRecordsError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2207,10 +2239,13 @@ impl PutTraceSegmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutTraceSegmentsError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        PutTraceSegmentsError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(PutTraceSegmentsError::Throttled(err.msg))
+                    return RusotoError::service(&res, PutTraceSegmentsError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2243,10 +2278,10 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::InvalidRequest(err.msg))
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UpdateGroupError::Throttled(err.msg))
+                    return RusotoError::service(&res, UpdateGroupError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2279,10 +2314,13 @@ impl UpdateSamplingRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSamplingRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service(
+                        &res,
+                        UpdateSamplingRuleError::InvalidRequest(err.msg),
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UpdateSamplingRuleError::Throttled(err.msg))
+                    return RusotoError::service(&res, UpdateSamplingRuleError::Throttled(err.msg))
                 }
                 "ValidationException" => return RusotoError::Validation(err.msg),
                 _ => {}
@@ -2444,6 +2482,7 @@ impl XRayClient {
         }
     }
 
+    /// Creates a client with the given request dispatcher and credentials provider.
     pub fn new_with<P, D>(
         request_dispatcher: D,
         credentials_provider: P,
@@ -2459,6 +2498,7 @@ impl XRayClient {
         }
     }
 
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`.
     pub fn new_with_client(client: Client, region: region::Region) -> XRayClient {
         XRayClient { client, region }
     }
@@ -2475,6 +2515,9 @@ impl XRay for XRayClient {
         let request_uri = "/Traces";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("BatchGetTraces");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2506,6 +2549,9 @@ impl XRay for XRayClient {
         let request_uri = "/CreateGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("CreateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2537,6 +2583,9 @@ impl XRay for XRayClient {
         let request_uri = "/CreateSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("CreateSamplingRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2568,6 +2617,9 @@ impl XRay for XRayClient {
         let request_uri = "/DeleteGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("DeleteGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2599,6 +2651,9 @@ impl XRay for XRayClient {
         let request_uri = "/DeleteSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("DeleteSamplingRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2629,6 +2684,9 @@ impl XRay for XRayClient {
         let request_uri = "/EncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetEncryptionConfig");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2657,6 +2715,9 @@ impl XRay for XRayClient {
         let request_uri = "/GetGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetGroup");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2688,6 +2749,9 @@ impl XRay for XRayClient {
         let request_uri = "/Groups";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetGroups");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2719,6 +2783,9 @@ impl XRay for XRayClient {
         let request_uri = "/GetSamplingRules";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetSamplingRules");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2751,6 +2818,9 @@ impl XRay for XRayClient {
         let request_uri = "/SamplingStatisticSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetSamplingStatisticSummaries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2782,6 +2852,9 @@ impl XRay for XRayClient {
         let request_uri = "/SamplingTargets";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetSamplingTargets");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2813,6 +2886,9 @@ impl XRay for XRayClient {
         let request_uri = "/ServiceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetServiceGraph");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2847,6 +2923,9 @@ impl XRay for XRayClient {
         let request_uri = "/TimeSeriesServiceStatistics";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetTimeSeriesServiceStatistics");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2878,6 +2957,9 @@ impl XRay for XRayClient {
         let request_uri = "/TraceGraph";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetTraceGraph");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2909,6 +2991,9 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSummaries";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("GetTraceSummaries");
+        request.set_read_only(true);
+        request.set_idempotent(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2940,6 +3025,9 @@ impl XRay for XRayClient {
         let request_uri = "/PutEncryptionConfig";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("PutEncryptionConfig");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2971,6 +3059,9 @@ impl XRay for XRayClient {
         let request_uri = "/TelemetryRecords";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("PutTelemetryRecords");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3002,6 +3093,9 @@ impl XRay for XRayClient {
         let request_uri = "/TraceSegments";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("PutTraceSegments");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3033,6 +3127,9 @@ impl XRay for XRayClient {
         let request_uri = "/UpdateGroup";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("UpdateGroup");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3064,6 +3161,9 @@ impl XRay for XRayClient {
         let request_uri = "/UpdateSamplingRule";
 
         let mut request = SignedRequest::new("POST", "xray", &self.region, &request_uri);
+        request.set_operation_name("UpdateSamplingRule");
+        request.set_read_only(false);
+        request.set_idempotent(false);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3086,3 +3186,265 @@ impl XRay for XRayClient {
         }
     }
 }
+
+/// A blocking client for the AWS X-Ray API, for use outside of async code.
+///
+/// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+/// Its methods panic when called from within a tokio runtime, like in async code, as
+/// `BlockingRuntime::block_on` does; use `XRayClient` there instead.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct XRayBlockingClient {
+    client: XRayClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl XRayBlockingClient {
+    /// Creates a client backed by its own runtime.
+    ///
+    /// The client will use the default credentials provider and tls client.
+    pub fn new(region: region::Region) -> XRayBlockingClient {
+        let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+            .expect("failed to create credentials provider");
+        let request_dispatcher =
+            rusoto_core::HttpClient::new().expect("failed to create request dispatcher");
+        XRayBlockingClient::new_with(request_dispatcher, credentials_provider, region)
+    }
+
+    /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> XRayBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        XRayBlockingClient::new_with_client(
+            Client::new_with(credentials_provider, request_dispatcher),
+            region,
+        )
+    }
+
+    /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+    pub fn new_with_client(client: Client, region: region::Region) -> XRayBlockingClient {
+        XRayBlockingClient {
+            client: XRayClient::new_with_client(client, region),
+            runtime: rusoto_core::BlockingRuntime::new(),
+        }
+    }
+
+    /// <p>Retrieves a list of traces specified by ID. Each trace is a collection of segment documents that originates from a single request. Use <code>GetTraceSummaries</code> to get a list of trace IDs.</p>
+    ///
+    /// See `XRay::batch_get_traces`.
+    pub fn batch_get_traces(
+        &self,
+        input: BatchGetTracesRequest,
+    ) -> Result<BatchGetTracesResult, RusotoError<BatchGetTracesError>> {
+        self.runtime.block_on(self.client.batch_get_traces(input))
+    }
+
+    /// <p>Creates a group resource with a name and a filter expression. </p>
+    ///
+    /// See `XRay::create_group`.
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResult, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// <p>Creates a rule to control sampling behavior for instrumented applications. Services retrieve rules with <a>GetSamplingRules</a>, and evaluate each rule in ascending order of <i>priority</i> for each request. If a rule matches, the service records a trace, borrowing it from the reservoir size. After 10 seconds, the service reports back to X-Ray with <a>GetSamplingTargets</a> to get updated versions of each in-use rule. The updated rule contains a trace quota that the service can use instead of borrowing from the reservoir.</p>
+    ///
+    /// See `XRay::create_sampling_rule`.
+    pub fn create_sampling_rule(
+        &self,
+        input: CreateSamplingRuleRequest,
+    ) -> Result<CreateSamplingRuleResult, RusotoError<CreateSamplingRuleError>> {
+        self.runtime
+            .block_on(self.client.create_sampling_rule(input))
+    }
+
+    /// <p>Deletes a group resource.</p>
+    ///
+    /// See `XRay::delete_group`.
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResult, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// <p>Deletes a sampling rule.</p>
+    ///
+    /// See `XRay::delete_sampling_rule`.
+    pub fn delete_sampling_rule(
+        &self,
+        input: DeleteSamplingRuleRequest,
+    ) -> Result<DeleteSamplingRuleResult, RusotoError<DeleteSamplingRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_sampling_rule(input))
+    }
+
+    /// <p>Retrieves the current encryption configuration for X-Ray data.</p>
+    ///
+    /// See `XRay::get_encryption_config`.
+    pub fn get_encryption_config(
+        &self,
+    ) -> Result<GetEncryptionConfigResult, RusotoError<GetEncryptionConfigError>> {
+        self.runtime.block_on(self.client.get_encryption_config())
+    }
+
+    /// <p>Retrieves group resource details.</p>
+    ///
+    /// See `XRay::get_group`.
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResult, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// <p>Retrieves all active group details.</p>
+    ///
+    /// See `XRay::get_groups`.
+    pub fn get_groups(
+        &self,
+        input: GetGroupsRequest,
+    ) -> Result<GetGroupsResult, RusotoError<GetGroupsError>> {
+        self.runtime.block_on(self.client.get_groups(input))
+    }
+
+    /// <p>Retrieves all sampling rules.</p>
+    ///
+    /// See `XRay::get_sampling_rules`.
+    pub fn get_sampling_rules(
+        &self,
+        input: GetSamplingRulesRequest,
+    ) -> Result<GetSamplingRulesResult, RusotoError<GetSamplingRulesError>> {
+        self.runtime.block_on(self.client.get_sampling_rules(input))
+    }
+
+    /// <p>Retrieves information about recent sampling results for all sampling rules.</p>
+    ///
+    /// See `XRay::get_sampling_statistic_summaries`.
+    pub fn get_sampling_statistic_summaries(
+        &self,
+        input: GetSamplingStatisticSummariesRequest,
+    ) -> Result<GetSamplingStatisticSummariesResult, RusotoError<GetSamplingStatisticSummariesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_sampling_statistic_summaries(input))
+    }
+
+    /// <p>Requests a sampling quota for rules that the service is using to sample requests. </p>
+    ///
+    /// See `XRay::get_sampling_targets`.
+    pub fn get_sampling_targets(
+        &self,
+        input: GetSamplingTargetsRequest,
+    ) -> Result<GetSamplingTargetsResult, RusotoError<GetSamplingTargetsError>> {
+        self.runtime
+            .block_on(self.client.get_sampling_targets(input))
+    }
+
+    /// <p>Retrieves a document that describes services that process incoming requests, and downstream services that they call as a result. Root services process incoming requests and make calls to downstream services. Root services are applications that use the <a href="https://docs.aws.amazon.com/xray/index.html">AWS X-Ray SDK</a>. Downstream services can be other applications, AWS resources, HTTP web APIs, or SQL databases.</p>
+    ///
+    /// See `XRay::get_service_graph`.
+    pub fn get_service_graph(
+        &self,
+        input: GetServiceGraphRequest,
+    ) -> Result<GetServiceGraphResult, RusotoError<GetServiceGraphError>> {
+        self.runtime.block_on(self.client.get_service_graph(input))
+    }
+
+    /// <p>Get an aggregation of service statistics defined by a specific time range.</p>
+    ///
+    /// See `XRay::get_time_series_service_statistics`.
+    pub fn get_time_series_service_statistics(
+        &self,
+        input: GetTimeSeriesServiceStatisticsRequest,
+    ) -> Result<
+        GetTimeSeriesServiceStatisticsResult,
+        RusotoError<GetTimeSeriesServiceStatisticsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_time_series_service_statistics(input))
+    }
+
+    /// <p>Retrieves a service graph for one or more specific trace IDs.</p>
+    ///
+    /// See `XRay::get_trace_graph`.
+    pub fn get_trace_graph(
+        &self,
+        input: GetTraceGraphRequest,
+    ) -> Result<GetTraceGraphResult, RusotoError<GetTraceGraphError>> {
+        self.runtime.block_on(self.client.get_trace_graph(input))
+    }
+
+    /// <p>Retrieves IDs and annotations for traces available for a specified time frame using an optional filter. To get the full traces, pass the trace IDs to <code>BatchGetTraces</code>.</p> <p>A filter expression can target traced requests that hit specific service nodes or edges, have errors, or come from a known user. For example, the following filter expression targets traces that pass through <code>api.example.com</code>:</p> <p> <code>service("api.example.com")</code> </p> <p>This filter expression finds traces that have an annotation named <code>account</code> with the value <code>12345</code>:</p> <p> <code>annotation.account = "12345"</code> </p> <p>For a full list of indexed fields and keywords that you can use in filter expressions, see <a href="https://docs.aws.amazon.com/xray/latest/devguide/xray-console-filters.html">Using Filter Expressions</a> in the <i>AWS X-Ray Developer Guide</i>.</p>
+    ///
+    /// See `XRay::get_trace_summaries`.
+    pub fn get_trace_summaries(
+        &self,
+        input: GetTraceSummariesRequest,
+    ) -> Result<GetTraceSummariesResult, RusotoError<GetTraceSummariesError>> {
+        self.runtime
+            .block_on(self.client.get_trace_summaries(input))
+    }
+
+    /// <p>Updates the encryption configuration for X-Ray data.</p>
+    ///
+    /// See `XRay::put_encryption_config`.
+    pub fn put_encryption_config(
+        &self,
+        input: PutEncryptionConfigRequest,
+    ) -> Result<PutEncryptionConfigResult, RusotoError<PutEncryptionConfigError>> {
+        self.runtime
+            .block_on(self.client.put_encryption_config(input))
+    }
+
+    /// <p>Used by the AWS X-Ray daemon to upload telemetry.</p>
+    ///
+    /// See `XRay::put_telemetry_records`.
+    pub fn put_telemetry_records(
+        &self,
+        input: PutTelemetryRecordsRequest,
+    ) -> Result<PutTelemetryRecordsResult, RusotoError<PutTelemetryRecordsError>> {
+        self.runtime
+            .block_on(self.client.put_telemetry_records(input))
+    }
+
+    /// <p><p>Uploads segment documents to AWS X-Ray. The <a href="https://docs.aws.amazon.com/xray/index.html">X-Ray SDK</a> generates segment documents and sends them to the X-Ray daemon, which uploads them in batches. A segment document can be a completed segment, an in-progress segment, or an array of subsegments.</p> <p>Segments must include the following fields. For the full segment document schema, see <a href="https://docs.aws.amazon.com/xray/latest/devguide/xray-api-segmentdocuments.html">AWS X-Ray Segment Documents</a> in the <i>AWS X-Ray Developer Guide</i>.</p> <p class="title"> <b>Required Segment Document Fields</b> </p> <ul> <li> <p> <code>name</code> - The name of the service that handled the request.</p> </li> <li> <p> <code>id</code> - A 64-bit identifier for the segment, unique among segments in the same trace, in 16 hexadecimal digits.</p> </li> <li> <p> <code>trace<em>id</code> - A unique identifier that connects all segments and subsegments originating from a single client request.</p> </li> <li> <p> <code>start</em>time</code> - Time the segment or subsegment was created, in floating point seconds in epoch time, accurate to milliseconds. For example, <code>1480615200.010</code> or <code>1.480615200010E9</code>.</p> </li> <li> <p> <code>end<em>time</code> - Time the segment or subsegment was closed. For example, <code>1480615200.090</code> or <code>1.480615200090E9</code>. Specify either an <code>end</em>time</code> or <code>in<em>progress</code>.</p> </li> <li> <p> <code>in</em>progress</code> - Set to <code>true</code> instead of specifying an <code>end<em>time</code> to record that a segment has been started, but is not complete. Send an in progress segment when your application receives a request that will take a long time to serve, to trace the fact that the request was received. When the response is sent, send the complete segment to overwrite the in-progress segment.</p> </li> </ul> <p>A <code>trace</em>id</code> consists of three numbers separated by hyphens. For example, 1-58406520-a006649127e371903a2de979. This includes:</p> <p class="title"> <b>Trace ID Format</b> </p> <ul> <li> <p>The version number, i.e. <code>1</code>.</p> </li> <li> <p>The time of the original request, in Unix epoch time, in 8 hexadecimal digits. For example, 10:00AM December 2nd, 2016 PST in epoch time is <code>1480615200</code> seconds, or <code>58406520</code> in hexadecimal.</p> </li> <li> <p>A 96-bit identifier for the trace, globally unique, in 24 hexadecimal digits.</p> </li> </ul></p>
+    ///
+    /// See `XRay::put_trace_segments`.
+    pub fn put_trace_segments(
+        &self,
+        input: PutTraceSegmentsRequest,
+    ) -> Result<PutTraceSegmentsResult, RusotoError<PutTraceSegmentsError>> {
+        self.runtime.block_on(self.client.put_trace_segments(input))
+    }
+
+    /// <p>Updates a group resource.</p>
+    ///
+    /// See `XRay::update_group`.
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResult, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// <p>Modifies a sampling rule's configuration.</p>
+    ///
+    /// See `XRay::update_sampling_rule`.
+    pub fn update_sampling_rule(
+        &self,
+        input: UpdateSamplingRuleRequest,
+    ) -> Result<UpdateSamplingRuleResult, RusotoError<UpdateSamplingRuleError>> {
+        self.runtime
+            .block_on(self.client.update_sampling_rule(input))
+    }
+}
@@ -0,0 +1,199 @@
+//! Checksums of payloads, which S3 validates on uploads and returns with downloads.
+//!
+//! See the [documentation](https://docs.aws.amazon.com/AmazonS3/latest/userguide/checking-object-integrity.html)
+//! for more information.
+
+use std::fmt;
+
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// The algorithm of a checksum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32, the fastest algorithm.
+    #[default]
+    Crc32,
+    /// CRC-32C, with the Castagnoli polynomial.
+    Crc32c,
+    /// SHA-1.
+    Sha1,
+    /// SHA-256.
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+    /// All the algorithms, in the order S3 prefers them.
+    pub const ALL: [ChecksumAlgorithm; 4] = [
+        ChecksumAlgorithm::Crc32c,
+        ChecksumAlgorithm::Crc32,
+        ChecksumAlgorithm::Sha1,
+        ChecksumAlgorithm::Sha256,
+    ];
+
+    /// The name of the algorithm, e.g. `CRC32`, as in the `x-amz-sdk-checksum-algorithm` header.
+    pub fn name(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "CRC32",
+            ChecksumAlgorithm::Crc32c => "CRC32C",
+            ChecksumAlgorithm::Sha1 => "SHA1",
+            ChecksumAlgorithm::Sha256 => "SHA256",
+        }
+    }
+
+    /// The header the checksum is sent with, e.g. `x-amz-checksum-crc32`.
+    pub fn header_name(self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "x-amz-checksum-crc32",
+            ChecksumAlgorithm::Crc32c => "x-amz-checksum-crc32c",
+            ChecksumAlgorithm::Sha1 => "x-amz-checksum-sha1",
+            ChecksumAlgorithm::Sha256 => "x-amz-checksum-sha256",
+        }
+    }
+
+    /// Returns the base64 encoded checksum of the data.
+    pub fn checksum(self, data: &[u8]) -> String {
+        let mut checksum = Checksum::new(self);
+        checksum.update(data);
+        checksum.finalize()
+    }
+
+    /// The length of base64 encoded checksums.
+    pub(crate) fn encoded_length(self) -> usize {
+        let length: usize = match self {
+            ChecksumAlgorithm::Crc32 | ChecksumAlgorithm::Crc32c => 4,
+            ChecksumAlgorithm::Sha1 => 20,
+            ChecksumAlgorithm::Sha256 => 32,
+        };
+        length.div_ceil(3) * 4
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A checksum computed incrementally, e.g. over the chunks of a stream.
+pub struct Checksum {
+    algorithm: ChecksumAlgorithm,
+    hasher: Hasher,
+}
+
+enum Hasher {
+    Crc32(crc32fast::Hasher),
+    Crc32c(u32),
+    Sha1(Sha1),
+    Sha256(Sha256),
+}
+
+impl Checksum {
+    /// Create a checksum of no data yet.
+    pub fn new(algorithm: ChecksumAlgorithm) -> Checksum {
+        let hasher = match algorithm {
+            ChecksumAlgorithm::Crc32 => Hasher::Crc32(crc32fast::Hasher::new()),
+            ChecksumAlgorithm::Crc32c => Hasher::Crc32c(0),
+            ChecksumAlgorithm::Sha1 => Hasher::Sha1(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+        };
+        Checksum { algorithm, hasher }
+    }
+
+    /// The algorithm of the checksum.
+    pub fn algorithm(&self) -> ChecksumAlgorithm {
+        self.algorithm
+    }
+
+    /// Adds the data to the checksum.
+    pub fn update(&mut self, data: &[u8]) {
+        match self.hasher {
+            Hasher::Crc32(ref mut hasher) => hasher.update(data),
+            Hasher::Crc32c(ref mut crc) => *crc = crc32c(*crc, data),
+            Hasher::Sha1(ref mut hasher) => hasher.update(data),
+            Hasher::Sha256(ref mut hasher) => hasher.update(data),
+        }
+    }
+
+    /// Returns the base64 encoded checksum.
+    pub fn finalize(self) -> String {
+        match self.hasher {
+            Hasher::Crc32(hasher) => base64::encode(hasher.finalize().to_be_bytes()),
+            Hasher::Crc32c(crc) => base64::encode(crc.to_be_bytes()),
+            Hasher::Sha1(hasher) => base64::encode(hasher.finalize()),
+            Hasher::Sha256(hasher) => base64::encode(hasher.finalize()),
+        }
+    }
+}
+
+impl fmt::Debug for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Checksum")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
+
+/// The lookup table of CRC-32C, with the reversed Castagnoli polynomial.
+const CRC32C_TABLE: [u32; 256] = crc32c_table();
+
+const fn crc32c_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0x82F6_3B78
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+/// Continues the CRC-32C of the data before with the data.
+fn crc32c(crc: u32, data: &[u8]) -> u32 {
+    let crc = data.iter().fold(!crc, |crc, byte| {
+        CRC32C_TABLE[((crc ^ u32::from(*byte)) & 0xff) as usize] ^ (crc >> 8)
+    });
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        let data = b"123456789";
+        let checksum = |algorithm: ChecksumAlgorithm| {
+            hex::encode(base64::decode(algorithm.checksum(data)).unwrap())
+        };
+        assert_eq!(checksum(ChecksumAlgorithm::Crc32), "cbf43926");
+        assert_eq!(checksum(ChecksumAlgorithm::Crc32c), "e3069283");
+        assert_eq!(
+            checksum(ChecksumAlgorithm::Sha1),
+            "f7c3bc1d808e04732adf679965ccc34ca7ae3441"
+        );
+        assert_eq!(
+            checksum(ChecksumAlgorithm::Sha256),
+            "15e2b0d3c33891ebb0f1ef609ec419420c20e320ce94c65fbc8c3312448eb225"
+        );
+
+        // incremental checksums match those of the whole data
+        for algorithm in &ChecksumAlgorithm::ALL {
+            let mut incremental = Checksum::new(*algorithm);
+            incremental.update(&data[..4]);
+            incremental.update(&data[4..]);
+            let incremental = incremental.finalize();
+            assert_eq!(incremental, algorithm.checksum(data));
+            assert_eq!(incremental.len(), algorithm.encoded_length());
+        }
+    }
+}
@@ -0,0 +1,319 @@
+//! Signing streamed payloads chunk by chunk, with the `aws-chunked` content encoding of S3.
+//!
+//! See the [documentation](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-streaming.html)
+//! for more information.
+
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::{Bytes, BytesMut};
+use futures::Stream;
+use hmac::Mac;
+
+use crate::checksum::{Checksum, ChecksumAlgorithm};
+use crate::signature::{hmac, to_hexdigest, EMPTY_SHA256_HASH};
+use crate::stream::ByteStream;
+
+/// The `x-amz-content-sha256` of requests whose payload is signed chunk by chunk.
+pub static STREAMING_PAYLOAD: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD";
+/// The `x-amz-content-sha256` of requests whose payload is signed chunk by chunk, followed by
+/// a signed trailer with its checksum.
+pub static STREAMING_PAYLOAD_TRAILER: &str = "STREAMING-AWS4-HMAC-SHA256-PAYLOAD-TRAILER";
+/// The `x-amz-content-sha256` of requests whose payload is sent in unsigned chunks, followed by
+/// a trailer with its checksum.
+pub static STREAMING_UNSIGNED_PAYLOAD_TRAILER: &str = "STREAMING-UNSIGNED-PAYLOAD-TRAILER";
+
+/// The size of the chunks payloads are signed in, except for the last ones.
+pub(crate) const CHUNK_SIZE: usize = 64 * 1024;
+
+/// The length of a chunk signature.
+const SIGNATURE_LENGTH: usize = 64;
+const CHUNK_SIGNATURE: &str = ";chunk-signature=";
+const TRAILER_SIGNATURE: &str = "x-amz-trailer-signature:";
+
+/// The length of the encoded payload, whose chunks are signed if `signed` is set, followed by
+/// a trailer with a checksum of the algorithm, if any.
+pub(crate) fn encoded_length(
+    decoded_length: usize,
+    chunk_size: usize,
+    signed: bool,
+    trailer: Option<ChecksumAlgorithm>,
+) -> usize {
+    let signature_length = if signed {
+        CHUNK_SIGNATURE.len() + SIGNATURE_LENGTH
+    } else {
+        0
+    };
+    let chunk_length = |size: usize| format!("{:x}", size).len() + signature_length + 2 + size + 2;
+    let full_chunks = decoded_length / chunk_size;
+    let mut length = full_chunks * chunk_length(chunk_size);
+    let last_chunk = decoded_length % chunk_size;
+    if last_chunk > 0 {
+        length += chunk_length(last_chunk);
+    }
+    match trailer {
+        None => length + chunk_length(0),
+        Some(algorithm) => {
+            length += 1 + signature_length + 2;
+            length += algorithm.header_name().len() + 1 + algorithm.encoded_length() + 2;
+            if signed {
+                length += TRAILER_SIGNATURE.len() + SIGNATURE_LENGTH + 2;
+            }
+            length + 2
+        }
+    }
+}
+
+/// Signs the chunks of a payload, each with the signature of the previous one, starting with
+/// the signature of the request.
+pub(crate) struct ChunkSigner {
+    signing_key: Vec<u8>,
+    /// The date of the request, as in its `x-amz-date` header.
+    date: String,
+    scope: String,
+    previous_signature: String,
+}
+
+impl ChunkSigner {
+    pub(crate) fn new(
+        signing_key: Vec<u8>,
+        date: String,
+        scope: String,
+        seed_signature: String,
+    ) -> ChunkSigner {
+        ChunkSigner {
+            signing_key,
+            date,
+            scope,
+            previous_signature: seed_signature,
+        }
+    }
+
+    fn sign(&mut self, algorithm: &str, hash: &str) -> String {
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}\n{}",
+            algorithm, self.date, self.scope, self.previous_signature, hash
+        );
+        let signature = hex::encode(
+            hmac(&self.signing_key, string_to_sign.as_bytes())
+                .finalize()
+                .into_bytes(),
+        );
+        self.previous_signature = signature.clone();
+        signature
+    }
+
+    fn sign_chunk(&mut self, chunk: &[u8]) -> String {
+        let hash = format!("{}\n{}", EMPTY_SHA256_HASH, to_hexdigest(chunk));
+        self.sign("AWS4-HMAC-SHA256-PAYLOAD", &hash)
+    }
+
+    fn sign_trailer(&mut self, trailer: &str) -> String {
+        self.sign("AWS4-HMAC-SHA256-TRAILER", &to_hexdigest(trailer))
+    }
+}
+
+/// A payload encoded with `aws-chunked`, whose chunks are signed unless it's sent unsigned,
+/// optionally followed by a trailer with its checksum.
+pub(crate) struct ChunkedBody {
+    inner: ByteStream,
+    buffer: BytesMut,
+    chunk_size: usize,
+    signer: Option<ChunkSigner>,
+    checksum: Option<Checksum>,
+    finished: bool,
+}
+
+impl ChunkedBody {
+    pub(crate) fn new(
+        inner: ByteStream,
+        chunk_size: usize,
+        signer: Option<ChunkSigner>,
+        trailer: Option<ChecksumAlgorithm>,
+    ) -> ChunkedBody {
+        ChunkedBody {
+            inner,
+            buffer: BytesMut::new(),
+            chunk_size,
+            signer,
+            checksum: trailer.map(Checksum::new),
+            finished: false,
+        }
+    }
+
+    /// Encodes the chunk, with its signature if the payload is signed.
+    fn encode(&mut self, chunk: &[u8]) -> Bytes {
+        if let Some(ref mut checksum) = self.checksum {
+            checksum.update(chunk);
+        }
+        let header = match self.signer {
+            Some(ref mut signer) => format!(
+                "{:x}{}{}\r\n",
+                chunk.len(),
+                CHUNK_SIGNATURE,
+                signer.sign_chunk(chunk)
+            ),
+            None => format!("{:x}\r\n", chunk.len()),
+        };
+        let mut encoded = BytesMut::with_capacity(header.len() + chunk.len() + 2);
+        encoded.extend_from_slice(header.as_bytes());
+        encoded.extend_from_slice(chunk);
+        encoded.extend_from_slice(b"\r\n");
+        encoded.freeze()
+    }
+
+    /// Encodes the empty chunk the payload ends with, followed by the trailer, if any.
+    fn encode_end(&mut self) -> Bytes {
+        let checksum = match self.checksum.take() {
+            Some(checksum) => checksum,
+            None => return self.encode(&[]),
+        };
+        let mut encoded = match self.signer {
+            Some(ref mut signer) => format!("0{}{}\r\n", CHUNK_SIGNATURE, signer.sign_chunk(&[])),
+            None => "0\r\n".to_owned(),
+        };
+        let trailer = format!(
+            "{}:{}",
+            checksum.algorithm().header_name(),
+            checksum.finalize()
+        );
+        encoded.push_str(&trailer);
+        encoded.push_str("\r\n");
+        if let Some(ref mut signer) = self.signer {
+            let signature = signer.sign_trailer(&format!("{}\n", trailer));
+            encoded.push_str(TRAILER_SIGNATURE);
+            encoded.push_str(&signature);
+            encoded.push_str("\r\n");
+        }
+        encoded.push_str("\r\n");
+        Bytes::from(encoded)
+    }
+}
+
+impl Stream for ChunkedBody {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.buffer.len() >= this.chunk_size {
+                let chunk = this.buffer.split_to(this.chunk_size);
+                return Poll::Ready(Some(Ok(this.encode(&chunk))));
+            }
+            if this.finished {
+                return Poll::Ready(None);
+            }
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(bytes))) => this.buffer.extend_from_slice(&bytes),
+                Poll::Ready(Some(Err(err))) => return Poll::Ready(Some(Err(err))),
+                Poll::Ready(None) => {
+                    this.finished = true;
+                    let chunk = this.buffer.split();
+                    let mut encoded = BytesMut::new();
+                    if !chunk.is_empty() {
+                        encoded.extend_from_slice(&this.encode(&chunk));
+                    }
+                    // the payload ends with an empty chunk
+                    encoded.extend_from_slice(&this.encode_end());
+                    return Poll::Ready(Some(Ok(encoded.freeze())));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signature::signing_key;
+    use futures::StreamExt;
+    use time::Date;
+
+    /// The example of the S3 documentation, uploading 66560 bytes in chunks of 64 KiB.
+    #[tokio::test]
+    async fn chunk_signatures() {
+        let date = Date::try_from_ymd(2013, 5, 24).unwrap();
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY",
+            date,
+            "us-east-1",
+            "s3",
+        );
+        let payload = ByteStream::from(vec![b'a'; 66560]);
+        let signer = ChunkSigner::new(
+            key,
+            "20130524T000000Z".to_owned(),
+            "20130524/us-east-1/s3/aws4_request".to_owned(),
+            "4f232c4386841ef735655705268965c44a0e4690baa4adea153f7db9fa80a0a9".to_owned(),
+        );
+        let body = ChunkedBody::new(payload, CHUNK_SIZE, Some(signer), None);
+        let encoded: Vec<Bytes> = body.map(Result::unwrap).collect().await;
+        let encoded = encoded.concat();
+        assert_eq!(encoded.len(), encoded_length(66560, CHUNK_SIZE, true, None));
+
+        let mut headers = Vec::new();
+        let mut rest = &encoded[..];
+        while !rest.is_empty() {
+            let end = rest.windows(2).position(|crlf| crlf == b"\r\n").unwrap();
+            let header = std::str::from_utf8(&rest[..end]).unwrap();
+            let size = usize::from_str_radix(header.split(';').next().unwrap(), 16).unwrap();
+            headers.push(header.to_owned());
+            rest = &rest[end + 2 + size + 2..];
+        }
+        assert_eq!(
+            headers,
+            vec![
+                "10000;chunk-signature=ad80c730a21e5b8d04586a2213dd63b9a0e99e0e2307b0ade35a65485a288648",
+                "400;chunk-signature=0055627c9e194cb4542bae2aa5492e3c1575bbb81b612b7d234b86a503ef5497",
+                "0;chunk-signature=b6c6ea8a5354eaf15b3cb7646744f4275b71ea724fed81ceb9323e279d449df9",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn trailing_checksums() {
+        let payload = ByteStream::from(b"hello world".to_vec());
+        let body = ChunkedBody::new(payload, 4, None, Some(ChecksumAlgorithm::Crc32));
+        let encoded: Vec<Bytes> = body.map(Result::unwrap).collect().await;
+        let encoded = encoded.concat();
+        assert_eq!(
+            std::str::from_utf8(&encoded).unwrap(),
+            "4\r\nhell\r\n4\r\no wo\r\n3\r\nrld\r\n0\r\nx-amz-checksum-crc32:DUoRhQ==\r\n\r\n"
+        );
+        assert_eq!(
+            encoded.len(),
+            encoded_length(11, 4, false, Some(ChecksumAlgorithm::Crc32))
+        );
+
+        let date = Date::try_from_ymd(2013, 5, 24).unwrap();
+        let key = signing_key("secret", date, "us-east-1", "s3");
+        let signer = || {
+            ChunkSigner::new(
+                key.clone(),
+                "20130524T000000Z".to_owned(),
+                "20130524/us-east-1/s3/aws4_request".to_owned(),
+                "seed".to_owned(),
+            )
+        };
+        for algorithm in &ChecksumAlgorithm::ALL {
+            let payload = ByteStream::from(vec![b'a'; 66560]);
+            let body = ChunkedBody::new(payload, CHUNK_SIZE, Some(signer()), Some(*algorithm));
+            let encoded: Vec<Bytes> = body.map(Result::unwrap).collect().await;
+            let encoded = encoded.concat();
+            assert_eq!(
+                encoded.len(),
+                encoded_length(66560, CHUNK_SIZE, true, Some(*algorithm))
+            );
+            let encoded = String::from_utf8(encoded).unwrap();
+            let trailer = format!(
+                "{}:{}\r\nx-amz-trailer-signature:",
+                algorithm.header_name(),
+                algorithm.checksum(&[b'a'; 66560])
+            );
+            assert!(encoded.contains(&trailer));
+            assert!(encoded.ends_with("\r\n\r\n"));
+        }
+    }
+}
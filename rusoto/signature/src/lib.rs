@@ -11,9 +11,14 @@
 #![cfg_attr(not(feature = "unstable"), deny(warnings))]
 #![cfg_attr(not(feature = "unstable"), allow(clippy::type_complexity))]
 pub extern crate rusoto_credential as credential;
+pub mod checksum;
+mod chunked;
+pub mod post_policy;
 pub mod region;
 pub mod signature;
+mod sigv4a;
 pub mod stream;
+pub use checksum::ChecksumAlgorithm;
 pub use region::Region;
-pub use signature::{SignedRequest, SignedRequestPayload};
+pub use signature::{PayloadSigning, SignedRequest, SignedRequestPayload};
 pub use stream::ByteStream;
@@ -0,0 +1,259 @@
+//! Policies for browser uploads to S3 with HTML forms.
+//!
+//! See the [documentation](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html)
+//! for more information.
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use time::OffsetDateTime;
+
+use crate::credential::AwsCredentials;
+use crate::region::Region;
+use crate::signature::{sign_string, SignedRequest};
+
+/// A policy allowing browsers to upload an object to an S3 bucket with an HTML form, without
+/// credentials of their own.
+///
+/// The policy restricts the key of the object, and anything else its conditions constrain, like
+/// the size or content type of the object. Signing it results in the URL the form is posted to
+/// and the fields the form has to include.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use rusoto_signature::credential::AwsCredentials;
+/// use rusoto_signature::post_policy::PostPolicy;
+/// use rusoto_signature::Region;
+///
+/// let mut policy = PostPolicy::new_with_key_prefix("my-bucket", "uploads/");
+/// policy.set_expires_in(Duration::from_secs(600));
+/// policy.set_content_length_range(1, 10 * 1024 * 1024);
+/// policy.add_starts_with("Content-Type", "image/");
+/// policy.add_field("acl", "public-read");
+///
+/// let credentials = AwsCredentials::new("key", "secret", None, None);
+/// let post = policy.sign(&Region::UsEast1, &credentials);
+/// assert_eq!(post.url, "https://s3.us-east-1.amazonaws.com/my-bucket");
+/// // the form includes these fields, followed by the `Content-Type` and the `file` field
+/// assert_eq!(post.fields[0], ("key".to_owned(), "uploads/${filename}".to_owned()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PostPolicy {
+    bucket: String,
+    key: String,
+    key_is_prefix: bool,
+    expires_in: Duration,
+    content_length_range: Option<(u64, u64)>,
+    fields: Vec<(String, String)>,
+    starts_with: Vec<(String, String)>,
+}
+
+/// The URL and fields of an HTML form uploading an object, see `PostPolicy::sign`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PresignedPost {
+    /// The URL the form is posted to.
+    pub url: String,
+    /// The fields the form includes, in order, before the fields restricted with
+    /// `PostPolicy::add_starts_with` and the `file` field with the content of the object.
+    pub fields: Vec<(String, String)>,
+}
+
+impl PostPolicy {
+    /// Create a policy allowing uploads to the key, valid for an hour.
+    pub fn new<B, K>(bucket: B, key: K) -> PostPolicy
+    where
+        B: Into<String>,
+        K: Into<String>,
+    {
+        PostPolicy {
+            bucket: bucket.into(),
+            key: key.into(),
+            key_is_prefix: false,
+            expires_in: Duration::from_secs(60 * 60),
+            content_length_range: None,
+            fields: Vec::new(),
+            starts_with: Vec::new(),
+        }
+    }
+
+    /// Create a policy allowing uploads to keys starting with the prefix, valid for an hour.
+    ///
+    /// The key field of the form is the prefix followed by `${filename}`, which S3 replaces
+    /// with the name of the uploaded file; the browser may change it within the prefix.
+    pub fn new_with_key_prefix<B, P>(bucket: B, prefix: P) -> PostPolicy
+    where
+        B: Into<String>,
+        P: Into<String>,
+    {
+        let mut policy = PostPolicy::new(bucket, prefix);
+        policy.key_is_prefix = true;
+        policy
+    }
+
+    /// Set how long uploads are allowed for.
+    pub fn set_expires_in(&mut self, expires_in: Duration) {
+        self.expires_in = expires_in;
+    }
+
+    /// Get how long uploads are allowed for.
+    pub fn expires_in(&self) -> Duration {
+        self.expires_in
+    }
+
+    /// Set the smallest and the largest size of objects which may be uploaded, in bytes.
+    pub fn set_content_length_range(&mut self, min: u64, max: u64) {
+        self.content_length_range = Some((min, max));
+    }
+
+    /// Add a field the form includes, with the value it must have, like `acl`, `Content-Type`,
+    /// `success_action_status` or `x-amz-meta-*` metadata.
+    pub fn add_field<N, V>(&mut self, name: N, value: V)
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.fields.push((name.into(), value.into()));
+    }
+
+    /// Add a field whose value must start with the prefix, like `Content-Type` with `image/`.
+    /// The browser sets the value of the field; an empty prefix allows any value.
+    pub fn add_starts_with<N, P>(&mut self, name: N, prefix: P)
+    where
+        N: Into<String>,
+        P: Into<String>,
+    {
+        self.starts_with.push((name.into(), prefix.into()));
+    }
+
+    /// Sign the policy for the bucket in the region, returning the URL and fields of the form.
+    ///
+    /// Uploads are allowed for no longer than the credentials are valid, e.g. those of an
+    /// assumed role.
+    pub fn sign(&self, region: &Region, credentials: &AwsCredentials) -> PresignedPost {
+        self.sign_at(region, credentials, OffsetDateTime::now_utc())
+    }
+
+    fn sign_at(
+        &self,
+        region: &Region,
+        credentials: &AwsCredentials,
+        now: OffsetDateTime,
+    ) -> PresignedPost {
+        let request = SignedRequest::new("POST", "s3", region, &format!("/{}", self.bucket));
+        let url = format!(
+            "{}://{}{}",
+            request.scheme(),
+            request.hostname(),
+            request.canonical_path()
+        );
+
+        let date = now.format("%Y%m%dT%H%M%SZ");
+        let credential = format!(
+            "{}/{}/{}/s3/aws4_request",
+            credentials.aws_access_key_id(),
+            now.format("%Y%m%d"),
+            region.name()
+        );
+        let key = if self.key_is_prefix {
+            format!("{}${{filename}}", self.key)
+        } else {
+            self.key.clone()
+        };
+        let mut fields = vec![("key".to_owned(), key)];
+        fields.extend(self.fields.iter().cloned());
+        fields.push(("X-Amz-Algorithm".to_owned(), "AWS4-HMAC-SHA256".to_owned()));
+        fields.push(("X-Amz-Credential".to_owned(), credential));
+        fields.push(("X-Amz-Date".to_owned(), date));
+        if let Some(ref token) = *credentials.token() {
+            fields.push(("X-Amz-Security-Token".to_owned(), token.clone()));
+        }
+
+        let mut conditions = vec![json!({ "bucket": self.bucket })];
+        for (name, value) in &fields {
+            if name == "key" && self.key_is_prefix {
+                conditions.push(json!(["starts-with", "$key", self.key]));
+            } else {
+                conditions.push(json!({ name.as_str(): value }));
+            }
+        }
+        for (name, prefix) in &self.starts_with {
+            conditions.push(json!(["starts-with", format!("${}", name), prefix]));
+        }
+        if let Some((min, max)) = self.content_length_range {
+            conditions.push(json!(["content-length-range", min, max]));
+        }
+        let expiration = now + self.expires_in;
+        let policy = json!({
+            "expiration": expiration.format("%Y-%m-%dT%H:%M:%SZ"),
+            "conditions": Value::Array(conditions),
+        });
+        let policy = base64::encode(policy.to_string());
+
+        let signature = sign_string(
+            &policy,
+            credentials.aws_secret_access_key(),
+            now.date(),
+            region.name(),
+            "s3",
+        );
+        fields.push(("Policy".to_owned(), policy));
+        fields.push(("X-Amz-Signature".to_owned(), signature));
+        PresignedPost { url, fields }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::{Date, Time};
+
+    #[test]
+    fn post_policy() {
+        let mut policy = PostPolicy::new_with_key_prefix("my-bucket", "uploads/");
+        policy.set_expires_in(Duration::from_secs(600));
+        policy.set_content_length_range(1, 1024);
+        policy.add_field("success_action_status", "201");
+        policy.add_starts_with("Content-Type", "image/");
+        let credentials = AwsCredentials::new("key", "secret", Some("token".to_owned()), None);
+        let now = Date::try_from_ymd(2020, 1, 2)
+            .unwrap()
+            .with_time(Time::try_from_hms(3, 4, 5).unwrap())
+            .assume_utc();
+        let post = policy.sign_at(&Region::EuWest1, &credentials, now);
+
+        assert_eq!(post.url, "https://s3.eu-west-1.amazonaws.com/my-bucket");
+        let field = |name: &str| {
+            post.fields
+                .iter()
+                .find(|field| field.0 == name)
+                .map(|field| field.1.clone())
+                .unwrap()
+        };
+        assert_eq!(field("key"), "uploads/${filename}");
+        assert_eq!(field("success_action_status"), "201");
+        assert_eq!(
+            field("X-Amz-Credential"),
+            "key/20200102/eu-west-1/s3/aws4_request"
+        );
+        assert_eq!(field("X-Amz-Date"), "20200102T030405Z");
+        assert_eq!(field("X-Amz-Security-Token"), "token");
+        assert_eq!(
+            field("X-Amz-Signature"),
+            sign_string(&field("Policy"), "secret", now.date(), "eu-west-1", "s3")
+        );
+
+        let policy: Value =
+            serde_json::from_slice(&base64::decode(field("Policy")).unwrap()).unwrap();
+        assert_eq!(policy["expiration"], "2020-01-02T03:14:05Z");
+        let conditions = policy["conditions"].as_array().unwrap();
+        assert!(conditions.contains(&json!({ "bucket": "my-bucket" })));
+        assert!(conditions.contains(&json!(["starts-with", "$key", "uploads/"])));
+        assert!(conditions.contains(&json!({ "success_action_status": "201" })));
+        assert!(conditions.contains(&json!(["starts-with", "$Content-Type", "image/"])));
+        assert!(conditions.contains(&json!(["content-length-range", 1, 1024])));
+        assert!(conditions.contains(&json!({ "X-Amz-Security-Token": "token" })));
+    }
+}
@@ -11,11 +11,13 @@
 
 use std::borrow::Cow;
 use std::collections::btree_map::Entry;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryInto;
 use std::fmt;
+use std::hash::Hash;
 use std::str;
-use std::time::Duration;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
 
 use base64;
 use bytes::Bytes;
@@ -24,14 +26,21 @@ use hmac::{Hmac, Mac, NewMac};
 use http::header::{HeaderMap, HeaderName, HeaderValue};
 use http::{Method, Request};
 use hyper::Body;
+use lazy_static::lazy_static;
 use log::{debug, log_enabled, Level::Debug};
 use md5;
 use percent_encoding::{percent_decode, utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use sha2::{Digest, Sha256};
 use time::{Date, OffsetDateTime};
 
+use crate::checksum::ChecksumAlgorithm;
+use crate::chunked::{
+    self, ChunkSigner, ChunkedBody, STREAMING_PAYLOAD, STREAMING_PAYLOAD_TRAILER,
+    STREAMING_UNSIGNED_PAYLOAD_TRAILER,
+};
 use crate::credential::AwsCredentials;
 use crate::region::Region;
+use crate::sigv4a;
 use crate::stream::ByteStream;
 
 pub type Params = BTreeMap<String, Option<String>>;
@@ -75,6 +84,65 @@ impl fmt::Debug for SignedRequestPayload {
     }
 }
 
+/// How the payload of a request is signed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PayloadSigning {
+    /// Sign buffered payloads with their hash. Streamed payloads can't be hashed without reading
+    /// them, so they're sent unsigned.
+    #[default]
+    Signed,
+    /// Sign streamed payloads of a known size chunk by chunk, with the `aws-chunked` content
+    /// encoding of S3, so they're signed without being buffered. Other payloads are signed as
+    /// with `Signed`.
+    Streaming,
+    /// Sign requests sent over HTTPS with `UNSIGNED-PAYLOAD`, rather than hashing their
+    /// payloads, which saves a pass over large bodies. TLS still protects the payloads from
+    /// being changed on the way; those of requests sent over HTTP are signed as with `Signed`.
+    Unsigned,
+}
+
+/// Options of presigned URLs, see `SignedRequest::generate_presigned_url_with_options`.
+#[derive(Clone, Debug)]
+pub struct PresignOptions {
+    /// How long the URL is valid for, from its start time.
+    pub expires_in: Duration,
+    /// The time the URL is signed at and valid from, the current time if `None`. URLs can be
+    /// signed ahead of the time they're used at, as long as the credentials are still valid.
+    pub start_time: Option<SystemTime>,
+    /// Headers added to the request and signed, which whoever uses the URL has to send with
+    /// the same values, e.g. `x-amz-server-side-encryption` to enforce the encryption of
+    /// uploads.
+    pub headers: Vec<(String, String)>,
+    /// Whether the session token of temporary credentials is signed, `true` by default. Some
+    /// services, like AWS IoT, expect the `X-Amz-Security-Token` parameter to be added to the
+    /// URL after it's signed.
+    pub sign_security_token: bool,
+}
+
+impl Default for PresignOptions {
+    fn default() -> PresignOptions {
+        PresignOptions {
+            expires_in: Duration::from_secs(3600),
+            start_time: None,
+            headers: Vec::new(),
+            sign_security_token: true,
+        }
+    }
+}
+
+/// The canonical request and string to sign of a request, see
+/// `SignedRequest::set_debug_signing`.
+///
+/// When AWS rejects a request with a `SignatureDoesNotMatch` error, comparing them with those
+/// AWS computed, which S3 includes in the error, shows which part of the request differed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningDetails {
+    /// The canonical request, with the value of the `x-amz-security-token` header redacted.
+    pub canonical_request: String,
+    /// The string to sign, including the hash of the canonical request as it was signed.
+    pub string_to_sign: String,
+}
+
 /// A data structure for all the elements of an HTTP request that are involved in
 /// the Amazon Signature Version 4 signing process
 #[derive(Debug)]
@@ -83,6 +151,18 @@ pub struct SignedRequest {
     pub method: String,
     /// The AWS Service
     pub service: String,
+    /// The name of the operation, like `GetObject`, if the request was made by a service client
+    pub operation_name: Option<String>,
+    /// Whether the operation only reads, if known from its model
+    pub read_only: Option<bool>,
+    /// Whether sending the request more than once has the same effect as sending it once, if
+    /// known from the model of its operation and its idempotency token
+    pub idempotent: Option<bool>,
+    /// The name the request is signed for, if it isn't the service
+    pub signing_name: Option<String>,
+    /// The regions the request is signed for with Signature Version 4A, if it's signed for
+    /// a set of regions rather than its region
+    pub region_set: Option<String>,
     /// The AWS Region
     pub region: Region,
     /// The HTTP request path
@@ -95,12 +175,29 @@ pub struct SignedRequest {
     pub scheme: Option<String>,
     /// The AWS hostname
     pub hostname: Option<String>,
+    /// The prefix the hostname was built from, if it was set with `set_endpoint_prefix`
+    pub endpoint_prefix: Option<String>,
     /// The HTTP Content
     pub payload: Option<SignedRequestPayload>,
     /// The Standardised query string
     pub canonical_query_string: String,
     /// The Standardised URI
     pub canonical_uri: String,
+    /// Whether the service accepts a gzip compressed payload
+    pub request_compression: bool,
+    /// Whether the operation can be charged to the requester with the `x-amz-request-payer`
+    /// header
+    pub request_payer: bool,
+    /// Tokens appended to the `User-Agent` header, like `app/my-service`
+    pub user_agent_tokens: Vec<String>,
+    /// How the payload is signed
+    pub payload_signing: PayloadSigning,
+    /// The algorithm of the checksum the payload is sent with, if any
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
+    /// Whether the canonical request and string to sign are kept when the request is signed
+    pub debug_signing: bool,
+    /// The canonical request and string to sign, if `debug_signing` is set
+    pub signing_details: Option<SigningDetails>,
 }
 
 impl SignedRequest {
@@ -109,26 +206,168 @@ impl SignedRequest {
         SignedRequest {
             method: method.to_string(),
             service: service.to_string(),
+            operation_name: None,
+            read_only: None,
+            idempotent: None,
+            signing_name: None,
+            region_set: None,
             region: region.clone(),
             path: path.to_string(),
             headers: BTreeMap::new(),
             params: Params::new(),
             scheme: None,
             hostname: None,
+            endpoint_prefix: None,
             payload: None,
             canonical_query_string: String::new(),
             canonical_uri: String::new(),
+            request_compression: false,
+            request_payer: false,
+            user_agent_tokens: Vec::new(),
+            payload_signing: PayloadSigning::default(),
+            checksum_algorithm: None,
+            debug_signing: false,
+            signing_details: None,
         }
     }
 
+    /// Returns a copy of the request, e.g. to send it again. Requests with a streaming payload
+    /// can only be sent once, so `None` is returned for them.
+    pub fn try_clone(&self) -> Option<SignedRequest> {
+        let payload = match self.payload {
+            None => None,
+            Some(SignedRequestPayload::Buffer(ref bytes)) => {
+                Some(SignedRequestPayload::Buffer(bytes.clone()))
+            }
+            Some(SignedRequestPayload::Stream(_)) => return None,
+        };
+        Some(SignedRequest {
+            method: self.method.clone(),
+            service: self.service.clone(),
+            operation_name: self.operation_name.clone(),
+            read_only: self.read_only,
+            idempotent: self.idempotent,
+            signing_name: self.signing_name.clone(),
+            region_set: self.region_set.clone(),
+            region: self.region.clone(),
+            path: self.path.clone(),
+            headers: self.headers.clone(),
+            params: self.params.clone(),
+            scheme: self.scheme.clone(),
+            hostname: self.hostname.clone(),
+            endpoint_prefix: self.endpoint_prefix.clone(),
+            payload,
+            canonical_query_string: self.canonical_query_string.clone(),
+            canonical_uri: self.canonical_uri.clone(),
+            request_compression: self.request_compression,
+            request_payer: self.request_payer,
+            user_agent_tokens: self.user_agent_tokens.clone(),
+            payload_signing: self.payload_signing,
+            checksum_algorithm: self.checksum_algorithm,
+            debug_signing: self.debug_signing,
+            signing_details: self.signing_details.clone(),
+        })
+    }
+
     /// Sets the value of the "content-type" header.
     pub fn set_content_type(&mut self, content_type: String) {
         self.add_header("content-type", &content_type);
     }
 
+    /// Sets whether the service accepts a gzip compressed payload, so the client may compress
+    /// it before sending the request
+    pub fn set_request_compression(&mut self, request_compression: bool) {
+        self.request_compression = request_compression;
+    }
+
+    /// Sets whether the operation accepts the `x-amz-request-payer` header, so the client may
+    /// add it for requester pays buckets
+    pub fn set_request_payer(&mut self, request_payer: bool) {
+        self.request_payer = request_payer;
+    }
+
+    /// Sets how the payload is signed
+    pub fn set_payload_signing(&mut self, payload_signing: PayloadSigning) {
+        self.payload_signing = payload_signing;
+    }
+
+    /// Sets the algorithm of the checksum the payload is sent with, which S3 validates.
+    /// Buffered payloads are sent with the checksum in a header, and streamed payloads of a
+    /// known size with the checksum in a trailer, encoded with `aws-chunked`
+    pub fn set_checksum_algorithm(&mut self, checksum_algorithm: Option<ChecksumAlgorithm>) {
+        self.checksum_algorithm = checksum_algorithm;
+    }
+
+    /// Sets the name of the operation, which the service clients set for every request, so it
+    /// can be reported and retried as that operation
+    pub fn set_operation_name(&mut self, operation_name: &str) {
+        self.operation_name = Some(operation_name.to_owned());
+    }
+
+    /// Returns the name of the operation, if it was set
+    pub fn operation_name(&self) -> Option<&str> {
+        self.operation_name.as_deref()
+    }
+
+    /// Sets whether the operation only reads, so the request may be hedged. The service
+    /// clients set it from the models of their operations; the requests which don't set it only
+    /// read if they're sent with `GET` or `HEAD`
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = Some(read_only);
+    }
+
+    /// Sets whether sending the request more than once has the same effect as sending it once,
+    /// so it may be sent again when it isn't known whether the service received it. The service
+    /// clients set it from the models of their operations; the requests which don't set it are
+    /// idempotent if they're sent with `GET`, `HEAD`, `PUT`, `DELETE` or `OPTIONS`
+    pub fn set_idempotent(&mut self, idempotent: bool) {
+        self.idempotent = Some(idempotent);
+    }
+
+    /// Sets the name the request is signed for, like `s3-outposts` for requests to S3 on
+    /// Outposts, rather than the name of the service
+    pub fn set_signing_name(&mut self, signing_name: Option<String>) {
+        self.signing_name = signing_name;
+    }
+
+    /// Returns the name the request is signed for
+    pub fn signing_name(&self) -> &str {
+        self.signing_name.as_ref().unwrap_or(&self.service)
+    }
+
+    /// Sets the regions the request is signed for, like `*` for requests to S3 Multi-Region
+    /// Access Points, which are signed with Signature Version 4A rather than for the region of
+    /// the request. The chunks of streamed payloads of these requests aren't signed
+    pub fn set_region_set(&mut self, region_set: Option<String>) {
+        self.region_set = region_set;
+    }
+
+    /// Sets whether the canonical request and string to sign are kept when the request is
+    /// signed, to debug signatures AWS rejects. Secrets aren't kept
+    pub fn set_debug_signing(&mut self, debug_signing: bool) {
+        self.debug_signing = debug_signing;
+    }
+
+    /// Returns the canonical request and string to sign of the request, if it was signed with
+    /// `debug_signing` set
+    pub fn signing_details(&self) -> Option<&SigningDetails> {
+        self.signing_details.as_ref()
+    }
+
+    /// Appends a token to the `User-Agent` header the request is sent with, which isn't signed.
+    /// Whitespace and other characters which can't be part of a token are replaced with dashes
+    pub fn add_user_agent_token(&mut self, token: &str) {
+        let token = token
+            .chars()
+            .map(|c| if c.is_ascii_graphic() { c } else { '-' })
+            .collect();
+        self.user_agent_tokens.push(token);
+    }
+
     /// Sets the target hostname
     pub fn set_hostname(&mut self, hostname: Option<String>) {
         self.hostname = hostname;
+        self.endpoint_prefix = None;
     }
 
     /// Sets the target hostname using the current service type and region
@@ -136,6 +375,18 @@ impl SignedRequest {
     /// See the implementation of build_hostname to see how this is done
     pub fn set_endpoint_prefix(&mut self, endpoint_prefix: String) {
         self.hostname = Some(build_hostname(&endpoint_prefix, &self.region));
+        self.endpoint_prefix = Some(endpoint_prefix);
+    }
+
+    /// Sets the region the request is sent to and signed for
+    ///
+    /// A hostname built from an endpoint prefix is rebuilt for the new region, while one set
+    /// with `set_hostname` is kept.
+    pub fn set_region(&mut self, region: Region) {
+        self.region = region;
+        if let Some(ref endpoint_prefix) = self.endpoint_prefix {
+            self.hostname = Some(build_hostname(endpoint_prefix, &self.region));
+        }
     }
 
     /// Sets the new body (payload)
@@ -219,8 +470,12 @@ impl SignedRequest {
             "organizations" => {
                 // Matches https://docs.aws.amazon.com/general/latest/gr/ao.html
                 match self.region {
-                    Region::CnNorth1 | Region::CnNorthwest1 => Region::CnNorthwest1.name().to_string(),
-                    Region::UsGovEast1 | Region::UsGovWest1 => Region::UsGovWest1.name().to_string(),
+                    Region::CnNorth1 | Region::CnNorthwest1 => {
+                        Region::CnNorthwest1.name().to_string()
+                    }
+                    Region::UsGovEast1 | Region::UsGovWest1 => {
+                        Region::UsGovWest1.name().to_string()
+                    }
                     _ => Region::UsEast1.name().to_string(),
                 }
             }
@@ -282,13 +537,35 @@ impl SignedRequest {
         creds: &AwsCredentials,
         expires_in: &Duration,
         should_sha256_sign_payload: bool,
+    ) -> String {
+        let options = PresignOptions {
+            expires_in: *expires_in,
+            ..PresignOptions::default()
+        };
+        self.generate_presigned_url_with_options(creds, &options, should_sha256_sign_payload)
+    }
+
+    /// Generate a Presigned URL for AWS, with additional signed headers, a start time or an
+    /// unsigned session token, see `PresignOptions`
+    pub fn generate_presigned_url_with_options(
+        &mut self,
+        creds: &AwsCredentials,
+        options: &PresignOptions,
+        should_sha256_sign_payload: bool,
     ) -> String {
         debug!("Presigning request URL");
 
+        for (name, value) in &options.headers {
+            self.remove_header(name);
+            self.add_header(name, value);
+        }
         self.sign(creds);
         let hostname = self.hostname();
 
-        let current_time = OffsetDateTime::now_utc();
+        let current_time = options
+            .start_time
+            .map(OffsetDateTime::from)
+            .unwrap_or_else(OffsetDateTime::now_utc);
         let current_time_fmted = current_time.format("%Y%m%dT%H%M%SZ");
         let current_date = current_time.format("%Y%m%d");
 
@@ -298,31 +575,44 @@ impl SignedRequest {
 
         self.remove_header("Content-Type");
 
+        self.remove_header("X-Amz-Security-Token");
         if let Some(ref token) = *creds.token() {
-            self.remove_header("X-Amz-Security-Token");
-            self.params
-                .insert("X-Amz-Security-Token".into(), Some(token.to_string()));
+            if options.sign_security_token {
+                self.params
+                    .insert("X-Amz-Security-Token".into(), Some(token.to_string()));
+            }
         }
 
+        let algorithm = match self.region_set {
+            Some(_) => sigv4a::ALGORITHM,
+            None => "AWS4-HMAC-SHA256",
+        };
         self.remove_header("X-Amz-Algorithm");
         self.params
-            .insert("X-Amz-Algorithm".into(), Some("AWS4-HMAC-SHA256".into()));
-
+            .insert("X-Amz-Algorithm".into(), Some(algorithm.into()));
+
+        let scope = match self.region_set.clone() {
+            Some(region_set) => {
+                self.remove_header("X-Amz-Region-Set");
+                self.params
+                    .insert("X-Amz-Region-Set".into(), Some(region_set));
+                format!("{}/{}/aws4_request", current_date, self.signing_name())
+            }
+            None => format!(
+                "{}/{}/{}/aws4_request",
+                current_date,
+                self.region.name(),
+                self.signing_name()
+            ),
+        };
         self.remove_header("X-Amz-Credential");
         self.params.insert(
             "X-Amz-Credential".into(),
-            format!(
-                "{}/{}/{}/{}/aws4_request",
-                &creds.aws_access_key_id(),
-                &current_date,
-                self.region.name(),
-                self.service
-            )
-            .into(),
+            format!("{}/{}", &creds.aws_access_key_id(), scope).into(),
         );
 
         self.remove_header("X-Amz-Expires");
-        let expiration_time = format!("{}", expires_in.as_secs());
+        let expiration_time = format!("{}", options.expires_in.as_secs());
         self.params
             .insert("X-Amz-Expires".into(), expiration_time.into());
 
@@ -373,28 +663,40 @@ impl SignedRequest {
 
         debug!("hashed_canonical_request: {:?}", hashed_canonical_request);
 
-        let scope = format!(
-            "{}/{}/{}/aws4_request",
-            current_date,
-            self.region.name(),
-            &self.service
-        );
-
         debug!("scope: {}", scope);
 
-        let string_to_sign = string_to_sign(current_time, &hashed_canonical_request, &scope);
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            algorithm,
+            current_time.format("%Y%m%dT%H%M%SZ"),
+            scope,
+            hashed_canonical_request
+        );
 
         debug!("string_to_sign: {}", string_to_sign);
 
-        let signature = sign_string(
-            &string_to_sign,
-            creds.aws_secret_access_key(),
-            current_time.date(),
-            &self.region.name(),
-            &self.service,
-        );
+        let signature = match self.region_set {
+            Some(_) => sigv4a::sign(
+                creds.aws_access_key_id(),
+                creds.aws_secret_access_key(),
+                &string_to_sign,
+            ),
+            None => sign_string(
+                &string_to_sign,
+                creds.aws_secret_access_key(),
+                current_time.date(),
+                &self.region.name(),
+                self.signing_name(),
+            ),
+        };
         self.params
             .insert("X-Amz-Signature".into(), signature.into());
+        if let Some(ref token) = *creds.token() {
+            if !options.sign_security_token {
+                self.params
+                    .insert("X-Amz-Security-Token".into(), Some(token.to_string()));
+            }
+        }
 
         format!(
             "{}://{}{}?{}",
@@ -432,6 +734,16 @@ impl SignedRequest {
             self.remove_header("content-length");
             self.add_header("content-length", &format!("{}", len));
         }
+        let checksum = match (self.checksum_algorithm, &self.payload) {
+            (Some(algorithm), Some(SignedRequestPayload::Buffer(ref payload))) => {
+                Some((algorithm, algorithm.checksum(payload)))
+            }
+            _ => None,
+        };
+        if let Some((algorithm, checksum)) = checksum {
+            self.remove_header(algorithm.header_name());
+            self.add_header(algorithm.header_name(), &checksum);
+        }
     }
 
     /// Signs the request using Amazon Signature version 4 to verify identity.
@@ -449,14 +761,45 @@ impl SignedRequest {
 
         let digest = match self.payload {
             None => Cow::Borrowed(EMPTY_SHA256_HASH),
+            Some(SignedRequestPayload::Buffer(_))
+                if self.payload_signing == PayloadSigning::Unsigned && self.scheme() == "https" =>
+            {
+                Cow::Borrowed(UNSIGNED_PAYLOAD)
+            }
             Some(SignedRequestPayload::Buffer(ref payload)) => {
                 let (digest, _) = digest_payload(&payload);
                 Cow::Owned(digest)
             }
-            Some(SignedRequestPayload::Stream(_)) => Cow::Borrowed(UNSIGNED_PAYLOAD),
+            Some(SignedRequestPayload::Stream(ref stream)) => {
+                // the chunks of requests signed with Signature Version 4A aren't signed
+                let streaming =
+                    self.payload_signing == PayloadSigning::Streaming && self.region_set.is_none();
+                match (streaming, stream.size_hint(), self.checksum_algorithm) {
+                    (true, Some(_), None) => Cow::Borrowed(STREAMING_PAYLOAD),
+                    (true, Some(_), Some(_)) => Cow::Borrowed(STREAMING_PAYLOAD_TRAILER),
+                    (false, Some(_), Some(_)) => Cow::Borrowed(STREAMING_UNSIGNED_PAYLOAD_TRAILER),
+                    _ => Cow::Borrowed(UNSIGNED_PAYLOAD),
+                }
+            }
+        };
+        // the size of a payload encoded with `aws-chunked`, and whether its chunks are signed
+        let chunked = match self.payload {
+            Some(SignedRequestPayload::Stream(ref stream)) if digest.starts_with("STREAMING-") => {
+                stream
+                    .size_hint()
+                    .map(|size| (size, digest != STREAMING_UNSIGNED_PAYLOAD_TRAILER))
+            }
+            _ => None,
         };
+        if let Some((size, signed)) = chunked {
+            self.encode_chunked(size, signed);
+        }
         self.remove_header("x-amz-content-sha256");
         self.add_header("x-amz-content-sha256", &digest);
+        if let Some(region_set) = self.region_set.clone() {
+            self.remove_header("x-amz-region-set");
+            self.add_header("x-amz-region-set", &region_set);
+        }
 
         let signed_headers = signed_headers(&self.headers);
 
@@ -482,26 +825,64 @@ impl SignedRequest {
 
         // use the hashed canonical request to build the string to sign
         let hashed_canonical_request = to_hexdigest(&canonical_request);
-        let scope = format!(
-            "{}/{}/{}/aws4_request",
-            date.format("%Y%m%d"),
-            &self.region_for_service(),
-            &self.service
+        let (algorithm, scope) = match self.region_set {
+            // requests signed for a set of regions aren't scoped to a region
+            Some(_) => (
+                sigv4a::ALGORITHM,
+                format!(
+                    "{}/{}/aws4_request",
+                    date.format("%Y%m%d"),
+                    self.signing_name()
+                ),
+            ),
+            None => (
+                "AWS4-HMAC-SHA256",
+                format!(
+                    "{}/{}/{}/aws4_request",
+                    date.format("%Y%m%d"),
+                    &self.region_for_service(),
+                    self.signing_name()
+                ),
+            ),
+        };
+        let string_to_sign = format!(
+            "{}\n{}\n{}\n{}",
+            algorithm,
+            date.format("%Y%m%dT%H%M%SZ"),
+            scope,
+            hashed_canonical_request
         );
-        let string_to_sign = string_to_sign(date, &hashed_canonical_request, &scope);
 
         // sign the string
-        let signature = sign_string(
-            &string_to_sign,
-            creds.aws_secret_access_key(),
-            date.date(),
-            &self.region_for_service(),
-            &self.service,
-        );
+        let (signature, signing_key) = match self.region_set {
+            Some(_) => {
+                let signature = sigv4a::sign(
+                    creds.aws_access_key_id(),
+                    creds.aws_secret_access_key(),
+                    &string_to_sign,
+                );
+                (signature, None)
+            }
+            None => {
+                let signing_key = signing_key(
+                    creds.aws_secret_access_key(),
+                    date.date(),
+                    &self.region_for_service(),
+                    self.signing_name(),
+                );
+                let signature = hex::encode(
+                    hmac(&signing_key, string_to_sign.as_bytes())
+                        .finalize()
+                        .into_bytes(),
+                );
+                (signature, Some(signing_key))
+            }
+        };
 
         // build the actual auth header
         let auth_header = format!(
-            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+            algorithm,
             &creds.aws_access_key_id(),
             scope,
             signed_headers,
@@ -509,6 +890,66 @@ impl SignedRequest {
         );
         self.remove_header("authorization");
         self.add_header("authorization", &auth_header);
+
+        if self.debug_signing {
+            self.signing_details = Some(SigningDetails {
+                canonical_request: redact_security_token(&canonical_request),
+                string_to_sign,
+            });
+        }
+
+        if let Some((size, signed)) = chunked {
+            if let Some(SignedRequestPayload::Stream(stream)) = self.payload.take() {
+                // the chunks are signed as they're sent, starting with the signature of the request
+                let signer = match signing_key {
+                    Some(signing_key) if signed => Some(ChunkSigner::new(
+                        signing_key,
+                        date.format("%Y%m%dT%H%M%SZ"),
+                        scope,
+                        signature,
+                    )),
+                    _ => None,
+                };
+                let body =
+                    ChunkedBody::new(stream, chunked::CHUNK_SIZE, signer, self.checksum_algorithm);
+                let encoded_length = chunked::encoded_length(
+                    size,
+                    chunked::CHUNK_SIZE,
+                    signed,
+                    self.checksum_algorithm,
+                );
+                self.payload = Some(SignedRequestPayload::Stream(ByteStream::new_with_size(
+                    body,
+                    encoded_length,
+                )));
+            }
+        }
+    }
+
+    /// Sets the headers of a streamed payload encoded with `aws-chunked`, whose chunks are
+    /// signed if `signed` is set, followed by a trailer with its checksum if it has one.
+    fn encode_chunked(&mut self, size: usize, signed: bool) {
+        let content_encoding = match self.headers.get("content-encoding") {
+            Some(values) => {
+                let encodings = String::from_utf8_lossy(&values[0]).into_owned();
+                format!("aws-chunked,{}", encodings)
+            }
+            None => "aws-chunked".to_owned(),
+        };
+        self.remove_header("content-encoding");
+        self.add_header("content-encoding", &content_encoding);
+        self.remove_header("x-amz-decoded-content-length");
+        self.add_header("x-amz-decoded-content-length", &size.to_string());
+        self.remove_header("content-length");
+        self.add_header(
+            "content-length",
+            &chunked::encoded_length(size, chunked::CHUNK_SIZE, signed, self.checksum_algorithm)
+                .to_string(),
+        );
+        if let Some(algorithm) = self.checksum_algorithm {
+            self.remove_header("x-amz-trailer");
+            self.add_header("x-amz-trailer", algorithm.header_name());
+        }
     }
 }
 
@@ -586,20 +1027,129 @@ fn digest_payload(payload: &[u8]) -> (String, usize) {
 }
 
 #[inline]
-fn hmac(secret: &[u8], message: &[u8]) -> Hmac<Sha256> {
+pub(crate) fn hmac(secret: &[u8], message: &[u8]) -> Hmac<Sha256> {
     let mut hmac = Hmac::<Sha256>::new_varkey(secret).expect("failed to create hmac");
     hmac.update(message);
     hmac
 }
 
 /// Takes a message and signs it using AWS secret, time, region keys and service keys.
-fn sign_string(
+pub(crate) fn sign_string(
     string_to_sign: &str,
     secret: &str,
     date: Date,
     region: &str,
     service: &str,
 ) -> String {
+    hex::encode(
+        hmac(
+            &signing_key(secret, date, region, service),
+            string_to_sign.as_bytes(),
+        )
+        .finalize()
+        .into_bytes(),
+    )
+}
+
+/// The most signing keys kept in the cache, e.g. for clients of many services and regions.
+const MAX_CACHED_SIGNING_KEYS: usize = 64;
+
+/// How long signing keys are cached, as they're only valid for their date.
+const SIGNING_KEY_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The SHA-256 digest of the secret, date, region and service of a signing key.
+type SigningKeyId = ([u8; 32], Date, String, String);
+
+/// A cached signing key, with when it was derived.
+struct CachedSigningKey<K> {
+    key: K,
+    derived_at: Instant,
+}
+
+/// Signing keys derived in the last day, by the digest of the secret they're derived from and
+/// whatever else they depend on. Once it's full, the oldest key is evicted.
+pub(crate) struct SigningKeyCache<I, K> {
+    keys: Mutex<HashMap<I, CachedSigningKey<K>>>,
+    max_len: usize,
+}
+
+impl<I, K> SigningKeyCache<I, K>
+where
+    I: Clone + Eq + Hash,
+    K: Clone,
+{
+    /// Creates a cache of at most `max_len` keys.
+    pub(crate) fn new(max_len: usize) -> Self {
+        SigningKeyCache {
+            keys: Mutex::new(HashMap::new()),
+            max_len,
+        }
+    }
+
+    /// Returns the cached key, or derives and caches it.
+    pub(crate) fn get_or_derive(&self, id: I, derive: impl FnOnce() -> K) -> K {
+        let now = Instant::now();
+        if let Some(cached) = self.keys.lock().unwrap().get(&id) {
+            if now.duration_since(cached.derived_at) < SIGNING_KEY_MAX_AGE {
+                return cached.key.clone();
+            }
+        }
+        let key = derive();
+        let mut keys = self.keys.lock().unwrap();
+        keys.retain(|_, cached| now.duration_since(cached.derived_at) < SIGNING_KEY_MAX_AGE);
+        if keys.len() >= self.max_len && !keys.contains_key(&id) {
+            // evict the oldest key
+            let oldest = keys
+                .iter()
+                .min_by_key(|(_, cached)| cached.derived_at)
+                .map(|(id, _)| id.clone());
+            if let Some(oldest) = oldest {
+                keys.remove(&oldest);
+            }
+        }
+        keys.insert(
+            id,
+            CachedSigningKey {
+                key: key.clone(),
+                derived_at: now,
+            },
+        );
+        key
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, id: &I) -> bool {
+        self.keys.lock().unwrap().contains_key(id)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.keys.lock().unwrap().len()
+    }
+}
+
+lazy_static! {
+    /// The signing keys derived in the last day. Secrets are only kept as their digest.
+    static ref SIGNING_KEYS: SigningKeyCache<SigningKeyId, Vec<u8>> =
+        SigningKeyCache::new(MAX_CACHED_SIGNING_KEYS);
+}
+
+/// Returns the key requests are signed with for the AWS secret, date, region and service.
+///
+/// Deriving it takes four HMACs, so keys are cached for a day, rather than derived again for
+/// every request.
+pub(crate) fn signing_key(secret: &str, date: Date, region: &str, service: &str) -> Vec<u8> {
+    let id = (
+        Sha256::digest(secret.as_bytes()).into(),
+        date,
+        region.to_owned(),
+        service.to_owned(),
+    );
+    SIGNING_KEYS.get_or_derive(id, || derive_signing_key(secret, date, region, service))
+}
+
+/// Derives the key requests are signed with from the AWS secret, date, region and service.
+fn derive_signing_key(secret: &str, date: Date, region: &str, service: &str) -> Vec<u8> {
     let date_str = date.format("%Y%m%d");
     let date_hmac = hmac(format!("AWS4{}", secret).as_bytes(), date_str.as_bytes())
         .finalize()
@@ -610,14 +1160,25 @@ fn sign_string(
     let service_hmac = hmac(region_hmac.as_ref(), service.as_bytes())
         .finalize()
         .into_bytes();
-    let signing_hmac = hmac(service_hmac.as_ref(), b"aws4_request")
+    hmac(service_hmac.as_ref(), b"aws4_request")
         .finalize()
-        .into_bytes();
-    hex::encode(
-        hmac(signing_hmac.as_ref(), string_to_sign.as_bytes())
-            .finalize()
-            .into_bytes(),
-    )
+        .into_bytes()
+        .to_vec()
+}
+
+/// Replaces the value of the `x-amz-security-token` header of a canonical request.
+fn redact_security_token(canonical_request: &str) -> String {
+    canonical_request
+        .split('\n')
+        .map(|line| {
+            if line.starts_with("x-amz-security-token:") {
+                "x-amz-security-token:<redacted>"
+            } else {
+                line
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 /// Mark string as AWS4-HMAC-SHA256 hashed
@@ -679,7 +1240,7 @@ fn canonical_values(values: &[Vec<u8>]) -> String {
 }
 
 fn skipped_headers(header: &str) -> bool {
-    ["authorization", "content-length", "user-agent"].contains(&header)
+    ["authorization", "content-length", "expect", "user-agent"].contains(&header)
 }
 
 /// Returns standardised URI
@@ -761,7 +1322,7 @@ pub fn decode_uri(uri: &str) -> String {
     }
 }
 
-fn to_hexdigest<T: AsRef<[u8]>>(t: T) -> String {
+pub(crate) fn to_hexdigest<T: AsRef<[u8]>>(t: T) -> String {
     let h = Sha256::digest(t.as_ref());
     hex::encode(h)
 }
@@ -787,15 +1348,19 @@ fn extract_hostname(endpoint: &str) -> &str {
 
 /// Takes a `Region` enum and a service and formas a vaild DNS name.
 /// E.g. `Region::ApNortheast1` and `s3` produces `s3.ap-northeast-1.amazonaws.com.cn`
-fn build_hostname(service: &str, region: &Region) -> String {
+pub fn build_hostname(service: &str, region: &Region) -> String {
     // Any of these that modify the region will need to have their signature adjusted as well: sign for destination region
     //iam & cloudfront have only 1 endpoint, other services have region-based endpoints
     match service {
         "organizations" => match *region {
             // organizations is routed specially: see https://docs.aws.amazon.com/organizations/latest/APIReference/Welcome.html and https://docs.aws.amazon.com/general/latest/gr/ao.html
             Region::Custom { ref endpoint, .. } => extract_hostname(endpoint).to_owned(),
-            Region::CnNorth1 | Region::CnNorthwest1 => "organizations.cn-northwest-1.amazonaws.com.cn".to_owned(),
-            Region::UsGovEast1 | Region::UsGovWest1 => "organizations.us-gov-west-1.amazonaws.com".to_owned(),
+            Region::CnNorth1 | Region::CnNorthwest1 => {
+                "organizations.cn-northwest-1.amazonaws.com.cn".to_owned()
+            }
+            Region::UsGovEast1 | Region::UsGovWest1 => {
+                "organizations.us-gov-west-1.amazonaws.com".to_owned()
+            }
             _ => "organizations.us-east-1.amazonaws.com".to_owned(),
         },
         "iam" => match *region {
@@ -854,6 +1419,22 @@ mod tests {
         assert_eq!("sqs.us-east-1.amazonaws.com", request.hostname());
     }
 
+    #[test]
+    fn set_region() {
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.set_region(Region::EuWest1);
+        assert_eq!("sqs.eu-west-1.amazonaws.com", request.hostname());
+
+        let mut request = SignedRequest::new("POST", "iot", &Region::UsEast1, "/");
+        request.set_endpoint_prefix("data.iot".to_string());
+        request.set_region(Region::EuWest1);
+        assert_eq!("data.iot.eu-west-1.amazonaws.com", request.hostname());
+
+        request.set_hostname(Some("localhost".to_string()));
+        request.set_region(Region::UsWest2);
+        assert_eq!("localhost", request.hostname());
+    }
+
     #[test]
     fn convert_request() {
         use http::{Method, Uri, Version};
@@ -1111,4 +1692,234 @@ mod tests {
         // and "authorization" header includes all signed headers
         assert!(authorization_header.contains("x-amz-content-sha256"));
     }
+
+    #[test]
+    fn try_clone() {
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.add_param("Action", "SendMessage");
+        request.set_payload(Some(b"body".to_vec()));
+        let clone = request.try_clone().expect("buffered payload");
+        assert_eq!(clone.path(), "/");
+        assert_eq!(clone.params, request.params);
+        match clone.payload {
+            Some(SignedRequestPayload::Buffer(ref bytes)) => assert_eq!(&bytes[..], b"body"),
+            _ => panic!("payload not copied"),
+        }
+
+        request.set_payload_stream(ByteStream::from(b"body".to_vec()));
+        assert!(request.try_clone().is_none());
+    }
+
+    #[test]
+    fn presign_options() {
+        let credentials = AwsCredentials::new("key", "secret", Some("token".to_owned()), None);
+        let options = PresignOptions {
+            expires_in: Duration::from_secs(60),
+            start_time: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_600_000_000)),
+            headers: vec![(
+                "x-amz-server-side-encryption".to_owned(),
+                "aws:kms".to_owned(),
+            )],
+            sign_security_token: false,
+        };
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        let url = request.generate_presigned_url_with_options(&credentials, &options, false);
+        assert!(url.contains("X-Amz-Date=20200913T122640Z"));
+        assert!(url.contains("X-Amz-Expires=60"));
+        assert!(url.contains("X-Amz-SignedHeaders=host%3Bx-amz-server-side-encryption"));
+        // the token is added after the URL is signed
+        assert!(url.contains("X-Amz-Security-Token=token"));
+        assert!(!request
+            .canonical_query_string
+            .contains("X-Amz-Security-Token"));
+
+        let options = PresignOptions::default();
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.generate_presigned_url_with_options(&credentials, &options, false);
+        assert!(request
+            .canonical_query_string
+            .contains("X-Amz-Security-Token=token"));
+    }
+
+    #[test]
+    fn debug_signing() {
+        let credentials = AwsCredentials::new("key", "secret", Some("token".to_owned()), None);
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.sign(&credentials);
+        assert!(request.signing_details().is_none());
+
+        request.set_debug_signing(true);
+        request.sign(&credentials);
+        let details = request.signing_details().unwrap();
+        assert!(details
+            .canonical_request
+            .starts_with("GET\n/bucket/key\n\n"));
+        assert!(details
+            .canonical_request
+            .contains("\nx-amz-security-token:<redacted>\n"));
+        assert!(!details.canonical_request.contains(":token"));
+        assert!(details.string_to_sign.starts_with("AWS4-HMAC-SHA256\n"));
+        assert!(!details.string_to_sign.contains("secret"));
+    }
+
+    #[test]
+    fn signing_name() {
+        let credentials = AwsCredentials::new("key", "secret", None, None);
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, "/key");
+        request.set_signing_name(Some("s3-outposts".to_owned()));
+        request.sign(&credentials);
+        let authorization =
+            String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+        assert!(authorization.contains("/us-west-2/s3-outposts/aws4_request"));
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsWest2, "/key");
+        request.set_signing_name(Some("s3-outposts".to_owned()));
+        let url = request.generate_presigned_url(&credentials, &Duration::from_secs(60), false);
+        assert!(url.contains("%2Fus-west-2%2Fs3-outposts%2Faws4_request"));
+    }
+
+    #[test]
+    fn signing_key_cache() {
+        // the example of the AWS documentation
+        let date = Date::try_from_ymd(2015, 8, 30).unwrap();
+        let secret = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+        let expected = "c4afb1cc5771d871763a393e44b703571b55cc28424d1a5e86da6ed3c154a4b9";
+        assert_eq!(
+            hex::encode(signing_key(secret, date, "us-east-1", "iam")),
+            expected
+        );
+        // cached
+        assert_eq!(
+            hex::encode(signing_key(secret, date, "us-east-1", "iam")),
+            expected
+        );
+        // keys of other dates, like those of presigned URLs starting later, are cached as well
+        let next_date = date.next_day();
+        signing_key(secret, next_date, "us-east-1", "iam");
+        let digest: [u8; 32] = Sha256::digest(secret.as_bytes()).into();
+        for &date in &[date, next_date] {
+            let id = (digest, date, "us-east-1".to_owned(), "iam".to_owned());
+            assert!(SIGNING_KEYS.contains(&id));
+        }
+
+        for i in 0..MAX_CACHED_SIGNING_KEYS * 2 {
+            let service = format!("service{}", i);
+            assert_eq!(
+                signing_key(secret, date, "us-east-1", &service),
+                derive_signing_key(secret, date, "us-east-1", &service)
+            );
+        }
+        assert!(SIGNING_KEYS.len() <= MAX_CACHED_SIGNING_KEYS);
+    }
+
+    #[test]
+    fn streaming_payload_signing() {
+        let credentials = AwsCredentials::new("key", "secret", None, None);
+        let header = |request: &SignedRequest, name: &str| {
+            String::from_utf8(request.headers()[name][0].clone()).unwrap()
+        };
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_signing(PayloadSigning::Streaming);
+        request.add_header("content-encoding", "gzip");
+        request.set_payload_stream(ByteStream::from(vec![0; 100]));
+        request.sign(&credentials);
+        assert_eq!(header(&request, "x-amz-content-sha256"), STREAMING_PAYLOAD);
+        assert_eq!(header(&request, "content-encoding"), "aws-chunked,gzip");
+        assert_eq!(header(&request, "x-amz-decoded-content-length"), "100");
+        let encoded_length = chunked::encoded_length(100, chunked::CHUNK_SIZE, true, None);
+        assert_eq!(
+            header(&request, "content-length"),
+            encoded_length.to_string()
+        );
+        match request.payload {
+            Some(SignedRequestPayload::Stream(ref stream)) => {
+                assert_eq!(stream.size_hint(), Some(encoded_length))
+            }
+            _ => panic!("payload not streamed"),
+        }
+
+        // the size of the payload has to be known to sign it chunk by chunk
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_signing(PayloadSigning::Streaming);
+        request.set_payload_stream(ByteStream::new(futures::stream::empty()));
+        request.sign(&credentials);
+        assert_eq!(header(&request, "x-amz-content-sha256"), UNSIGNED_PAYLOAD);
+        assert!(!request.headers().contains_key("content-encoding"));
+    }
+
+    #[test]
+    fn checksums() {
+        let credentials = AwsCredentials::new("key", "secret", None, None);
+        let header = |request: &SignedRequest, name: &str| {
+            String::from_utf8(request.headers()[name][0].clone()).unwrap()
+        };
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_checksum_algorithm(Some(ChecksumAlgorithm::Crc32));
+        request.set_payload(Some(b"hello world".to_vec()));
+        request.sign(&credentials);
+        assert_eq!(header(&request, "x-amz-checksum-crc32"), "DUoRhQ==");
+
+        // streamed payloads are followed by a trailer with their checksum
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_checksum_algorithm(Some(ChecksumAlgorithm::Sha256));
+        request.set_payload_stream(ByteStream::from(vec![0; 100]));
+        request.sign(&credentials);
+        assert_eq!(
+            header(&request, "x-amz-content-sha256"),
+            STREAMING_UNSIGNED_PAYLOAD_TRAILER
+        );
+        assert_eq!(header(&request, "x-amz-trailer"), "x-amz-checksum-sha256");
+        assert_eq!(header(&request, "content-encoding"), "aws-chunked");
+        assert_eq!(header(&request, "x-amz-decoded-content-length"), "100");
+        let encoded_length = chunked::encoded_length(
+            100,
+            chunked::CHUNK_SIZE,
+            false,
+            Some(ChecksumAlgorithm::Sha256),
+        );
+        assert_eq!(
+            header(&request, "content-length"),
+            encoded_length.to_string()
+        );
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_signing(PayloadSigning::Streaming);
+        request.set_checksum_algorithm(Some(ChecksumAlgorithm::Crc32c));
+        request.set_payload_stream(ByteStream::from(vec![0; 100]));
+        request.sign(&credentials);
+        assert_eq!(
+            header(&request, "x-amz-content-sha256"),
+            STREAMING_PAYLOAD_TRAILER
+        );
+        assert_eq!(header(&request, "x-amz-trailer"), "x-amz-checksum-crc32c");
+    }
+
+    #[test]
+    fn unsigned_payload_signing() {
+        let credentials = AwsCredentials::new("key", "secret", None, None);
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_signing(PayloadSigning::Unsigned);
+        request.set_payload(Some(b"body".to_vec()));
+        request.sign(&credentials);
+        assert_eq!(
+            request.headers()["x-amz-content-sha256"][0],
+            UNSIGNED_PAYLOAD.as_bytes()
+        );
+
+        // payloads sent over HTTP are still signed
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        let mut request = SignedRequest::new("PUT", "s3", &region, "/bucket/key");
+        request.set_payload_signing(PayloadSigning::Unsigned);
+        request.set_payload(Some(b"body".to_vec()));
+        request.sign(&credentials);
+        assert_eq!(
+            request.headers()["x-amz-content-sha256"][0],
+            to_hexdigest(b"body").as_bytes()
+        );
+    }
 }
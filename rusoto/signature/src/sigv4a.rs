@@ -0,0 +1,173 @@
+//! Signature Version 4A, which signs requests for a set of regions, like those to S3
+//! Multi-Region Access Points, with an ECDSA key derived from the AWS secret.
+
+use hmac::{Hmac, Mac, NewMac};
+use lazy_static::lazy_static;
+use p256::ecdsa::signature::Signer;
+use p256::ecdsa::{Signature, SigningKey};
+use sha2::{Digest, Sha256};
+
+use crate::signature::SigningKeyCache;
+
+/// The algorithm of Signature Version 4A.
+pub(crate) const ALGORITHM: &str = "AWS4-ECDSA-P256-SHA256";
+
+/// The order of the P-256 curve minus two, the largest key derived before adding one.
+const N_MINUS_TWO: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x4f,
+];
+
+/// The most signing keys kept in the cache, e.g. for clients of several accounts or roles.
+const MAX_CACHED_SIGNING_KEYS: usize = 16;
+
+/// The access key ID and the SHA-256 digest of the secret of a signing key.
+type SigningKeyId = (String, [u8; 32]);
+
+lazy_static! {
+    /// The signing keys derived in the last day. Secrets are only kept as their digest.
+    static ref SIGNING_KEYS: SigningKeyCache<SigningKeyId, SigningKey> =
+        SigningKeyCache::new(MAX_CACHED_SIGNING_KEYS);
+}
+
+/// Returns the hex encoded signature of the string to sign.
+pub(crate) fn sign(access_key_id: &str, secret: &str, string_to_sign: &str) -> String {
+    let key = signing_key(access_key_id, secret);
+    let signature: Signature = key.sign(string_to_sign.as_bytes());
+    hex::encode(signature.to_der().as_bytes())
+}
+
+/// Returns the key requests are signed with, which only depends on the credentials.
+fn signing_key(access_key_id: &str, secret: &str) -> SigningKey {
+    let id = (
+        access_key_id.to_owned(),
+        Sha256::digest(secret.as_bytes()).into(),
+    );
+    SIGNING_KEYS.get_or_derive(id, || derive_signing_key(access_key_id, secret))
+}
+
+/// Derives the private key with the counter mode KDF of NIST SP 800-108, increasing the
+/// counter in the context until the result is a valid key.
+fn derive_signing_key(access_key_id: &str, secret: &str) -> SigningKey {
+    let input_key = format!("AWS4A{}", secret);
+    for counter in 1..=u8::MAX {
+        let mut mac = Hmac::<Sha256>::new_varkey(input_key.as_bytes())
+            .expect("HMAC can take keys of any size");
+        mac.update(&1u32.to_be_bytes());
+        mac.update(ALGORITHM.as_bytes());
+        mac.update(&[0]);
+        mac.update(access_key_id.as_bytes());
+        mac.update(&[counter]);
+        mac.update(&256u32.to_be_bytes());
+        let mut candidate: [u8; 32] = mac.finalize().into_bytes().into();
+        // big-endian integers of the same length compare like their bytes
+        if candidate > N_MINUS_TWO {
+            continue;
+        }
+        add_one(&mut candidate);
+        return SigningKey::from_bytes(&candidate.into()).expect("the key is in range");
+    }
+    unreachable!("no key could be derived from the credentials")
+}
+
+/// Adds one to a big-endian integer, which isn't at its maximum.
+fn add_one(bytes: &mut [u8; 32]) {
+    for byte in bytes.iter_mut().rev() {
+        let (sum, overflow) = byte.overflowing_add(1);
+        *byte = sum;
+        if !overflow {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p256::ecdsa::signature::Verifier;
+    use p256::ecdsa::VerifyingKey;
+    use std::time::Duration;
+
+    use crate::credential::AwsCredentials;
+    use crate::{Region, SignedRequest};
+
+    #[test]
+    fn signing_keys() {
+        let key = derive_signing_key(
+            "AKISORANDOMAASORANDOM",
+            "q+jcrXGc+0zWN6uzclKVhvMmUsIfRPa4rlRandom",
+        );
+        assert_eq!(
+            hex::encode(key.to_bytes()),
+            "7fd3bd010c0d9c292141c2b77bfbde1042c92e6836fff749d1269ec890fca1bd"
+        );
+
+        let mut bytes = [0xff; 32];
+        bytes[0] = 0;
+        add_one(&mut bytes);
+        let mut expected = [0; 32];
+        expected[0] = 1;
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn signing_key_cache() {
+        let key = signing_key("key", "cached secret");
+        assert_eq!(key, derive_signing_key("key", "cached secret"));
+        // secrets are only kept as their digest
+        let digest: [u8; 32] = Sha256::digest(b"cached secret").into();
+        assert!(SIGNING_KEYS.contains(&("key".to_owned(), digest)));
+
+        for i in 0..MAX_CACHED_SIGNING_KEYS * 2 {
+            let access_key_id = format!("key{}", i);
+            assert_eq!(
+                signing_key(&access_key_id, "secret"),
+                derive_signing_key(&access_key_id, "secret")
+            );
+        }
+        assert!(SIGNING_KEYS.len() <= MAX_CACHED_SIGNING_KEYS);
+    }
+
+    #[test]
+    fn signatures() {
+        let string_to_sign = "AWS4-ECDSA-P256-SHA256\n20150830T123600Z\n20150830/s3/aws4_request\n";
+        let signature = sign("key", "secret", string_to_sign);
+        let signature = Signature::from_der(&hex::decode(signature).unwrap()).unwrap();
+        let verifying_key = VerifyingKey::from(&signing_key("key", "secret"));
+        assert!(verifying_key
+            .verify(string_to_sign.as_bytes(), &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn region_set_signing() {
+        let credentials = AwsCredentials::new("key", "secret", None, None);
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/key");
+        request.set_region_set(Some("*".to_owned()));
+        request.set_debug_signing(true);
+        request.sign(&credentials);
+
+        let authorization =
+            String::from_utf8(request.headers()["authorization"][0].clone()).unwrap();
+        assert!(authorization.starts_with("AWS4-ECDSA-P256-SHA256 Credential=key/"));
+        assert!(authorization.contains("/s3/aws4_request, "));
+        assert!(authorization.contains(";x-amz-region-set, "));
+        assert_eq!(request.headers()["x-amz-region-set"][0], b"*");
+
+        let signature = authorization.rsplit("Signature=").next().unwrap();
+        let signature = Signature::from_der(&hex::decode(signature).unwrap()).unwrap();
+        let string_to_sign = request.signing_details().unwrap().string_to_sign.clone();
+        assert!(string_to_sign.starts_with("AWS4-ECDSA-P256-SHA256\n"));
+        let verifying_key = VerifyingKey::from(&signing_key("key", "secret"));
+        assert!(verifying_key
+            .verify(string_to_sign.as_bytes(), &signature)
+            .is_ok());
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/key");
+        request.set_region_set(Some("*".to_owned()));
+        let url = request.generate_presigned_url(&credentials, &Duration::from_secs(60), false);
+        assert!(url.contains("X-Amz-Algorithm=AWS4-ECDSA-P256-SHA256"));
+        assert!(url.contains("X-Amz-Region-Set=%2A"));
+        assert!(url.contains("%2Fs3%2Faws4_request"));
+    }
+}
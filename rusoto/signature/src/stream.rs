@@ -1,17 +1,30 @@
 use std::fmt;
 use std::io;
+use std::path::Path;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use bytes::{BufMut, Bytes, BytesMut};
-use futures::{future, stream, Stream, StreamExt};
+use bytes::{Bytes, BytesMut};
+use futures::{future, stream, Stream, TryStreamExt};
 use pin_project::pin_project;
+use tokio::fs::File;
 use tokio::io::AsyncRead;
 
+/// The size of the chunks a `ByteStream` created from an `AsyncRead` is read in.
+const CHUNK_SIZE: usize = 64 * 1024;
+/// The most memory `collect` allocates up front, since the size hint of a response to a `HEAD`
+/// request is the length of a body which isn't sent.
+const MAX_PREALLOCATION: usize = 8 * 1024 * 1024;
+
 /// Stream of bytes.
+///
+/// It's a `Stream` of `Bytes` chunks, and an `AsyncRead` of the bytes, so it can be copied to a
+/// file with `tokio::io::copy`, or read into memory with `collect`.
 #[pin_project]
 pub struct ByteStream {
     size_hint: Option<usize>,
+    /// The rest of a chunk of which only a part was read with `AsyncRead`.
+    remaining: Bytes,
     #[pin]
     inner: Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync + 'static>>,
 }
@@ -24,6 +37,7 @@ impl ByteStream {
     {
         ByteStream {
             size_hint: None,
+            remaining: Bytes::new(),
             inner: Box::pin(stream),
         }
     }
@@ -36,22 +50,116 @@ impl ByteStream {
     {
         ByteStream {
             size_hint: Some(size_hint),
+            remaining: Bytes::new(),
             inner: Box::pin(stream),
         }
     }
 
-    pub(crate) fn size_hint(&self) -> Option<usize> {
+    /// Create a new `ByteStream` reading from an `AsyncRead`, with the number of bytes the reader
+    /// returns as its size hint.
+    ///
+    /// Requests like those of S3's `PutObject` need the length of the body, so it should only be
+    /// `None` for requests which don't.
+    pub fn from_async_read<R>(read: R, len: Option<usize>) -> ByteStream
+    where
+        R: AsyncRead + Send + Sync + 'static,
+    {
+        ByteStream {
+            size_hint: len,
+            remaining: Bytes::new(),
+            inner: Box::pin(ReadStream {
+                read: Box::pin(read),
+                buffer: vec![0; CHUNK_SIZE],
+                done: false,
+            }),
+        }
+    }
+
+    /// Create a new `ByteStream` reading a file, with the length of the file as its size hint.
+    pub async fn from_file(file: File) -> io::Result<ByteStream> {
+        let len = file.metadata().await?.len() as usize;
+        Ok(ByteStream::from_async_read(file, Some(len)))
+    }
+
+    /// Create a new `ByteStream` reading the file at `path`, with the length of the file as its
+    /// size hint.
+    pub async fn from_path<P: AsRef<Path>>(path: P) -> io::Result<ByteStream> {
+        ByteStream::from_file(File::open(path).await?).await
+    }
+
+    /// Call `progress` with the number of bytes read from the stream so far after each chunk,
+    /// e.g. to report the progress of an upload.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # async fn example() -> std::io::Result<()> {
+    /// use rusoto_signature::ByteStream;
+    ///
+    /// let body = ByteStream::from_path("backup.tar")
+    ///     .await?
+    ///     .with_progress(|sent| println!("sent {} bytes", sent));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_progress<F>(self, progress: F) -> ByteStream
+    where
+        F: FnMut(u64) + Send + Sync + 'static,
+    {
+        ByteStream {
+            size_hint: self.size_hint,
+            remaining: Bytes::new(),
+            inner: Box::pin(ProgressStream {
+                inner: self,
+                sent: 0,
+                progress,
+            }),
+        }
+    }
+
+    /// The length of the stream, if it's known, e.g. from the `Content-Length` of a response.
+    pub fn size_hint(&self) -> Option<usize> {
         self.size_hint
     }
 
+    /// Read the whole stream into memory.
+    ///
+    /// A stream of a single chunk, like a body which was already buffered, is returned without
+    /// being copied.
+    pub async fn collect(mut self) -> Result<Bytes, io::Error> {
+        let first = match self.try_next().await? {
+            Some(chunk) => chunk,
+            None => return Ok(Bytes::new()),
+        };
+        let second = match self.try_next().await? {
+            Some(chunk) => chunk,
+            None => return Ok(first),
+        };
+        let capacity = self
+            .size_hint
+            .unwrap_or(0)
+            .min(MAX_PREALLOCATION)
+            .max(first.len() + second.len());
+        let mut bytes = BytesMut::with_capacity(capacity);
+        bytes.extend_from_slice(&first);
+        bytes.extend_from_slice(&second);
+        let bytes = self
+            .try_fold(bytes, |mut bytes, chunk| {
+                bytes.extend_from_slice(&chunk);
+                future::ready(Ok(bytes))
+            })
+            .await?;
+        Ok(bytes.freeze())
+    }
+
     /// Return an implementation of `AsyncRead` that uses async i/o to consume the stream.
     pub fn into_async_read(self) -> impl AsyncRead + Send + Sync {
-        ImplAsyncRead::new(self.inner)
+        self
     }
 
     /// Return an implementation of `Read` that uses blocking i/o to consume the stream.
     pub fn into_blocking_read(self) -> impl io::Read + Send + Sync {
-        ImplBlockingRead::new(self.inner)
+        ImplBlockingRead { inner: self }
     }
 }
 
@@ -59,11 +167,22 @@ impl From<Vec<u8>> for ByteStream {
     fn from(buf: Vec<u8>) -> ByteStream {
         ByteStream {
             size_hint: Some(buf.len()),
+            remaining: Bytes::new(),
             inner: Box::pin(stream::once(async move { Ok(Bytes::from(buf)) })),
         }
     }
 }
 
+impl From<Bytes> for ByteStream {
+    fn from(buf: Bytes) -> ByteStream {
+        ByteStream {
+            size_hint: Some(buf.len()),
+            remaining: Bytes::new(),
+            inner: Box::pin(stream::once(async move { Ok(buf) })),
+        }
+    }
+}
+
 impl fmt::Debug for ByteStream {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "<ByteStream size_hint={:?}>", self.size_hint)
@@ -75,64 +194,91 @@ impl Stream for ByteStream {
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.project();
-        this.inner.poll_next(cx)
-    }
-}
-
-#[pin_project]
-struct ImplAsyncRead {
-    buffer: BytesMut,
-    #[pin]
-    stream: futures::stream::Fuse<Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync>>>,
-}
-
-impl ImplAsyncRead {
-    fn new(stream: Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync>>) -> Self {
-        ImplAsyncRead {
-            buffer: BytesMut::new(),
-            stream: stream.fuse(),
+        if !this.remaining.is_empty() {
+            return Poll::Ready(Some(Ok(std::mem::replace(this.remaining, Bytes::new()))));
         }
+        this.inner.poll_next(cx)
     }
 }
 
-impl AsyncRead for ImplAsyncRead {
+impl AsyncRead for ByteStream {
     fn poll_read(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
         buf: &mut [u8],
     ) -> Poll<io::Result<usize>> {
-        let this = self.project();
-        if this.buffer.is_empty() {
-            match futures::ready!(this.stream.poll_next(cx)) {
+        let mut this = self.project();
+        // skip empty chunks, reading nothing means the end of the stream
+        while this.remaining.is_empty() {
+            match futures::ready!(this.inner.as_mut().poll_next(cx)) {
                 None => return Poll::Ready(Ok(0)),
                 Some(Err(e)) => return Poll::Ready(Err(e)),
-                Some(Ok(bytes)) => {
-                    this.buffer.put(bytes);
-                }
+                Some(Ok(bytes)) => *this.remaining = bytes,
             }
         }
-        let available = std::cmp::min(buf.len(), this.buffer.len());
-        let bytes = this.buffer.split_to(available);
-        let (left, _) = buf.split_at_mut(available);
-        left.copy_from_slice(&bytes[..available]);
+        let available = std::cmp::min(buf.len(), this.remaining.len());
+        let bytes = this.remaining.split_to(available);
+        buf[..available].copy_from_slice(&bytes);
         Poll::Ready(Ok(available))
     }
 }
 
+/// The chunks read from an `AsyncRead`.
+struct ReadStream<R> {
+    read: Pin<Box<R>>,
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl<R: AsyncRead> Stream for ReadStream<R> {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        match futures::ready!(this.read.as_mut().poll_read(cx, &mut this.buffer)) {
+            Ok(0) => {
+                this.done = true;
+                Poll::Ready(None)
+            }
+            Ok(read) => Poll::Ready(Some(Ok(Bytes::copy_from_slice(&this.buffer[..read])))),
+            Err(e) => {
+                this.done = true;
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+}
+
+/// The chunks of a stream, counting the bytes read.
 #[pin_project]
-struct ImplBlockingRead {
+struct ProgressStream<F> {
     #[pin]
-    inner: ImplAsyncRead,
+    inner: ByteStream,
+    sent: u64,
+    progress: F,
 }
 
-impl ImplBlockingRead {
-    fn new(stream: Pin<Box<dyn Stream<Item = Result<Bytes, io::Error>> + Send + Sync>>) -> Self {
-        ImplBlockingRead {
-            inner: ImplAsyncRead::new(stream),
+impl<F: FnMut(u64)> Stream for ProgressStream<F> {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let chunk = futures::ready!(this.inner.poll_next(cx));
+        if let Some(Ok(bytes)) = &chunk {
+            *this.sent += bytes.len() as u64;
+            (this.progress)(*this.sent);
         }
+        Poll::Ready(chunk)
     }
 }
 
+struct ImplBlockingRead {
+    inner: ByteStream,
+}
+
 impl io::Read for ImplBlockingRead {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let mut rt = tokio::runtime::Runtime::new()?;
@@ -216,3 +362,82 @@ async fn test_new_with_size_read() {
     assert_eq!(&buf[..1], b"8");
     assert_eq!(async_read.read(&mut buf).await.unwrap(), 0);
 }
+
+#[tokio::test]
+async fn test_collect() {
+    use bytes::Bytes;
+
+    let chunks = vec![
+        Ok(Bytes::from_static(b"1234")),
+        Ok(Bytes::new()),
+        Ok(Bytes::from_static(b"5678")),
+    ];
+    let stream = ByteStream::new(stream::iter(chunks));
+    assert_eq!(
+        stream.collect().await.unwrap(),
+        Bytes::from_static(b"12345678")
+    );
+
+    let chunks = vec![
+        Ok(Bytes::from_static(b"1234")),
+        Err(io::Error::new(io::ErrorKind::Other, "reset")),
+    ];
+    let stream = ByteStream::new(stream::iter(chunks));
+    assert_eq!(stream.collect().await.unwrap_err().to_string(), "reset");
+
+    // a single chunk isn't copied
+    let chunk = Bytes::from(b"1234".to_vec());
+    let collected = ByteStream::from(chunk.clone()).collect().await.unwrap();
+    assert_eq!(collected.as_ptr(), chunk.as_ptr());
+}
+
+#[tokio::test]
+async fn test_from_async_read() {
+    use futures::StreamExt;
+    use tokio::io::AsyncReadExt;
+
+    let data = vec![7u8; CHUNK_SIZE + 1];
+    let stream = ByteStream::from_async_read(io::Cursor::new(data.clone()), None);
+    assert_eq!(stream.size_hint(), None);
+    let chunks: Vec<_> = stream.map(|chunk| chunk.unwrap().len()).collect().await;
+    assert_eq!(chunks, vec![CHUNK_SIZE, 1]);
+
+    // reading a part of a chunk leaves the rest for the stream
+    let mut stream = ByteStream::from_async_read(io::Cursor::new(data), Some(CHUNK_SIZE + 1));
+    assert_eq!(stream.size_hint(), Some(CHUNK_SIZE + 1));
+    let mut buf = [0u8; 10];
+    assert_eq!(stream.read(&mut buf).await.unwrap(), 10);
+    assert_eq!(stream.next().await.unwrap().unwrap().len(), CHUNK_SIZE - 10);
+}
+
+#[tokio::test]
+async fn test_from_file() {
+    let path = std::env::temp_dir().join(format!("rusoto-stream-{}", std::process::id()));
+    std::fs::write(&path, b"file contents").unwrap();
+    let file = File::open(&path).await.unwrap();
+    let stream = ByteStream::from_file(file).await.unwrap();
+    assert_eq!(stream.size_hint(), Some(13));
+    assert_eq!(&stream.collect().await.unwrap()[..], b"file contents");
+    let stream = ByteStream::from_path(&path).await.unwrap();
+    assert_eq!(stream.size_hint(), Some(13));
+    assert_eq!(&stream.collect().await.unwrap()[..], b"file contents");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_with_progress() {
+    use std::sync::{Arc, Mutex};
+
+    let chunks = vec![
+        Ok(Bytes::from_static(b"1234")),
+        Ok(Bytes::from_static(b"56")),
+        Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset")),
+    ];
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let reported = sent.clone();
+    let stream = ByteStream::new_with_size(stream::iter(chunks), 8)
+        .with_progress(move |sent| reported.lock().unwrap().push(sent));
+    assert_eq!(stream.size_hint(), Some(8));
+    assert_eq!(stream.collect().await.unwrap_err().to_string(), "reset");
+    assert_eq!(*sent.lock().unwrap(), vec![4, 6]);
+}
@@ -128,7 +128,10 @@ pub struct HttpError {
 pub struct Member {
     pub deprecated: Option<bool>,
     pub documentation: Option<String>,
+    pub eventpayload: Option<bool>,
     pub flattened: Option<bool>,
+    #[serde(rename = "idempotencyToken")]
+    pub idempotency_token: Option<bool>,
     pub location: Option<String>,
     #[serde(rename = "locationName")]
     pub location_name: Option<String>,
@@ -149,6 +152,14 @@ impl Member {
     pub fn streaming(&self) -> bool {
         self.streaming.unwrap_or(false)
     }
+
+    pub fn eventpayload(&self) -> bool {
+        self.eventpayload.unwrap_or(false)
+    }
+
+    pub fn idempotency_token(&self) -> bool {
+        self.idempotency_token.unwrap_or(false)
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -316,9 +327,13 @@ pub struct Operation {
     pub http: HttpRequest,
     #[serde(rename = "httpChecksumRequired")]
     pub http_checksum_required: Option<bool>,
+    pub idempotent: Option<bool>,
     pub input: Option<Input>,
     pub name: String,
     pub output: Option<Output>,
+    pub readonly: Option<bool>,
+    #[serde(rename = "requestCompression")]
+    pub request_compression: Option<RequestCompression>,
 }
 
 impl<'a> Operation {
@@ -344,6 +359,14 @@ impl<'a> Operation {
         }
     }
 
+    /// Whether the service accepts gzip compressed payloads for the operation.
+    pub fn accepts_compressed_request(&self) -> bool {
+        self.request_compression
+            .as_ref()
+            .map(|compression| compression.encodings.iter().any(|e| e == "gzip"))
+            .unwrap_or(false)
+    }
+
     // botocore duplicates errors in a few places
     // return a unique set
     pub fn errors(&'a self) -> &BTreeSet<Error> {
@@ -351,6 +374,11 @@ impl<'a> Operation {
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RequestCompression {
+    pub encodings: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct Metadata {
     #[serde(rename = "apiVersion")]
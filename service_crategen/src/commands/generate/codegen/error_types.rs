@@ -224,7 +224,7 @@ impl XmlErrorTypes {
                     .as_ref()
                     .and_then(|http_error| http_error.code.as_ref())
                     .unwrap_or(&error.shape);
-                type_matchers.push(format!("\"{error_code}\" => return RusotoError::Service({error_type}::{error_name}(parsed_error.message))",
+                type_matchers.push(format!("\"{error_code}\" => return RusotoError::service(&res, {error_type}::{error_name}(parsed_error.message))",
                     error_code = error_code,
                     error_type = error_type,
                     error_name = error.idiomatic_error_name()))
@@ -276,7 +276,7 @@ impl JsonErrorTypes {
         if operation.errors.is_some() {
             for error in operation.errors() {
                 if error.shape != "ValidationException" {
-                    type_matchers.push(format!("\"{error_shape}\" => return RusotoError::Service({error_type}::{error_name}(err.msg))",
+                    type_matchers.push(format!("\"{error_shape}\" => return RusotoError::service(&res, {error_type}::{error_name}(err.msg))",
                         error_shape = error.shape,
                         error_type = error_type,
                         error_name = error.idiomatic_error_name()))
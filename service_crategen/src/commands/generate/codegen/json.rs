@@ -2,7 +2,8 @@ use inflector::Inflector;
 use std::io::Write;
 
 use super::{
-    error_type_name, eventstream_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    error_type_name, eventstream_field_name, generate_operation_settings, get_rust_type,
+    input_has_members, FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape};
 use crate::Service;
@@ -43,6 +44,7 @@ impl GenerateProtocol for JsonGenerator {
                 {documentation}
                 {method_signature} -> Result<{output_type}, RusotoError<{error_type}>> {{
                     let mut request = self.new_signed_request(\"{http_method}\", \"{request_uri}\");
+                    {operation_settings}
                     request.add_header(\"x-amz-target\", \"{target_prefix}.{name}\");
                     {payload}
 
@@ -52,6 +54,7 @@ impl GenerateProtocol for JsonGenerator {
                 ",
                 documentation = generate_documentation(operation).unwrap_or_else(|| "".to_owned()),
                 method_signature = generate_method_signature(service, operation),
+                operation_settings = generate_operation_settings(service, operation),
                 payload = generate_payload(service, operation),
                 http_method = operation.http.method,
                 name = operation.name,
@@ -137,6 +140,10 @@ impl GenerateProtocol for JsonGenerator {
         "f64"
     }
 
+    fn takes_input(&self, service: &Service<'_>, operation: &Operation) -> bool {
+        input_has_members(service, operation)
+    }
+
     fn generate_event_enum_deserialize_impl(
         &self,
         service: &Service<'_>,
@@ -209,14 +216,7 @@ fn generate_endpoint_modification(service: &Service<'_>) -> Option<String> {
 }
 
 fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> String {
-    if operation.input.is_some()
-        && service
-            .get_shape(operation.input_shape())
-            .as_ref()
-            .and_then(|s| s.members.as_ref())
-            .map(|m| !m.is_empty())
-            .unwrap_or(false)
-    {
+    if input_has_members(service, operation) {
         format!(
             "async fn {method_name}(&self, input: {input_type}) ",
             input_type = operation.input_shape(),
@@ -231,6 +231,15 @@ fn generate_method_signature(service: &Service<'_>, operation: &Operation) -> St
 }
 
 fn generate_payload(service: &Service<'_>, operation: &Operation) -> String {
+    let payload = generate_payload_serialization(service, operation);
+    if operation.accepts_compressed_request() {
+        payload + "request.set_request_compression(true);\n"
+    } else {
+        payload
+    }
+}
+
+fn generate_payload_serialization(service: &Service<'_>, operation: &Operation) -> String {
     if operation.input.is_some()
         && service
             .get_shape(operation.input_shape())
@@ -11,7 +11,7 @@ use self::rest_json::RestJsonGenerator;
 use self::rest_xml::RestXmlGenerator;
 use self::tests::generate_tests;
 use self::type_filter::filter_types;
-use crate::botocore::{Member, Shape, ShapeType};
+use crate::botocore::{Member, Operation, Shape, ShapeType};
 use crate::util;
 use crate::Service;
 
@@ -80,6 +80,11 @@ pub trait GenerateProtocol {
     /// Return the type used by this protocol for timestamps
     fn timestamp_type(&self) -> &'static str;
 
+    /// Whether the methods generated for the operation take an `input` argument
+    fn takes_input(&self, _service: &Service<'_>, operation: &Operation) -> bool {
+        operation.input.is_some()
+    }
+
     fn generate_event_enum_deserialize_impl(
         &self,
         _service: &Service<'_>,
@@ -166,6 +171,7 @@ where
     generate_types(writer, service, &protocol_generator)?;
     error_type_generator.generate_error_types(writer, service)?;
     generate_client(writer, service, &protocol_generator)?;
+    generate_blocking_client(writer, service, &protocol_generator)?;
     generate_tests(writer, service)?;
 
     Ok(())
@@ -212,6 +218,7 @@ where
                 }}
             }}
 
+            /// Creates a client with the given request dispatcher and credentials provider.
             pub fn new_with<P, D>(request_dispatcher: D, credentials_provider: P, region: region::Region) -> {type_name}
                 where P: ProvideAwsCredentials + Send + Sync + 'static,
                       D: DispatchSignedRequest + Send + Sync + 'static,
@@ -222,6 +229,7 @@ where
                 }}
             }}
 
+            /// Creates a client sharing the request dispatcher and credentials provider of `client`.
             pub fn new_with_client(client: Client, region: region::Region) -> {type_name}
             {{
                 {type_name} {{
@@ -242,6 +250,181 @@ where
     writeln!(writer, "}}")
 }
 
+fn generate_blocking_client<P>(
+    writer: &mut FileWriter,
+    service: &Service<'_>,
+    protocol_generator: &P,
+) -> IoResult
+where
+    P: GenerateProtocol,
+{
+    writeln!(writer,
+        "/// A blocking client for the {service_name} API, for use outside of async code.
+        ///
+        /// It owns the runtime its requests are made on, and is available with the `blocking` feature.
+        /// Its methods panic when called from within a tokio runtime, like in async code, as
+        /// `BlockingRuntime::block_on` does; use `{type_name}` there instead.
+        #[cfg(feature = \"blocking\")]
+        #[derive(Clone)]
+        pub struct {blocking_type_name} {{
+            client: {type_name},
+            runtime: rusoto_core::BlockingRuntime,
+        }}
+
+        #[cfg(feature = \"blocking\")]
+        impl {blocking_type_name} {{
+            /// Creates a client backed by its own runtime.
+            ///
+            /// The client will use the default credentials provider and tls client.
+            pub fn new(region: region::Region) -> {blocking_type_name} {{
+                let credentials_provider = rusoto_core::credential::DefaultCredentialsProvider::new()
+                    .expect(\"failed to create credentials provider\");
+                let request_dispatcher = rusoto_core::HttpClient::new()
+                    .expect(\"failed to create request dispatcher\");
+                {blocking_type_name}::new_with(request_dispatcher, credentials_provider, region)
+            }}
+
+            /// Creates a client with the given request dispatcher and credentials provider, backed by its own runtime.
+            pub fn new_with<P, D>(request_dispatcher: D, credentials_provider: P, region: region::Region) -> {blocking_type_name}
+                where P: ProvideAwsCredentials + Send + Sync + 'static,
+                      D: DispatchSignedRequest + Send + Sync + 'static,
+            {{
+                {blocking_type_name}::new_with_client(Client::new_with(credentials_provider, request_dispatcher), region)
+            }}
+
+            /// Creates a client sharing the request dispatcher and credentials provider of `client`, backed by its own runtime.
+            pub fn new_with_client(client: Client, region: region::Region) -> {blocking_type_name}
+            {{
+                {blocking_type_name} {{
+                    client: {type_name}::new_with_client(client, region),
+                    runtime: rusoto_core::BlockingRuntime::new(),
+                }}
+            }}
+        ",
+        service_name = service.name(),
+        type_name = service.client_type_name(),
+        blocking_type_name = service.blocking_client_type_name(),
+    )?;
+
+    for (operation_name, operation) in service.operations().iter() {
+        let method_name = operation.name.to_snake_case();
+        let (input_parameter, input_argument) = if protocol_generator.takes_input(service, operation) {
+            (format!(", input: {}", operation.input_shape()), "input")
+        } else {
+            (String::new(), "")
+        };
+        let documentation = match operation.documentation {
+            Some(ref docs) => format!("{}\n///\n", crate::doco::Item(docs)),
+            None => String::new(),
+        };
+        writeln!(writer,
+            "
+            {documentation}/// See `{trait_name}::{method_name}`.
+            pub fn {method_name}(&self{input_parameter}) -> Result<{output_type}, RusotoError<{error_type}>> {{
+                self.runtime.block_on(self.client.{method_name}({input_argument}))
+            }}
+            ",
+            documentation = documentation,
+            trait_name = service.service_type_name(),
+            method_name = method_name,
+            input_parameter = input_parameter,
+            input_argument = input_argument,
+            output_type = operation.output_shape_or("()"),
+            error_type = error_type_name(service, operation_name),
+        )?;
+    }
+    writeln!(writer, "}}")
+}
+
+/// The operations which only read, by the verb of their name, for models which don't mark them.
+const READ_ONLY_OPERATION_PREFIXES: &[&str] = &[
+    "BatchGet", "Describe", "Get", "List", "Query", "Scan", "Search",
+];
+
+/// Generates the code setting the name of the operation on its request, and whether the
+/// operation only reads and is idempotent, which decide whether the request is hedged and sent
+/// again after dispatch errors.
+fn generate_operation_settings(service: &Service<'_>, operation: &Operation) -> String {
+    let read_only = is_read_only(service, operation);
+    let idempotent = if read_only
+        || operation.idempotent.unwrap_or(false)
+        || operation.http.method == "PUT"
+        || operation.http.method == "DELETE"
+    {
+        "true".to_owned()
+    } else {
+        generate_idempotency_token_check(service, operation)
+    };
+    format!(
+        "request.set_operation_name(\"{name}\");
+        request.set_read_only({read_only});
+        request.set_idempotent({idempotent});",
+        name = operation.name,
+        read_only = read_only,
+        idempotent = idempotent,
+    )
+}
+
+/// Whether the operation only reads. Models which mark their read-only operations are
+/// followed, otherwise operations sent with `GET` or `HEAD`, or named like `DescribeInstances`,
+/// only read.
+fn is_read_only(service: &Service<'_>, operation: &Operation) -> bool {
+    if service
+        .operations()
+        .values()
+        .any(|operation| operation.readonly.is_some())
+    {
+        return operation.readonly.unwrap_or(false);
+    }
+    match &operation.http.method[..] {
+        "GET" | "HEAD" => true,
+        _ => READ_ONLY_OPERATION_PREFIXES
+            .iter()
+            .any(|prefix| operation.name.starts_with(prefix)),
+    }
+}
+
+/// Generates the expression telling whether the request carries an idempotency token, which
+/// makes sending it again safe.
+fn generate_idempotency_token_check(service: &Service<'_>, operation: &Operation) -> String {
+    let input_shape = match operation
+        .input
+        .as_ref()
+        .and_then(|input| service.get_shape(&input.shape))
+    {
+        Some(shape) => shape,
+        None => return "false".to_owned(),
+    };
+    let mut checks = Vec::new();
+    for (member_name, member) in input_shape.members.iter().flatten() {
+        if !member.idempotency_token() {
+            continue;
+        }
+        if input_shape.required(member_name) {
+            return "true".to_owned();
+        }
+        checks.push(format!(
+            "input.{}.is_some()",
+            generate_field_name(member_name)
+        ));
+    }
+    if checks.is_empty() {
+        "false".to_owned()
+    } else {
+        checks.join(" || ")
+    }
+}
+
+/// Whether the input shape of the operation has any members
+fn input_has_members(service: &Service<'_>, operation: &Operation) -> bool {
+    operation.input.is_some()
+        && service
+            .get_shape(operation.input_shape())
+            .and_then(|s| s.members.as_ref())
+            .map(|m| !m.is_empty())
+            .unwrap_or(false)
+}
+
 pub fn get_rust_type(
     service: &Service<'_>,
     shape_name: &str,
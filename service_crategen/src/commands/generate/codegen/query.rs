@@ -7,7 +7,8 @@ use crate::Service;
 
 use super::xml_payload_parser;
 use super::{
-    error_type_name, generate_field_name, get_rust_type, FileWriter, GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_operation_settings, get_rust_type, FileWriter,
+    GenerateProtocol, IoResult,
 };
 
 pub struct QueryGenerator;
@@ -39,6 +40,7 @@ impl GenerateProtocol for QueryGenerator {
                 {documentation}
                 {method_signature} {{
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, \"{request_uri}\");
+                    {operation_settings}
                     let params = self.new_params(\"{operation_name}\");
                     {serialize_input}
                     {set_input_params}
@@ -56,6 +58,7 @@ impl GenerateProtocol for QueryGenerator {
                          xml_payload_parser::generate_response_parser(service, operation, false, "drop(response);"),
                      method_signature = generate_method_signature(operation_name, operation, service),
                      operation_name = &operation.name,
+                     operation_settings = generate_operation_settings(service, operation),
                      request_uri = &operation.http.request_uri,
                      serialize_input = generate_method_input_serialization(operation),
                      set_input_params = generate_set_input_params(operation))?;
@@ -174,9 +177,14 @@ fn generate_set_input_params(operation: &Operation) -> String {
     if operation.http.method != "POST" {
         panic!("query protocol supports only POST method: {:?}", operation);
     }
-    "request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
-    request.set_content_type(\"application/x-www-form-urlencoded\".to_owned());"
-        .to_owned()
+    let mut parts = vec![
+        "request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));",
+        "request.set_content_type(\"application/x-www-form-urlencoded\".to_owned());",
+    ];
+    if operation.accepts_compressed_request() {
+        parts.push("request.set_request_compression(true);");
+    }
+    parts.join("\n")
 }
 
 fn generate_serializer_body(service: &Service<'_>, shape: &Shape) -> String {
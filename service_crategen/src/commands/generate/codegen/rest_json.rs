@@ -3,8 +3,8 @@ use std::io::Write;
 use inflector::Inflector;
 
 use super::{
-    error_type_name, generate_field_name, rest_request_generator, rest_response_parser, FileWriter,
-    GenerateProtocol, IoResult,
+    error_type_name, generate_field_name, generate_operation_settings, input_has_members,
+    rest_request_generator, rest_response_parser, FileWriter, GenerateProtocol, IoResult,
 };
 use crate::botocore::{Operation, Shape, ShapeType};
 use crate::Service;
@@ -61,6 +61,7 @@ impl GenerateProtocol for RestJsonGenerator {
                     {request_uri_formatter}
 
                     let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                    {operation_settings}
                     {default_headers}
                     {set_headers}
                     {modify_endpoint_prefix}
@@ -86,6 +87,7 @@ impl GenerateProtocol for RestJsonGenerator {
                 endpoint_prefix = service.signing_name(),
                 modify_endpoint_prefix = generate_endpoint_modification(service).unwrap_or_else(|| "".to_owned()),
                 http_method = operation.http.method,
+                operation_settings = generate_operation_settings(service, operation),
                 error_type = error_type_name(service, operation_name),
                 status_check = http_code_expected(operation.http.response_code),
                 parse_body = generate_body_parser(operation, service),
@@ -140,6 +142,10 @@ impl GenerateProtocol for RestJsonGenerator {
     fn timestamp_type(&self) -> &'static str {
         "f64"
     }
+
+    fn takes_input(&self, service: &Service<'_>, operation: &Operation) -> bool {
+        input_has_members(service, operation)
+    }
 }
 
 fn http_code_expected(code: Option<i32>) -> String {
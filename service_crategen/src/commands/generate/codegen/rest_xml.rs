@@ -1,7 +1,7 @@
 use inflector::Inflector;
 use std::io::Write;
 
-use super::{error_type_name, generate_field_name, GenerateProtocol};
+use super::{error_type_name, generate_field_name, generate_operation_settings, GenerateProtocol};
 use super::{
     get_rust_type, mutate_type_name, rest_request_generator, rest_response_parser,
     xml_payload_parser,
@@ -46,6 +46,7 @@ impl GenerateProtocol for RestXmlGenerator {
                         {modify_uri}
 
                         let mut request = SignedRequest::new(\"{http_method}\", \"{endpoint_prefix}\", &self.region, &request_uri);
+                        {operation_settings}
 
                         {set_headers}
                         {set_parameters}
@@ -58,6 +59,7 @@ impl GenerateProtocol for RestXmlGenerator {
                     ",
                      documentation = generate_documentation(operation, service),
                      http_method = &operation.http.method,
+                     operation_settings = generate_operation_settings(service, operation),
                      endpoint_prefix = service.endpoint_prefix(),
                      method_signature = generate_method_signature(operation_name, operation, service),
                      error_type = error_type_name(service, operation_name),
@@ -186,6 +188,95 @@ impl GenerateProtocol for RestXmlGenerator {
     fn timestamp_type(&self) -> &'static str {
         "String"
     }
+
+    fn generate_event_enum_deserialize_impl(
+        &self,
+        service: &Service<'_>,
+        name: &str,
+        shape: &Shape,
+    ) -> String {
+        let match_arms = shape
+            .members
+            .as_ref()
+            .unwrap()
+            .iter()
+            .filter_map(|(member_name, member)| {
+                if member.deprecated == Some(true) {
+                    return None;
+                }
+
+                let event_shape = service.shape_for_member(member).unwrap();
+                let event_type = mutate_type_name(service, &member.shape);
+                Some(format!(
+                    "\"{member_name}\" => {name}::{member_name}({event}),",
+                    name = name,
+                    member_name = member_name,
+                    event = generate_event_deserialization(service, &event_type, event_shape),
+                ))
+            })
+            .chain(std::iter::once(format!(
+                "_ => Err(RusotoError::ParseError({err_fmt}))?",
+                err_fmt = "format!(\"Invalid event type: {}\", event_type)",
+            )))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            "impl DeserializeEvent for {name} {{
+                fn deserialize_event(
+                    event_type: &str,
+                    data: &[u8],
+                ) -> Result<Self, RusotoError<()>> {{
+                    let deserialized = match event_type {{
+                        {match_arms}
+                    }};
+                    Ok(deserialized)
+                }}
+            }}
+            ",
+            name = name,
+            match_arms = match_arms,
+        )
+    }
+}
+
+/// The payload of an event is its member marked as `eventpayload`: blobs are passed through, and
+/// structures are parsed from XML. Events without one, like the `End` of S3 Select, are empty.
+fn generate_event_deserialization(service: &Service<'_>, event_type: &str, shape: &Shape) -> String {
+    let payload = shape
+        .members
+        .iter()
+        .flatten()
+        .find(|(_, member)| member.eventpayload());
+    let (member_name, member) = match payload {
+        Some(payload) => payload,
+        None => return format!("{}::default()", event_type),
+    };
+
+    let payload_shape = service.shape_for_member(member).unwrap();
+    let deserialize = match payload_shape.shape_type {
+        ShapeType::Blob => "bytes::Bytes::copy_from_slice(data)".to_owned(),
+        ShapeType::String => "String::from_utf8_lossy(data).into_owned()".to_owned(),
+        _ => format!(
+            "xml_util::parse_payload(data, |actual_tag_name, stack| {{
+                {payload_shape}Deserializer::deserialize(actual_tag_name, stack)
+            }})?",
+            payload_shape = member.shape,
+        ),
+    };
+    // the other members of the event are sent as headers
+    let other_members = if shape.members.as_ref().unwrap().len() > 1 {
+        "..Default::default()"
+    } else {
+        ""
+    };
+    format!(
+        "{event_type} {{ {field_name}: Some({deserialize}), {other_members} }}",
+        event_type = event_type,
+        field_name = generate_field_name(member_name),
+        deserialize = deserialize,
+        other_members = other_members,
+    )
 }
 
 fn generate_documentation(operation: &Operation, service: &Service<'_>) -> String {
@@ -234,9 +325,27 @@ fn generate_payload_serialization(service: &Service<'_>, operation: &Operation)
         parts.push("request.set_content_md5_header();".to_owned());
     }
 
+    if operation.accepts_compressed_request() {
+        parts.push("request.set_request_compression(true);".to_owned());
+    }
+
+    if accepts_request_payer(input_shape) {
+        parts.push("request.set_request_payer(true);".to_owned());
+    }
+
     Some(parts.join("\n"))
 }
 
+/// Whether the operation can be charged to the requester of a requester pays bucket.
+fn accepts_request_payer(input_shape: &Shape) -> bool {
+    input_shape.members.as_ref().map_or(false, |members| {
+        members.values().any(|member| {
+            member.location.as_deref() == Some("header")
+                && member.location_name.as_deref() == Some("x-amz-request-payer")
+        })
+    })
+}
+
 fn generate_payload_member_serialization(service: &Service, shape: &Shape) -> String {
     let payload_field = shape.payload.as_ref().unwrap();
     let payload_member = shape.members.as_ref().unwrap().get(payload_field).unwrap();
@@ -164,8 +164,12 @@ fn xml_body_parser(
     mutable_result: bool,
     parse_non_payload: &str,
 ) -> String {
-    if let Some(_eventstream_field) = eventstream_field_name(service, shape) {
-        return "unimplemented!()".to_string();
+    if let Some(eventstream_field) = eventstream_field_name(service, shape) {
+        return format!(
+            "Ok({output_shape} {{ {eventstream_field}: Some(EventStream::new(response)) }})",
+            output_shape = output_shape,
+            eventstream_field = eventstream_field,
+        );
     }
 
     let xml_deserialize = if needs_xml_deserializer(shape) {
@@ -85,6 +85,7 @@ pub fn generate_services(
         }
 
         let mut features = BTreeMap::new();
+        features.insert("blocking".into(), vec!["rusoto_core/blocking".into()]);
         features.insert("default".into(), vec!["native-tls".into()]);
         features.insert("native-tls".into(), vec!["rusoto_core/native-tls".into()]);
         features.insert("rustls".into(), vec!["rusoto_core/rustls".into()]);
@@ -105,6 +106,10 @@ pub fn generate_services(
         features.insert("serialize_structs".into(), serialize_feature_dependencies.clone());
         features.insert("deserialize_structs".into(), serialize_feature_dependencies.clone());
 
+        if let Some(ref custom_features) = service_config.custom_features {
+            features.extend(custom_features.clone());
+        }
+
         let mut cargo_manifest = BufWriter::new(
             OpenOptions::new()
                 .write(true)
@@ -189,6 +194,7 @@ To use `{crate_name}` in your application, add it as a dependency in your `Cargo
 ```
 
 ## Crate Features
+- `blocking` - generate `{client_type_name}`, a client with synchronous methods for use outside of async code.
 - `native-tls` - use platform-specific TLS implementation.
 - `rustls` - use rustls TLS implementation.
 - `serialize_structs` - output structs of most operations get `derive(Serialize)`.
@@ -216,6 +222,7 @@ See [LICENSE][license] for details.
                  short_name = service.service_type_name(),
                  aws_name = service.full_name(),
                  crate_name = crate_name,
+                 client_type_name = service.blocking_client_type_name(),
                  version = service_config.version
         ).expect("Couldn't write README for crate");
 
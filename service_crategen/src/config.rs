@@ -21,6 +21,8 @@ pub struct ServiceConfig {
     pub custom_dependencies: Option<BTreeMap<String, cargo::Dependency>>,
     #[serde(rename = "customDevDependencies")]
     pub custom_dev_dependencies: Option<BTreeMap<String, cargo::Dependency>>,
+    #[serde(rename = "customFeatures")]
+    pub custom_features: Option<BTreeMap<String, Vec<String>>>,
     #[serde(rename = "baseTypeName")]
     pub base_type_name: String,
 }
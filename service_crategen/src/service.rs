@@ -46,6 +46,10 @@ impl<'b> Service<'b> {
         format!("{}Client", self.service_type_name())
     }
 
+    pub fn blocking_client_type_name(&self) -> String {
+        format!("{}BlockingClient", self.service_type_name())
+    }
+
     pub fn service_type_name(&self) -> &str {
         &self.config.base_type_name
     }